@@ -0,0 +1,143 @@
+//! Dependency free in-memory plotting
+//!
+//! `BufferPlot` works like `ImagePlot`, but keeps its pixels in a plain buffer and can save them
+//! with the built-in PNG and Netpbm writers, so it's available even without the `image_compat` feature.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use ::color::{Color, Dither, linear_to_srgb, quantize_u8, blend_over_linear};
+use ::plot::Plotter;
+use ::plot::clip::ClipStack;
+use ::utils::clamp;
+
+/// Image formats that `BufferPlot` can be written as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary RGB Netpbm, without alpha
+    Ppm,
+    /// Binary grayscale Netpbm, without alpha
+    Pgm,
+    /// Netpbm with an alpha channel
+    Pam,
+    /// Uncompressed RGBA PNG
+    Png,
+}
+
+impl ImageFormat {
+    /// Guess the format from a file extension
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<ImageFormat> {
+        let extension = path.as_ref().extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+
+        match extension.as_ref().map(|e| &e[..]) {
+            Some("ppm") => Some(ImageFormat::Ppm),
+            Some("pgm") => Some(ImageFormat::Pgm),
+            Some("pam") => Some(ImageFormat::Pam),
+            Some("png") => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+/// Plotter that draws onto an RGBA buffer stored in linear light.
+///
+/// Rows are stored from the top of the image, like most image formats.
+pub struct BufferPlot {
+    width: u32,
+    height: u32,
+    pixels: Vec<[f32; 4]>,
+    foreground: [f32; 4],
+    clip: ClipStack,
+}
+
+impl BufferPlot {
+    pub fn new(width: u32, height: u32) -> BufferPlot {
+        BufferPlot::with_background(width, height, Color::white())
+    }
+
+    /// Create a new plot filled with the given sRGB background color
+    pub fn with_background(width: u32, height: u32, background: Color) -> BufferPlot {
+        BufferPlot {
+            width: width,
+            height: height,
+            pixels: vec![background.to_linear(); width as usize * height as usize],
+            foreground: Color::black().to_linear(),
+            clip: ClipStack::new(),
+        }
+    }
+
+    /// The pixels in linear light
+    pub fn pixels(&self) -> &[[f32; 4]] { &self.pixels }
+
+    /// The pixels in linear light
+    pub fn pixels_mut(&mut self) -> &mut [[f32; 4]] { &mut self.pixels }
+
+    /// Encode the pixels as 8-bit sRGB RGBA
+    pub fn to_rgba8(&self, dither: Dither) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.pixels.len() * 4);
+
+        for (i, p) in self.pixels.iter().enumerate() {
+            let (x, y) = ((i % self.width as usize) as u32, (i / self.width as usize) as u32);
+            let d = dither.offset(x, y);
+
+            for c in 0..3 {
+                out.push(quantize_u8(linear_to_srgb(clamp(p[c], 0.0, 1.0)), d));
+            }
+
+            out.push(quantize_u8(p[3], d));
+        }
+
+        out
+    }
+
+    /// Write the image in the given format
+    pub fn write_to<W: Write>(&self, writer: &mut W, format: ImageFormat) -> io::Result<()> {
+        let rgba = self.to_rgba8(Dither::None);
+
+        match format {
+            ImageFormat::Ppm => ::pnm::write_ppm(writer, self.width, self.height, &rgba),
+            ImageFormat::Pgm => ::pnm::write_pgm(writer, self.width, self.height, &rgba),
+            ImageFormat::Pam => ::pnm::write_pam(writer, self.width, self.height, &rgba),
+            ImageFormat::Png => ::png::write_png(writer, self.width, self.height, &rgba),
+        }
+    }
+
+    /// Save the image to a file, choosing the format from the file extension
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let format = ImageFormat::from_path(&path).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "unknown image file extension")
+        })?;
+
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        self.write_to(&mut writer, format)?;
+
+        writer.flush()
+    }
+}
+
+impl Plotter for BufferPlot {
+    fn width(&self) -> u32 { self.width }
+
+    fn height(&self) -> u32 { self.height }
+
+    fn set_color(&mut self, color: Color) {
+        self.foreground = color.to_linear();
+    }
+
+    fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
+        let (w, h) = (self.width as i64, self.height as i64);
+
+        if 0 <= x && x < w && 0 <= y && y < h {
+            let i = ((h - y - 1) * w + x) as usize;
+
+            let mut top = self.foreground;
+            top[3] *= clamp(alpha, 0.0, 1.0) as f32;
+
+            self.pixels[i] = blend_over_linear(top, self.pixels[i]);
+        }
+    }
+
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> { Some(&mut self.clip) }
+}
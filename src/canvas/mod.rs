@@ -0,0 +1,398 @@
+//! Resolution independent drawing interface shared by raster and vector backends.
+//!
+//! A `Canvas` receives whole primitives, such as lines, polygons, ellipses and text, along with their
+//! colors and styles. Vector backends like `SvgPlot` write them out directly, while every `Plotter`
+//! is a `Canvas` that rasterizes them through its own drawing methods.
+//!
+//! Canvas coordinates are in pixels, with the origin at the bottom left and y pointing up,
+//! the same as for `Plotter`.
+
+pub mod record;
+
+use std::f64::consts::PI;
+
+use ::color::Color;
+use ::geometry::{clip_line_to_polygon, clip_polygon};
+use ::plot::{LineStyle, Plotter};
+
+/// Color, width and dash pattern of a stroked line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    pub color: Color,
+    pub style: LineStyle,
+    /// Lengths of the drawn and skipped parts of a dashed line, in pixels
+    pub dash: Option<(f64, f64)>,
+}
+
+impl Stroke {
+    pub fn new(color: Color, style: LineStyle) -> Stroke {
+        Stroke { color: color, style: style, dash: None }
+    }
+
+    /// Same stroke with a dash pattern
+    pub fn dashed(self, on: f64, off: f64) -> Stroke {
+        Stroke { dash: Some((on, off)), ..self }
+    }
+
+    /// Same stroke with a different color
+    pub fn with_color(self, color: Color) -> Stroke {
+        Stroke { color: color, ..self }
+    }
+
+    /// Width of the stroke in pixels
+    pub fn width(&self) -> f64 {
+        self.style.width()
+    }
+}
+
+impl From<LineStyle> for Stroke {
+    fn from(style: LineStyle) -> Stroke {
+        Stroke::new(Color::black(), style)
+    }
+}
+
+/// Horizontal alignment of text relative to its anchor point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical alignment of text relative to its anchor point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VAlign {
+    /// The top of the tallest glyphs
+    Top,
+    /// Halfway between the top and bottom
+    Middle,
+    /// The baseline the glyphs sit on
+    Baseline,
+    /// The bottom of the descenders
+    Bottom,
+}
+
+/// Point of the text that is placed at the drawing coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Anchor {
+    pub horizontal: HAlign,
+    pub vertical: VAlign,
+}
+
+impl Anchor {
+    pub fn new(horizontal: HAlign, vertical: VAlign) -> Anchor {
+        Anchor { horizontal: horizontal, vertical: vertical }
+    }
+}
+
+impl Default for Anchor {
+    fn default() -> Anchor {
+        Anchor::new(HAlign::Left, VAlign::Baseline)
+    }
+}
+
+/// Size, color, alignment and rotation of drawn text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextStyle {
+    /// Height of the font in pixels
+    pub size: f64,
+    pub color: Color,
+    pub anchor: Anchor,
+    /// Counter-clockwise rotation around the anchor point, in radians
+    pub rotation: f64,
+}
+
+impl TextStyle {
+    pub fn new(size: f64) -> TextStyle {
+        TextStyle { size: size, color: Color::black(), anchor: Anchor::default(), rotation: 0.0 }
+    }
+
+    pub fn with_color(self, color: Color) -> TextStyle {
+        TextStyle { color: color, ..self }
+    }
+
+    pub fn with_anchor(self, horizontal: HAlign, vertical: VAlign) -> TextStyle {
+        TextStyle { anchor: Anchor::new(horizontal, vertical), ..self }
+    }
+
+    pub fn with_rotation(self, rotation: f64) -> TextStyle {
+        TextStyle { rotation: rotation, ..self }
+    }
+}
+
+/// Measured size of a line of text, in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TextExtent {
+    /// Horizontal advance of the whole string
+    pub width: f64,
+    /// Height above the baseline
+    pub ascent: f64,
+    /// Depth below the baseline, as a positive number
+    pub descent: f64,
+}
+
+impl TextExtent {
+    /// Total height of the text
+    pub fn height(&self) -> f64 {
+        self.ascent + self.descent
+    }
+
+    /// Offset from the anchor point to the left end of the baseline, before rotation
+    pub fn anchor_offset(&self, anchor: Anchor) -> (f64, f64) {
+        let dx = match anchor.horizontal {
+            HAlign::Left => 0.0,
+            HAlign::Center => -self.width * 0.5,
+            HAlign::Right => -self.width,
+        };
+
+        let dy = match anchor.vertical {
+            VAlign::Top => -self.ascent,
+            VAlign::Middle => (self.descent - self.ascent) * 0.5,
+            VAlign::Baseline => 0.0,
+            VAlign::Bottom => self.descent,
+        };
+
+        (dx, dy)
+    }
+}
+
+/// Vector drawing interface implemented by all output backends
+pub trait Canvas {
+    /// Width and height of the canvas in pixels
+    fn dimensions(&self) -> (u32, u32);
+
+    /// Draw a straight line
+    fn line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, stroke: Stroke) {
+        self.polyline(&[(x0, y0), (x1, y1)], stroke)
+    }
+
+    /// Draw connected line segments through the given points
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke);
+
+    /// Draw a closed polygon, filled and/or outlined
+    fn polygon(&mut self, points: &[(f64, f64)], fill: Option<Color>, stroke: Option<Stroke>) {
+        self.compound_polygon(&[points], fill, stroke)
+    }
+
+    /// Draw a polygon made of several closed contours, filled with the nonzero winding rule
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>);
+
+    /// Draw an axis-aligned rectangle between two corners
+    fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.polygon(&[(x0, y0), (x1, y0), (x1, y1), (x0, y1)], fill, stroke)
+    }
+
+    /// Draw an axis-aligned ellipse with the given center and radii
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>);
+
+    /// Draw a circle with the given center and radius
+    fn circle(&mut self, cx: f64, cy: f64, radius: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.ellipse(cx, cy, radius, radius, fill, stroke)
+    }
+
+    /// Draw a line of text anchored at the given point
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle);
+
+    /// Measure a line of text as it would be drawn by `text`
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent;
+
+    /// Restrict all following drawing to an axis-aligned rectangle between two corners, until the matching `pop_clip`
+    fn push_clip_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        self.push_clip_polygon(&[(x0, y0), (x1, y0), (x1, y1), (x0, y1)])
+    }
+
+    /// Restrict all following drawing to a polygon, until the matching `pop_clip`
+    ///
+    /// Every pushed region is intersected with the ones below it. Canvases that can't clip draw everything.
+    /// Plotters clip geometrically to convex regions only, so they clip to the bounding box of a concave polygon
+    /// unless wrapped in a `ClipPlot`.
+    fn push_clip_polygon(&mut self, _points: &[(f64, f64)]) {}
+
+    /// Remove the most recently pushed clipping region
+    fn pop_clip(&mut self) {}
+}
+
+/// Splits a polyline into the segments of a dash pattern, continuing the pattern across vertices.
+pub fn dash_polyline<F>(points: &[(f64, f64)], on: f64, off: f64, mut segment: F) where F: FnMut(f64, f64, f64, f64) {
+    if on <= 0.0 || off < 0.0 {
+        return;
+    }
+
+    // Position within the current dash period
+    let mut phase = 0.0;
+
+    for w in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+
+        let length = (x1 - x0).hypot(y1 - y0);
+
+        if length == 0.0 { continue; }
+
+        let (dx, dy) = ((x1 - x0) / length, (y1 - y0) / length);
+
+        let mut t = 0.0;
+
+        while t < length {
+            let (limit, drawn) = if phase < on { (on, true) } else { (on + off, false) };
+
+            let step = (limit - phase).min(length - t);
+
+            if drawn {
+                segment(x0 + dx * t, y0 + dy * t, x0 + dx * (t + step), y0 + dy * (t + step));
+            }
+
+            t += step;
+            phase += step;
+
+            if phase >= on + off {
+                phase = 0.0;
+            }
+        }
+    }
+}
+
+/// Approximates an ellipse with a closed polygon fine enough to be indistinguishable when rasterized
+pub fn flatten_ellipse(cx: f64, cy: f64, rx: f64, ry: f64) -> Vec<(f64, f64)> {
+    let r = rx.abs().max(ry.abs());
+
+    // Keep the deviation from the true curve to around a tenth of a pixel
+    let segments = if r < 1.0 { 8 } else { ((PI / (1.0 - 0.1 / r).acos()).ceil() as usize).max(8) };
+
+    (0..segments).map(|i| {
+        let theta = 2.0 * PI * i as f64 / segments as f64;
+
+        (cx + rx * theta.cos(), cy + ry * theta.sin())
+    }).collect()
+}
+
+/// Every plotter rasterizes canvas primitives through its own drawing methods
+///
+/// Primitives are clipped geometrically to the current region of the plotter's `clip_stack` first. Lines are
+/// clipped along their middle, so thick lines reach past the edge by half their width, and text is drawn whole
+/// if its anchor point lies inside the region and left out otherwise.
+impl<P: Plotter> Canvas for P {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width(), self.height())
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke) {
+        self.set_color(stroke.color);
+
+        let style = stroke.style;
+        let region = clip_region(self);
+
+        let segment = |plotter: &mut P, x0: f64, y0: f64, x1: f64, y1: f64| {
+            match region {
+                Some(ref region) => {
+                    if let Some((x0, y0, x1, y1)) = clip_line_to_polygon(x0, y0, x1, y1, region) {
+                        plotter.draw_line(x0, y0, x1, y1, style)
+                    }
+                }
+                None => plotter.draw_line(x0, y0, x1, y1, style),
+            }
+        };
+
+        match stroke.dash {
+            Some((on, off)) => {
+                dash_polyline(points, on, off, |x0, y0, x1, y1| segment(self, x0, y0, x1, y1))
+            }
+            None => {
+                for w in points.windows(2) {
+                    segment(self, w[0].0, w[0].1, w[1].0, w[1].1);
+                }
+            }
+        }
+    }
+
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>) {
+        if let Some(color) = fill {
+            self.set_color(color);
+
+            match clip_region(self) {
+                Some(region) => {
+                    let clipped: Vec<Vec<(f64, f64)>> = contours.iter()
+                        .map(|contour| clip_polygon(contour, &region))
+                        .filter(|contour| contour.len() > 2)
+                        .collect();
+
+                    let clipped: Vec<&[(f64, f64)]> = clipped.iter().map(|contour| &contour[..]).collect();
+
+                    if !clipped.is_empty() {
+                        self.fill_compound_polygon(&clipped);
+                    }
+                }
+                None => self.fill_compound_polygon(contours),
+            }
+        }
+
+        if let Some(stroke) = stroke {
+            for contour in contours {
+                if let Some(&first) = contour.first() {
+                    let mut closed = contour.to_vec();
+                    closed.push(first);
+
+                    self.polyline(&closed, stroke);
+                }
+            }
+        }
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        // Clipped ellipses are drawn as polygons, which clip like any other
+        if clip_region(self).is_some() {
+            return self.polygon(&flatten_ellipse(cx, cy, rx, ry), fill, stroke);
+        }
+
+        if let Some(color) = fill {
+            self.set_color(color);
+            self.fill_polygon(&flatten_ellipse(cx, cy, rx, ry));
+        }
+
+        if let Some(stroke) = stroke {
+            if stroke.dash.is_some() {
+                let mut points = flatten_ellipse(cx, cy, rx, ry);
+                let first = points[0];
+                points.push(first);
+
+                self.polyline(&points, stroke);
+            } else {
+                self.set_color(stroke.color);
+
+                if rx == ry {
+                    self.draw_circle(cx.round() as i64, cy.round() as i64, rx.round() as i64, stroke.style);
+                } else {
+                    self.draw_ellipse((cx - rx).round() as i64, (cy - ry).round() as i64,
+                                      (cx + rx).round() as i64, (cy + ry).round() as i64, stroke.style);
+                }
+            }
+        }
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle) {
+        if let Some(stack) = self.clip_stack() {
+            if !stack.contains(x, y) {
+                return;
+            }
+        }
+
+        self.set_color(style.color);
+        self.draw_rotated_text(x, y, text, style.anchor, style.size, style.rotation);
+    }
+
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        self.measure_text(text, style.size)
+    }
+
+    fn push_clip_polygon(&mut self, points: &[(f64, f64)]) {
+        self.push_clip_region(points)
+    }
+
+    fn pop_clip(&mut self) {
+        self.pop_clip_region()
+    }
+}
+
+/// Current clipping region of a plotter's clip stack, if anything is clipped
+fn clip_region<P: Plotter>(plotter: &mut P) -> Option<Vec<(f64, f64)>> {
+    plotter.clip_stack().and_then(|stack| stack.region().map(|region| region.to_vec()))
+}
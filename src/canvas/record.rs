@@ -0,0 +1,618 @@
+//! Recording canvas primitives into a display list
+//!
+//! A `DisplayList` is a `Canvas` that stores every primitive drawn onto it, so a figure can be drawn once
+//! and then replayed onto any number of backends, possibly at a different resolution. Display lists can be
+//! written to and read from a simple line based text format, which also makes them easy to compare in tests.
+//!
+//! Clipping regions are recorded along with the primitives. Transforms aren't part of the `Canvas` interface, so
+//! primitives are recorded in the coordinates they were drawn at, after any transform of the code drawing them.
+//!
+//! ```
+//! use rust_plot::canvas::{Canvas, TextStyle};
+//! use rust_plot::canvas::record::{Command, DisplayList};
+//! use rust_plot::color::Color;
+//!
+//! let mut list = DisplayList::new(200, 100);
+//!
+//! list.push_clip_rect(10.0, 10.0, 190.0, 90.0);
+//! list.circle(100.0, 50.0, 60.0, Some(Color::rgb(0.2, 0.4, 0.8)), None);
+//! list.pop_clip();
+//! list.text(100.0, 50.0, "\tx\u{a0}=\u{3000}1 ", &TextStyle::new(12.0));
+//!
+//! let read = DisplayList::parse(&list.to_text()).unwrap();
+//!
+//! assert_eq!(read, list);
+//! assert_eq!(read.commands()[2], Command::PopClip);
+//!
+//! let error = DisplayList::parse("size 200 100\nrect - - 0 0 10\n").unwrap_err();
+//! assert_eq!(error.line, 2);
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use ::color::Color;
+use ::plot::LineStyle;
+use ::vector::StandardFont;
+
+use super::{Canvas, Stroke, TextStyle, TextExtent, Anchor, HAlign, VAlign};
+
+/// A single recorded primitive
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Polyline { points: Vec<(f64, f64)>, stroke: Stroke },
+    Polygon { contours: Vec<Vec<(f64, f64)>>, fill: Option<Color>, stroke: Option<Stroke> },
+    Rect { x0: f64, y0: f64, x1: f64, y1: f64, fill: Option<Color>, stroke: Option<Stroke> },
+    Ellipse { cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke> },
+    Text { x: f64, y: f64, text: String, style: TextStyle },
+    /// Restrict the following primitives to a polygon, until the matching `PopClip`
+    PushClip { points: Vec<(f64, f64)> },
+    PopClip,
+}
+
+/// Canvas that records primitives for later replay
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayList {
+    width: u32,
+    height: u32,
+    font: StandardFont,
+    commands: Vec<Command>,
+}
+
+/// Error produced when reading a malformed display list
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Line the error was found on, counting from one
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl DisplayList {
+    pub fn new(width: u32, height: u32) -> DisplayList {
+        DisplayList {
+            width: width,
+            height: height,
+            font: StandardFont::Helvetica,
+            commands: Vec::new(),
+        }
+    }
+
+    /// Set the font metrics used to measure text while recording.
+    ///
+    /// Text is measured with standard font metrics so that layout doesn't depend on where the list is replayed.
+    pub fn set_font_metrics(&mut self, font: StandardFont) {
+        self.font = font;
+    }
+
+    pub fn commands(&self) -> &[Command] { &self.commands }
+
+    pub fn push(&mut self, command: Command) {
+        self.commands.push(command)
+    }
+
+    pub fn clear(&mut self) {
+        self.commands.clear()
+    }
+
+    /// Draw all recorded primitives onto another canvas at the same coordinates
+    pub fn replay<C: Canvas>(&self, canvas: &mut C) {
+        self.replay_transformed(canvas, 1.0, 1.0)
+    }
+
+    /// Draw all recorded primitives onto another canvas, scaled to fill its dimensions
+    ///
+    /// Line widths, dashes and text sizes are scaled by the geometric mean of the horizontal and vertical scale.
+    pub fn replay_scaled<C: Canvas>(&self, canvas: &mut C) {
+        let (w, h) = canvas.dimensions();
+
+        let sx = if self.width > 0 { w as f64 / self.width as f64 } else { 1.0 };
+        let sy = if self.height > 0 { h as f64 / self.height as f64 } else { 1.0 };
+
+        self.replay_transformed(canvas, sx, sy)
+    }
+
+    fn replay_transformed<C: Canvas>(&self, canvas: &mut C, sx: f64, sy: f64) {
+        let s = (sx * sy).sqrt();
+
+        let point = |&(x, y): &(f64, f64)| (x * sx, y * sy);
+        let stroke = |stroke: Stroke| scale_stroke(stroke, s);
+
+        for command in &self.commands {
+            match *command {
+                Command::Polyline { ref points, stroke: st } => {
+                    let points: Vec<(f64, f64)> = points.iter().map(&point).collect();
+
+                    canvas.polyline(&points, stroke(st));
+                }
+                Command::Polygon { ref contours, fill, stroke: st } => {
+                    let contours: Vec<Vec<(f64, f64)>> = contours.iter().map(|c| c.iter().map(&point).collect()).collect();
+                    let contours: Vec<&[(f64, f64)]> = contours.iter().map(|c| &c[..]).collect();
+
+                    canvas.compound_polygon(&contours, fill, st.map(&stroke));
+                }
+                Command::Rect { x0, y0, x1, y1, fill, stroke: st } => {
+                    canvas.rect(x0 * sx, y0 * sy, x1 * sx, y1 * sy, fill, st.map(&stroke));
+                }
+                Command::Ellipse { cx, cy, rx, ry, fill, stroke: st } => {
+                    canvas.ellipse(cx * sx, cy * sy, rx * sx, ry * sy, fill, st.map(&stroke));
+                }
+                Command::Text { x, y, ref text, style } => {
+                    canvas.text(x * sx, y * sy, text, &TextStyle { size: style.size * s, ..style });
+                }
+                Command::PushClip { ref points } => {
+                    let points: Vec<(f64, f64)> = points.iter().map(&point).collect();
+
+                    canvas.push_clip_polygon(&points);
+                }
+                Command::PopClip => canvas.pop_clip(),
+            }
+        }
+    }
+
+    /// Write the display list in its text format
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+
+        writeln!(out, "size {} {}", self.width, self.height).unwrap();
+
+        for command in &self.commands {
+            match *command {
+                Command::Polyline { ref points, stroke } => {
+                    write!(out, "polyline {}", format_stroke(stroke)).unwrap();
+                    write_points(&mut out, points);
+                }
+                Command::Polygon { ref contours, fill, stroke } => {
+                    write!(out, "polygon {} {} {}", format_fill(fill), format_optional_stroke(stroke), contours.len()).unwrap();
+
+                    for contour in contours {
+                        write!(out, " {}", contour.len()).unwrap();
+                        write_points(&mut out, contour);
+                    }
+                }
+                Command::Rect { x0, y0, x1, y1, fill, stroke } => {
+                    write!(out, "rect {} {} {} {} {} {}", format_fill(fill), format_optional_stroke(stroke), x0, y0, x1, y1).unwrap();
+                }
+                Command::Ellipse { cx, cy, rx, ry, fill, stroke } => {
+                    write!(out, "ellipse {} {} {} {} {} {}", format_fill(fill), format_optional_stroke(stroke), cx, cy, rx, ry).unwrap();
+                }
+                Command::Text { x, y, ref text, style } => {
+                    write!(out, "text {} {} {} {} {} {} {}", x, y, style.size, format_color(style.color),
+                           format_halign(style.anchor.horizontal), format_valign(style.anchor.vertical),
+                           style.rotation).unwrap();
+
+                    if !text.is_empty() {
+                        write!(out, " {}", escape_text(text)).unwrap();
+                    }
+                }
+                Command::PushClip { ref points } => {
+                    out.push_str("push_clip");
+                    write_points(&mut out, points);
+                }
+                Command::PopClip => out.push_str("pop_clip"),
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Read a display list from its text format
+    pub fn parse(text: &str) -> Result<DisplayList, ParseError> {
+        let mut list: Option<DisplayList> = None;
+
+        let mut lines = 0;
+
+        for (i, line) in text.lines().enumerate() {
+            lines = i + 1;
+
+            // Text escapes all whitespace, so only the separating spaces are left to trim
+            if line.trim().is_empty() || line.trim().starts_with('#') { continue; }
+
+            let mut tokens = Tokens { tokens: line.split(' ').filter(|t| !t.is_empty()), line: lines };
+
+            if tokens.peek_keyword("size") {
+                tokens.next()?;
+
+                list = Some(DisplayList::new(tokens.parse()?, tokens.parse()?));
+            } else {
+                match list {
+                    Some(ref mut list) => {
+                        let command = parse_command(&mut tokens).map_err(|error| ParseError { line: lines, ..error })?;
+
+                        list.push(command);
+                    }
+                    None => return Err(ParseError { line: lines, message: "expected size before drawing commands".to_owned() }),
+                }
+            }
+        }
+
+        list.ok_or(ParseError { line: lines.max(1), message: "missing size".to_owned() })
+    }
+}
+
+fn parse_command<'a, I: Iterator<Item = &'a str> + Clone>(tokens: &mut Tokens<I>) -> Result<Command, ParseError> {
+    Ok(match tokens.next()? {
+        "polyline" => {
+            let stroke = parse_stroke(tokens.next()?)?;
+
+            Command::Polyline { points: tokens.points()?, stroke: stroke }
+        }
+        "polygon" => {
+            let fill = parse_fill(tokens.next()?)?;
+            let stroke = parse_optional_stroke(tokens.next()?)?;
+            let count: usize = tokens.parse()?;
+
+            let mut contours = Vec::with_capacity(count);
+
+            for _ in 0..count {
+                let n: usize = tokens.parse()?;
+
+                let mut contour = Vec::with_capacity(n);
+
+                for _ in 0..n {
+                    contour.push(parse_point(tokens.next()?)?);
+                }
+
+                contours.push(contour);
+            }
+
+            Command::Polygon { contours: contours, fill: fill, stroke: stroke }
+        }
+        "rect" => {
+            Command::Rect {
+                fill: parse_fill(tokens.next()?)?,
+                stroke: parse_optional_stroke(tokens.next()?)?,
+                x0: tokens.parse()?,
+                y0: tokens.parse()?,
+                x1: tokens.parse()?,
+                y1: tokens.parse()?,
+            }
+        }
+        "ellipse" => {
+            Command::Ellipse {
+                fill: parse_fill(tokens.next()?)?,
+                stroke: parse_optional_stroke(tokens.next()?)?,
+                cx: tokens.parse()?,
+                cy: tokens.parse()?,
+                rx: tokens.parse()?,
+                ry: tokens.parse()?,
+            }
+        }
+        "text" => {
+            let x = tokens.parse()?;
+            let y = tokens.parse()?;
+            let size = tokens.parse()?;
+            let color = parse_color(tokens.next()?)?;
+            let horizontal = parse_halign(tokens.next()?)?;
+            let vertical = parse_valign(tokens.next()?)?;
+            let rotation = tokens.parse()?;
+
+            // Empty text leaves no token at all
+            let text = tokens.tokens.next().unwrap_or("");
+
+            Command::Text {
+                x: x,
+                y: y,
+                text: unescape_text(text),
+                style: TextStyle {
+                    size: size,
+                    color: color,
+                    anchor: Anchor::new(horizontal, vertical),
+                    rotation: rotation,
+                },
+            }
+        }
+        "push_clip" => Command::PushClip { points: tokens.points()? },
+        "pop_clip" => Command::PopClip,
+        keyword => return Err(invalid("command", keyword)),
+    })
+}
+
+impl FromStr for DisplayList {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<DisplayList, ParseError> {
+        DisplayList::parse(s)
+    }
+}
+
+impl Canvas for DisplayList {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke) {
+        self.push(Command::Polyline { points: points.to_vec(), stroke: stroke });
+    }
+
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>) {
+        self.push(Command::Polygon { contours: contours.iter().map(|c| c.to_vec()).collect(), fill: fill, stroke: stroke });
+    }
+
+    fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.push(Command::Rect { x0: x0, y0: y0, x1: x1, y1: y1, fill: fill, stroke: stroke });
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.push(Command::Ellipse { cx: cx, cy: cy, rx: rx, ry: ry, fill: fill, stroke: stroke });
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle) {
+        self.push(Command::Text { x: x, y: y, text: text.to_owned(), style: *style });
+    }
+
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        self.font.extent(text, style.size)
+    }
+
+    fn push_clip_polygon(&mut self, points: &[(f64, f64)]) {
+        self.push(Command::PushClip { points: points.to_vec() });
+    }
+
+    fn pop_clip(&mut self) {
+        self.push(Command::PopClip);
+    }
+}
+
+fn scale_stroke(stroke: Stroke, s: f64) -> Stroke {
+    let style = match stroke.style {
+        LineStyle::Thick { width, hardness } => LineStyle::Thick { width: width * s, hardness: hardness },
+        LineStyle::ThickAA { width, hardness } => LineStyle::ThickAA { width: width * s, hardness: hardness },
+        style => style,
+    };
+
+    Stroke {
+        color: stroke.color,
+        style: style,
+        dash: stroke.dash.map(|(on, off)| (on * s, off * s)),
+    }
+}
+
+/// Iterator over the space separated tokens of a line that produces parse errors
+struct Tokens<I> {
+    tokens: I,
+    /// Line the tokens come from, counting from one
+    line: usize,
+}
+
+impl<'a, I: Iterator<Item = &'a str> + Clone> Tokens<I> {
+    fn next(&mut self) -> Result<&'a str, ParseError> {
+        let line = self.line;
+
+        self.tokens.next().ok_or(ParseError { line: line, message: "unexpected end of line".to_owned() })
+    }
+
+    fn parse<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let token = self.next()?;
+
+        token.parse().map_err(|_| ParseError { line: self.line, message: format!("invalid number `{}`", token) })
+    }
+
+    /// All remaining tokens as points
+    fn points(&mut self) -> Result<Vec<(f64, f64)>, ParseError> {
+        let mut points = Vec::new();
+
+        while let Some(token) = self.tokens.next() {
+            points.push(parse_point(token)?);
+        }
+
+        Ok(points)
+    }
+
+    /// Whether the next token is the given keyword, without consuming it
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.tokens.clone().next() == Some(keyword)
+    }
+}
+
+/// Error about a malformed token, given its line by `DisplayList::parse`
+fn invalid(what: &str, token: &str) -> ParseError {
+    ParseError { line: 0, message: format!("invalid {} `{}`", what, token) }
+}
+
+fn write_points(out: &mut String, points: &[(f64, f64)]) {
+    for &(x, y) in points {
+        write!(out, " {},{}", x, y).unwrap();
+    }
+}
+
+fn parse_numbers(token: &str, separator: char) -> Option<Vec<f64>> {
+    token.split(separator).map(|n| n.parse().ok()).collect()
+}
+
+fn parse_point(token: &str) -> Result<(f64, f64), ParseError> {
+    match parse_numbers(token, ',') {
+        Some(ref n) if n.len() == 2 => Ok((n[0], n[1])),
+        _ => Err(invalid("point", token)),
+    }
+}
+
+fn format_color(color: Color) -> String {
+    format!("{},{},{},{}", color.r, color.g, color.b, color.a)
+}
+
+fn parse_color(token: &str) -> Result<Color, ParseError> {
+    match token.split(',').map(|n| n.parse().ok()).collect::<Option<Vec<f32>>>() {
+        Some(ref n) if n.len() == 4 => Ok(Color::rgba(n[0], n[1], n[2], n[3])),
+        _ => Err(invalid("color", token)),
+    }
+}
+
+fn format_fill(fill: Option<Color>) -> String {
+    fill.map_or("-".to_owned(), format_color)
+}
+
+fn parse_fill(token: &str) -> Result<Option<Color>, ParseError> {
+    if token == "-" { Ok(None) } else { parse_color(token).map(Some) }
+}
+
+/// Strokes are written as a single token of `color/style/dash`
+fn format_stroke(stroke: Stroke) -> String {
+    let style = match stroke.style {
+        LineStyle::Thin => "thin".to_owned(),
+        LineStyle::ThinAA => "thin_aa".to_owned(),
+        LineStyle::Thick { width, hardness } => format!("thick:{}:{}", width, hardness),
+        LineStyle::ThickAA { width, hardness } => format!("thick_aa:{}:{}", width, hardness),
+    };
+
+    let dash = stroke.dash.map_or("-".to_owned(), |(on, off)| format!("{}:{}", on, off));
+
+    format!("{}/{}/{}", format_color(stroke.color), style, dash)
+}
+
+fn parse_stroke(token: &str) -> Result<Stroke, ParseError> {
+    let parts: Vec<&str> = token.split('/').collect();
+
+    if parts.len() != 3 {
+        return Err(invalid("stroke", token));
+    }
+
+    let color = parse_color(parts[0])?;
+
+    let style_parts: Vec<&str> = parts[1].splitn(2, ':').collect();
+
+    let params = || {
+        match style_parts.get(1).and_then(|p| parse_numbers(p, ':')) {
+            Some(ref n) if n.len() == 2 => Ok((n[0], n[1])),
+            _ => Err(invalid("line style", parts[1])),
+        }
+    };
+
+    let style = match style_parts[0] {
+        "thin" => LineStyle::Thin,
+        "thin_aa" => LineStyle::ThinAA,
+        "thick" => {
+            let (width, hardness) = params()?;
+            LineStyle::Thick { width: width, hardness: hardness }
+        }
+        "thick_aa" => {
+            let (width, hardness) = params()?;
+            LineStyle::ThickAA { width: width, hardness: hardness }
+        }
+        _ => return Err(invalid("line style", parts[1])),
+    };
+
+    let dash = if parts[2] == "-" {
+        None
+    } else {
+        match parse_numbers(parts[2], ':') {
+            Some(ref n) if n.len() == 2 => Some((n[0], n[1])),
+            _ => return Err(invalid("dash", parts[2])),
+        }
+    };
+
+    Ok(Stroke { color: color, style: style, dash: dash })
+}
+
+fn format_optional_stroke(stroke: Option<Stroke>) -> String {
+    stroke.map_or("-".to_owned(), format_stroke)
+}
+
+fn parse_optional_stroke(token: &str) -> Result<Option<Stroke>, ParseError> {
+    if token == "-" { Ok(None) } else { parse_stroke(token).map(Some) }
+}
+
+fn format_halign(align: HAlign) -> &'static str {
+    match align {
+        HAlign::Left => "left",
+        HAlign::Center => "center",
+        HAlign::Right => "right",
+    }
+}
+
+fn parse_halign(token: &str) -> Result<HAlign, ParseError> {
+    match token {
+        "left" => Ok(HAlign::Left),
+        "center" => Ok(HAlign::Center),
+        "right" => Ok(HAlign::Right),
+        _ => Err(invalid("alignment", token)),
+    }
+}
+
+fn format_valign(align: VAlign) -> &'static str {
+    match align {
+        VAlign::Top => "top",
+        VAlign::Middle => "middle",
+        VAlign::Baseline => "baseline",
+        VAlign::Bottom => "bottom",
+    }
+}
+
+fn parse_valign(token: &str) -> Result<VAlign, ParseError> {
+    match token {
+        "top" => Ok(VAlign::Top),
+        "middle" => Ok(VAlign::Middle),
+        "baseline" => Ok(VAlign::Baseline),
+        "bottom" => Ok(VAlign::Bottom),
+        _ => Err(invalid("alignment", token)),
+    }
+}
+
+/// Escapes backslashes, whitespace and control characters, so that text is always a single token
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ' ' => escaped.push_str("\\s"),
+            c if c.is_whitespace() || c.is_control() => write!(escaped, "\\u{{{:x}}}", c as u32).unwrap(),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some('s') => unescaped.push(' '),
+                Some('t') => unescaped.push('\t'),
+                Some('u') => {
+                    let rest = chars.as_str();
+
+                    let code = rest.find('}').filter(|_| rest.starts_with('{'))
+                        .and_then(|end| u32::from_str_radix(&rest[1..end], 16).ok().and_then(::std::char::from_u32).map(|c| (c, end)));
+
+                    match code {
+                        Some((c, end)) => {
+                            unescaped.push(c);
+                            chars = rest[end + 1..].chars();
+                        }
+                        None => unescaped.push('u'),
+                    }
+                }
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    unescaped
+}
@@ -0,0 +1,173 @@
+//! Color space conversion and quantization utilities
+
+use ::utils::clamp;
+
+/// Converts an sRGB encoded component in the range `[0, 1]` to linear light.
+///
+/// [https://en.wikipedia.org/wiki/SRGB](https://en.wikipedia.org/wiki/SRGB)
+///
+/// ```
+/// use rust_plot::color::srgb_to_linear;
+///
+/// assert_eq!(srgb_to_linear(1.0), 1.0);
+/// assert!((srgb_to_linear(0.5) - 0.214).abs() < 0.001);
+/// ```
+#[inline]
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light component in the range `[0, 1]` to the sRGB encoding.
+///
+/// ```
+/// use rust_plot::color::{linear_to_srgb, srgb_to_linear};
+///
+/// assert!((linear_to_srgb(srgb_to_linear(0.25)) - 0.25).abs() < 0.0001);
+/// ```
+#[inline]
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Dithering methods applied when quantizing components to 8 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    /// Plain rounding to the nearest value.
+    None,
+    /// Ordered dithering using a 4x4 Bayer threshold matrix.
+    ///
+    /// This is deterministic, so the same drawing always produces the same image.
+    Ordered,
+    /// Uniform random noise of one quantization step.
+    Random,
+}
+
+const BAYER_4X4: [[u8; 4]; 4] = [
+    [0, 8, 2, 10],
+    [12, 4, 14, 6],
+    [3, 11, 1, 9],
+    [15, 7, 13, 5],
+];
+
+impl Dither {
+    /// Offset, in quantization steps, added to the pixel at `(x, y)` before rounding.
+    ///
+    /// The offset is always within `[-0.5, 0.5)`.
+    #[inline]
+    pub fn offset(&self, x: u32, y: u32) -> f32 {
+        match *self {
+            Dither::None => 0.0,
+            Dither::Ordered => {
+                (BAYER_4X4[(y & 3) as usize][(x & 3) as usize] as f32 + 0.5) / 16.0 - 0.5
+            }
+            Dither::Random => ::rand::random::<f32>() - 0.5,
+        }
+    }
+}
+
+/// Quantizes a component in the range `[0, 1]` to 8 bits with rounding and clamping.
+///
+/// `dither` is an offset in quantization steps, as given by `Dither::offset`.
+///
+/// ```
+/// use rust_plot::color::quantize_u8;
+///
+/// assert_eq!(quantize_u8(0.999, 0.0), 255);
+/// assert_eq!(quantize_u8(1.5, 0.0), 255);
+/// assert_eq!(quantize_u8(-0.2, 0.0), 0);
+/// ```
+#[inline]
+pub fn quantize_u8(c: f32, dither: f32) -> u8 {
+    clamp((c * 255.0 + dither).round(), 0.0, 255.0) as u8
+}
+
+/// Composites a straight alpha RGBA color over another using the Porter-Duff "over" operator.
+///
+/// Both colors should be in linear light for physically correct results.
+pub fn blend_over_linear(top: [f32; 4], bottom: [f32; 4]) -> [f32; 4] {
+    fn over_component(x: f32, y: f32, a: f32, b: f32, alpha: f32) -> f32 {
+        if alpha > 0.0 {
+            (x * a + y * b * (1.0 - a)) / alpha
+        } else {
+            0.0
+        }
+    }
+
+    let top_alpha = top[3];
+    let bottom_alpha = bottom[3];
+
+    let alpha = top_alpha + bottom_alpha * (1.0 - top_alpha);
+
+    [
+        over_component(top[0], bottom[0], top_alpha, bottom_alpha, alpha),
+        over_component(top[1], bottom[1], top_alpha, bottom_alpha, alpha),
+        over_component(top[2], bottom[2], top_alpha, bottom_alpha, alpha),
+        alpha
+    ]
+}
+
+/// An sRGB encoded color with straight (non-premultiplied) alpha.
+///
+/// Components are in the range `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    /// Opaque color from sRGB components
+    pub fn rgb(r: f32, g: f32, b: f32) -> Color {
+        Color { r: r, g: g, b: b, a: 1.0 }
+    }
+
+    pub fn rgba(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color { r: r, g: g, b: b, a: a }
+    }
+
+    /// Color from 8-bit sRGB components
+    pub fn from_u8(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color::rgba(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+
+    pub fn black() -> Color { Color::rgb(0.0, 0.0, 0.0) }
+
+    pub fn white() -> Color { Color::rgb(1.0, 1.0, 1.0) }
+
+    pub fn transparent() -> Color { Color::rgba(0.0, 0.0, 0.0, 0.0) }
+
+    /// Same color with a different alpha
+    pub fn with_alpha(self, a: f32) -> Color {
+        Color { a: a, ..self }
+    }
+
+    /// Quantize the color to 8-bit components
+    ///
+    /// ```
+    /// use rust_plot::color::Color;
+    ///
+    /// assert_eq!(Color::rgb(1.0, 0.5, 0.0).to_u8(), [255, 128, 0, 255]);
+    /// ```
+    pub fn to_u8(&self) -> [u8; 4] {
+        [quantize_u8(self.r, 0.0), quantize_u8(self.g, 0.0), quantize_u8(self.b, 0.0), quantize_u8(self.a, 0.0)]
+    }
+
+    /// Convert the color components to linear light, leaving alpha untouched
+    pub fn to_linear(&self) -> [f32; 4] {
+        [srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a]
+    }
+}
+
+impl Default for Color {
+    fn default() -> Color { Color::black() }
+}
@@ -0,0 +1,265 @@
+//! Encapsulated PostScript vector output
+//!
+//! `EpsPlot` is a `Canvas` that writes a single page EPS file. Text uses the standard fonts,
+//! re-encoded to WinAnsi like in PDF output, with Greek letters and mathematical symbols taken from
+//! the Symbol font. Every font used is set up in the prolog, so changing the font only affects text
+//! drawn afterwards.
+//!
+//! PostScript has no notion of transparency, so the alpha of all colors is ignored.
+//! One pixel of the canvas is one PostScript point in the document.
+//!
+//! ```
+//! use rust_plot::canvas::{Canvas, TextStyle};
+//! use rust_plot::eps::EpsPlot;
+//! use rust_plot::vector::StandardFont;
+//!
+//! let mut plot = EpsPlot::new(300, 200);
+//!
+//! plot.push_clip_rect(10.0, 10.0, 290.0, 190.0);
+//! plot.push_clip_rect(20.0, 20.0, 280.0, 180.0);
+//! plot.text(30.0, 30.0, "−1.5", &TextStyle::new(12.0));
+//! plot.pop_clip();
+//! plot.set_font(StandardFont::Courier);
+//! plot.text(30.0, 50.0, "σ = 2", &TextStyle::new(12.0));
+//!
+//! let document = String::from_utf8(plot.document()).unwrap();
+//!
+//! assert!(document.lines().any(|line| line == "%%BoundingBox: 0 0 300 200"));
+//!
+//! // Negative numbers keep their sign, Greek letters come from the Symbol font
+//! assert!(document.contains("/Helvetica-WinAnsi findfont 12 scalefont setfont (-1.5) show"));
+//! assert!(document.contains("/Symbol findfont 12 scalefont setfont (s) show"));
+//!
+//! // Both text fonts are defined, not just the last one set
+//! assert!(document.contains("/Helvetica-WinAnsi /Helvetica findfont"));
+//! assert!(document.contains("/Courier-WinAnsi /Courier findfont"));
+//!
+//! // Clips left open are closed, so saving and restoring the graphics state is balanced
+//! assert_eq!(document.matches("gsave").count(), document.matches("grestore").count());
+//! ```
+
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use ::color::Color;
+use ::canvas::{Canvas, Stroke, TextStyle, TextExtent};
+use ::vector::{format_number, ellipse_beziers, string_literal, StandardFont};
+
+/// Canvas that records primitives as PostScript operators
+pub struct EpsPlot {
+    width: u32,
+    height: u32,
+    font: StandardFont,
+    /// Fonts used by text so far, which the prolog sets up
+    fonts: Vec<StandardFont>,
+    body: String,
+    clip_depth: usize,
+}
+
+fn point(x: f64, y: f64) -> String {
+    format!("{} {}", format_number(x), format_number(y))
+}
+
+/// Name of the font as set up by the prolog
+fn font_name(font: StandardFont) -> String {
+    match font {
+        StandardFont::Symbol => font.name().to_owned(),
+        _ => format!("{}-WinAnsi", font.name()),
+    }
+}
+
+/// Glyphs of the WinAnsi encoding at 128 to 159, where it differs from Latin-1
+const WIN_ANSI_HIGH: &'static str = "/Euro /.notdef /quotesinglbase /florin /quotedblbase /ellipsis /dagger /daggerdbl \
+    /circumflex /perthousand /Scaron /guilsinglleft /OE /.notdef /Zcaron /.notdef \
+    /.notdef /quoteleft /quoteright /quotedblleft /quotedblright /bullet /endash /emdash \
+    /tilde /trademark /scaron /guilsinglright /oe /.notdef /zcaron /Ydieresis";
+
+fn set_color(color: Color) -> String {
+    format!("{} {} {} setrgbcolor", format_number(color.r as f64), format_number(color.g as f64), format_number(color.b as f64))
+}
+
+impl EpsPlot {
+    /// Create a new document with a transparent background
+    pub fn new(width: u32, height: u32) -> EpsPlot {
+        EpsPlot {
+            width: width,
+            height: height,
+            font: StandardFont::Helvetica,
+            fonts: Vec::new(),
+            body: String::new(),
+            clip_depth: 0,
+        }
+    }
+
+    /// Create a new document filled by a background color
+    pub fn with_background(width: u32, height: u32, background: Color) -> EpsPlot {
+        let mut plot = EpsPlot::new(width, height);
+
+        plot.rect(0.0, 0.0, width as f64, height as f64, Some(background), None);
+
+        plot
+    }
+
+    /// Set the font used for text drawn from now on
+    pub fn set_font(&mut self, font: StandardFont) {
+        self.font = font;
+    }
+
+    fn set_stroke(&mut self, stroke: Stroke) {
+        writeln!(self.body, "{} {} setlinewidth 1 setlinejoin", set_color(stroke.color), format_number(stroke.width())).unwrap();
+
+        match stroke.dash {
+            Some((on, off)) => writeln!(self.body, "0 setlinecap [{} {}] 0 setdash", format_number(on), format_number(off)).unwrap(),
+            None => self.body.push_str("1 setlinecap [] 0 setdash\n"),
+        }
+    }
+
+    fn path(&mut self, contours: &[&[(f64, f64)]]) {
+        self.body.push_str("newpath\n");
+
+        for contour in contours {
+            for (i, &(x, y)) in contour.iter().enumerate() {
+                writeln!(self.body, "{} {}", point(x, y), if i == 0 { "moveto" } else { "lineto" }).unwrap();
+            }
+
+            if !contour.is_empty() {
+                self.body.push_str("closepath\n");
+            }
+        }
+    }
+
+    /// Paints the current path, preserving it between the fill and the stroke
+    fn paint(&mut self, fill: Option<Color>, stroke: Option<Stroke>) {
+        if let Some(color) = fill {
+            writeln!(self.body, "gsave {} fill grestore", set_color(color)).unwrap();
+        }
+
+        if let Some(stroke) = stroke {
+            self.set_stroke(stroke);
+            self.body.push_str("stroke\n");
+        }
+
+        self.body.push_str("newpath\n");
+    }
+
+    /// The complete EPS document
+    pub fn document(&self) -> Vec<u8> {
+        let mut document = String::new();
+
+        document.push_str("%!PS-Adobe-3.0 EPSF-3.0\n");
+        writeln!(document, "%%BoundingBox: 0 0 {} {}", self.width, self.height).unwrap();
+        document.push_str("%%Creator: rust-plot\n%%LanguageLevel: 2\n%%Pages: 1\n%%EndComments\n");
+
+        // Re-encode the text fonts with WinAnsi so the bytes written for text match, the Symbol font
+        // keeps its own encoding. WinAnsi is Latin-1 apart from a few codes.
+        document.push_str("%%BeginProlog\n");
+        writeln!(document, "/WinAnsiEncoding ISOLatin1Encoding dup length array copy def\n\
+                            WinAnsiEncoding 16#2D /hyphen put WinAnsiEncoding 16#27 /quotesingle put WinAnsiEncoding 16#60 /grave put\n\
+                            WinAnsiEncoding 16#80 [ {} ] putinterval", WIN_ANSI_HIGH).unwrap();
+
+        for &font in self.fonts.iter().filter(|&&f| f != StandardFont::Symbol) {
+            writeln!(document, "/{} /{} findfont dup length dict begin\n\
+                                {{ 1 index /FID ne {{ def }} {{ pop pop }} ifelse }} forall\n\
+                                /Encoding WinAnsiEncoding def currentdict end definefont pop", font_name(font), font.name()).unwrap();
+        }
+
+        document.push_str("%%EndProlog\n%%Page: 1 1\ngsave\n");
+
+        document.push_str(&self.body);
+
+        for _ in 0..self.clip_depth {
+            document.push_str("grestore\n");
+        }
+
+        document.push_str("grestore\nshowpage\n%%EOF\n");
+
+        // Text is escaped by `string_literal`, so the document is plain ASCII
+        document.into_bytes()
+    }
+
+    /// Write the EPS document to a writer
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.document())
+    }
+
+    /// Write the EPS document to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(&mut File::create(path)?)
+    }
+}
+
+impl Canvas for EpsPlot {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke) {
+        if points.len() < 2 { return; }
+
+        self.body.push_str("newpath\n");
+
+        for (i, &(x, y)) in points.iter().enumerate() {
+            writeln!(self.body, "{} {}", point(x, y), if i == 0 { "moveto" } else { "lineto" }).unwrap();
+        }
+
+        self.paint(None, Some(stroke));
+    }
+
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>) {
+        self.path(contours);
+        self.paint(fill, stroke);
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        let (start, curves) = ellipse_beziers(cx, cy, rx, ry);
+
+        writeln!(self.body, "newpath\n{} moveto", point(start.0, start.1)).unwrap();
+
+        for curve in &curves {
+            writeln!(self.body, "{} {} {} curveto", point(curve[0].0, curve[0].1),
+                     point(curve[1].0, curve[1].1), point(curve[2].0, curve[2].1)).unwrap();
+        }
+
+        self.body.push_str("closepath\n");
+        self.paint(fill, stroke);
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle) {
+        let extent = self.text_extent(text, style);
+        let (dx, dy) = extent.anchor_offset(style.anchor);
+
+        writeln!(self.body, "gsave\n{}\n{} translate {} rotate\n{} moveto", set_color(style.color), point(x, y),
+                 format_number(style.rotation.to_degrees()), point(dx, dy)).unwrap();
+
+        // Showing a string moves to its end, so the runs follow each other
+        for (font, bytes) in self.font.runs(text) {
+            if !self.fonts.contains(&font) {
+                self.fonts.push(font);
+            }
+
+            writeln!(self.body, "/{} findfont {} scalefont setfont {} show", font_name(font), format_number(style.size), string_literal(&bytes)).unwrap();
+        }
+
+        self.body.push_str("grestore\n");
+    }
+
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        self.font.extent(text, style.size)
+    }
+
+    fn push_clip_polygon(&mut self, points: &[(f64, f64)]) {
+        self.body.push_str("gsave\n");
+        self.path(&[points]);
+        self.body.push_str("clip newpath\n");
+        self.clip_depth += 1;
+    }
+
+    fn pop_clip(&mut self) {
+        if self.clip_depth > 0 {
+            self.body.push_str("grestore\n");
+            self.clip_depth -= 1;
+        }
+    }
+}
@@ -35,4 +35,294 @@ impl Intersection {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// An axis-aligned rectangle
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub x0: f64,
+    pub y0: f64,
+    pub x1: f64,
+    pub y1: f64,
+}
+
+impl Rect {
+    /// Create a rectangle between two corners, in any order
+    pub fn new(x0: f64, y0: f64, x1: f64, y1: f64) -> Rect {
+        let (x0, x1) = min_max(x0, x1);
+        let (y0, y1) = min_max(y0, y1);
+
+        Rect { x0: x0, y0: y0, x1: x1, y1: y1 }
+    }
+
+    /// Create a rectangle from its bottom left corner and size
+    pub fn with_size(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect::new(x, y, x + width, y + height)
+    }
+
+    pub fn width(&self) -> f64 { self.x1 - self.x0 }
+
+    pub fn height(&self) -> f64 { self.y1 - self.y0 }
+
+    pub fn center(&self) -> (f64, f64) {
+        ((self.x0 + self.x1) * 0.5, (self.y0 + self.y1) * 0.5)
+    }
+
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        self.x0 <= x && x <= self.x1 && self.y0 <= y && y <= self.y1
+    }
+
+    /// The overlapping part of two rectangles, if any
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let r = Rect {
+            x0: self.x0.max(other.x0),
+            y0: self.y0.max(other.y0),
+            x1: self.x1.min(other.x1),
+            y1: self.y1.min(other.y1),
+        };
+
+        if r.x0 <= r.x1 && r.y0 <= r.y1 { Some(r) } else { None }
+    }
+
+    /// Shrink the rectangle by the given amount on each side
+    pub fn inset(&self, left: f64, bottom: f64, right: f64, top: f64) -> Rect {
+        Rect::new(self.x0 + left, self.y0 + bottom, (self.x1 - right).max(self.x0 + left), (self.y1 - top).max(self.y0 + bottom))
+    }
+}
+
+/// Clips a line segment to a rectangle using the Liang–Barsky algorithm
+///
+/// Returns the visible part of the segment, or `None` if it lies entirely outside.
+///
+/// ```
+/// use rust_plot::geometry::{Rect, clip_line};
+///
+/// let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+///
+/// assert_eq!(clip_line(-5.0, 5.0, 15.0, 5.0, &rect), Some((0.0, 5.0, 10.0, 5.0)));
+/// assert_eq!(clip_line(-5.0, 20.0, 15.0, 20.0, &rect), None);
+/// ```
+pub fn clip_line(x0: f64, y0: f64, x1: f64, y1: f64, rect: &Rect) -> Option<(f64, f64, f64, f64)> {
+    let (dx, dy) = (x1 - x0, y1 - y0);
+
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+
+    // Each edge as (p, q) with the segment inside where p * t <= q
+    let edges = [
+        (-dx, x0 - rect.x0),
+        (dx, rect.x1 - x0),
+        (-dy, y0 - rect.y0),
+        (dy, rect.y1 - y0),
+    ];
+
+    for &(p, q) in &edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+
+    if t0 > t1 {
+        None
+    } else {
+        Some((x0 + t0 * dx, y0 + t0 * dy, x0 + t1 * dx, y0 + t1 * dy))
+    }
+}
+
+/// Clips a polyline to a rectangle, returning the visible pieces
+///
+/// ```
+/// use rust_plot::geometry::{Rect, clip_polyline};
+///
+/// let rect = Rect::new(0.0, 0.0, 10.0, 10.0);
+///
+/// // Leaves the rectangle through the top and comes back in
+/// let pieces = clip_polyline(&[(0.0, 5.0), (5.0, 15.0), (10.0, 5.0)], &rect);
+///
+/// assert_eq!(pieces, vec![vec![(0.0, 5.0), (2.5, 10.0)], vec![(7.5, 10.0), (10.0, 5.0)]]);
+/// ```
+pub fn clip_polyline(points: &[(f64, f64)], rect: &Rect) -> Vec<Vec<(f64, f64)>> {
+    let mut pieces = Vec::new();
+    let mut current: Vec<(f64, f64)> = Vec::new();
+
+    for w in points.windows(2) {
+        match clip_line(w[0].0, w[0].1, w[1].0, w[1].1, rect) {
+            Some((x0, y0, x1, y1)) => {
+                if current.last() != Some(&(x0, y0)) {
+                    if current.len() > 1 {
+                        pieces.push(current);
+                    }
+
+                    current = vec![(x0, y0)];
+                }
+
+                current.push((x1, y1));
+            }
+            None => {
+                if current.len() > 1 {
+                    pieces.push(::std::mem::replace(&mut current, Vec::new()));
+                } else {
+                    current.clear();
+                }
+            }
+        }
+    }
+
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+
+    pieces
+}
+
+/// Whether a polygon is convex, turning the same way at every corner and winding around only once
+///
+/// Repeated points and straight corners are allowed, and so are degenerate polygons of fewer than three points.
+///
+/// ```
+/// use rust_plot::geometry::is_convex;
+///
+/// assert!(is_convex(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (1.0, 2.0), (0.0, 2.0)]));
+/// assert!(!is_convex(&[(0.0, 0.0), (2.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 2.0)]));
+///
+/// let pentagram: Vec<(f64, f64)> = (0..5).map(|i| (i as f64 * 4.0 * ::std::f64::consts::PI / 5.0).sin_cos()).collect();
+/// assert!(!is_convex(&pentagram));
+/// ```
+pub fn is_convex(points: &[(f64, f64)]) -> bool {
+    let edges: Vec<(f64, f64)> = points.iter().zip(points.iter().cycle().skip(1))
+        .map(|(&(x0, y0), &(x1, y1))| (x1 - x0, y1 - y0))
+        .filter(|&(dx, dy)| dx != 0.0 || dy != 0.0)
+        .collect();
+
+    let mut sign = 0.0;
+    let mut turning = 0.0;
+
+    for (&(ax, ay), &(bx, by)) in edges.iter().zip(edges.iter().cycle().skip(1)) {
+        let cross = ax * by - ay * bx;
+
+        if cross * sign < 0.0 {
+            return false;
+        }
+
+        if cross != 0.0 {
+            sign = cross.signum();
+        }
+
+        turning += cross.atan2(ax * bx + ay * by);
+    }
+
+    // Polygons winding around more than once, like a pentagram, also turn the same way at every corner
+    turning.abs() < 3.0 * ::std::f64::consts::PI
+}
+
+/// Clips a polygon to a convex clipping polygon using the Sutherland–Hodgman algorithm
+///
+/// The subject polygon may be concave, in which case the result can contain zero-width edges along the clip
+/// boundary, which don't affect filling. The clipping polygon may wind in either direction.
+pub fn clip_polygon(subject: &[(f64, f64)], clip: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    if clip.len() < 3 {
+        return Vec::new();
+    }
+
+    // Signed area tells which side of each clip edge is the inside
+    let area: f64 = clip.iter().zip(clip.iter().cycle().skip(1))
+        .map(|(&(x0, y0), &(x1, y1))| x0 * y1 - x1 * y0).sum();
+
+    let orientation = if area < 0.0 { -1.0 } else { 1.0 };
+
+    let mut output = subject.to_vec();
+
+    for (&(cx0, cy0), &(cx1, cy1)) in clip.iter().zip(clip.iter().cycle().skip(1)) {
+        if output.is_empty() {
+            break;
+        }
+
+        let side = |x: f64, y: f64| orientation * ((cx1 - cx0) * (y - cy0) - (cy1 - cy0) * (x - cx0));
+
+        let input = output;
+        output = Vec::with_capacity(input.len() + 2);
+
+        let mut previous = input[input.len() - 1];
+
+        for &current in &input {
+            let (s0, s1) = (side(previous.0, previous.1), side(current.0, current.1));
+
+            if (s0 >= 0.0) != (s1 >= 0.0) {
+                let t = s0 / (s0 - s1);
+
+                output.push((previous.0 + t * (current.0 - previous.0), previous.1 + t * (current.1 - previous.1)));
+            }
+
+            if s1 >= 0.0 {
+                output.push(current);
+            }
+
+            previous = current;
+        }
+    }
+
+    output
+}
+
+/// Clips a line segment to a convex polygon using the Cyrus–Beck algorithm
+///
+/// Returns the visible part of the segment, or `None` if it lies entirely outside. The polygon may wind in
+/// either direction.
+///
+/// ```
+/// use rust_plot::geometry::clip_line_to_polygon;
+///
+/// let diamond = [(5.0, 0.0), (10.0, 5.0), (5.0, 10.0), (0.0, 5.0)];
+///
+/// assert_eq!(clip_line_to_polygon(-5.0, 5.0, 15.0, 5.0, &diamond), Some((0.0, 5.0, 10.0, 5.0)));
+/// assert_eq!(clip_line_to_polygon(0.0, 0.0, 1.0, 1.0, &diamond), None);
+/// ```
+pub fn clip_line_to_polygon(x0: f64, y0: f64, x1: f64, y1: f64, clip: &[(f64, f64)]) -> Option<(f64, f64, f64, f64)> {
+    if clip.len() < 3 {
+        return None;
+    }
+
+    let area: f64 = clip.iter().zip(clip.iter().cycle().skip(1))
+        .map(|(&(x0, y0), &(x1, y1))| x0 * y1 - x1 * y0).sum();
+
+    let orientation = if area < 0.0 { -1.0 } else { 1.0 };
+
+    let (dx, dy) = (x1 - x0, y1 - y0);
+
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+
+    for (&(cx0, cy0), &(cx1, cy1)) in clip.iter().zip(clip.iter().cycle().skip(1)) {
+        // Distance to the inside of the edge at the start of the segment, and how fast it changes along it
+        let start = orientation * ((cx1 - cx0) * (y0 - cy0) - (cy1 - cy0) * (x0 - cx0));
+        let rate = orientation * ((cx1 - cx0) * dy - (cy1 - cy0) * dx);
+
+        if rate == 0.0 {
+            if start < 0.0 {
+                return None;
+            }
+        } else {
+            let t = -start / rate;
+
+            if rate > 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+
+    if t0 > t1 {
+        None
+    } else {
+        Some((x0 + t0 * dx, y0 + t0 * dy, x0 + t1 * dx, y0 + t1 * dy))
+    }
+}
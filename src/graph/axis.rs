@@ -0,0 +1,364 @@
+//! Axis scales, ticks and their labels
+
+use std::ops::Range;
+
+use super::range::AutoRange;
+
+/// Upper limit on the number of major or minor ticks, so a huge tick count or a range too narrow for its
+/// magnitude can't allocate without bound
+pub const MAX_TICKS: usize = 10_000;
+
+/// How values are spaced along an axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scale {
+    Linear,
+    /// Logarithmic with base 10, for positive values only
+    Log,
+}
+
+impl Scale {
+    /// Position of a value along the axis, in units that are evenly spaced on the canvas
+    ///
+    /// Values outside of a logarithmic scale's domain become NaN or negative infinity.
+    #[inline]
+    pub fn apply(&self, value: f64) -> f64 {
+        match *self {
+            Scale::Linear => value,
+            Scale::Log => value.log10(),
+        }
+    }
+
+    /// The value at a position along the axis, undoing `apply`
+    #[inline]
+    pub fn invert(&self, position: f64) -> f64 {
+        match *self {
+            Scale::Linear => position,
+            Scale::Log => 10f64.powf(position),
+        }
+    }
+
+    /// Positions of the ends of a domain along the axis
+    ///
+    /// A logarithmic scale can't show zero or negative values, so a domain starting at them is cut off
+    /// three decades below its end.
+    pub fn apply_domain(&self, domain: &Range<f64>) -> Range<f64> {
+        match *self {
+            Scale::Linear => domain.clone(),
+            Scale::Log => {
+                let (start, end) = (domain.start.min(domain.end), domain.start.max(domain.end));
+
+                let end = if end > 0.0 { end } else { 1.0 };
+                let start = if start > 0.0 { start } else { end * 1e-3 };
+
+                if domain.start <= domain.end {
+                    start.log10()..end.log10()
+                } else {
+                    end.log10()..start.log10()
+                }
+            }
+        }
+    }
+}
+
+/// Settings for one axis of a graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis {
+    /// Label drawn along the axis, which may contain markup from `text::markup`
+    pub label: Option<String>,
+    /// Number of major ticks to aim for, the actual number depends on where round values fall
+    pub tick_count: usize,
+    pub scale: Scale,
+    /// Whether to draw tick marks between the major ones
+    pub show_minor_ticks: bool,
+    /// How the domain is fitted to the data, or `None` to keep the domain it was given
+    pub auto_range: Option<AutoRange>,
+    /// Names of the categories at `0, 1, 2, …`, which replace the numeric ticks when set
+    pub categories: Option<Vec<String>>,
+}
+
+impl Axis {
+    pub fn new() -> Axis {
+        Axis { label: None, tick_count: 6, scale: Scale::Linear, show_minor_ticks: false, auto_range: None, categories: None }
+    }
+
+    /// Positions of the major ticks within the domain
+    pub fn ticks(&self, domain: &Range<f64>) -> Vec<f64> {
+        if let Some(ref categories) = self.categories {
+            let (start, end) = (domain.start.min(domain.end), domain.start.max(domain.end));
+
+            return (0..categories.len()).map(|i| i as f64).filter(|&i| start <= i && i <= end).collect();
+        }
+
+        match self.scale {
+            Scale::Linear => ticks(domain, self.tick_count),
+            Scale::Log => log_ticks(domain, self.tick_count),
+        }
+    }
+
+    /// Positions of the minor ticks within the domain, leaving out those at major ticks
+    ///
+    /// Axes with categories don't have minor ticks.
+    pub fn minor_ticks(&self, domain: &Range<f64>) -> Vec<f64> {
+        if self.categories.is_some() {
+            return Vec::new();
+        }
+
+        match self.scale {
+            Scale::Linear => minor_ticks(domain, self.tick_count),
+            Scale::Log => log_minor_ticks(domain, self.tick_count),
+        }
+    }
+
+    /// Labels for the given major ticks, as markup
+    pub fn tick_labels(&self, ticks: &[f64]) -> Vec<String> {
+        if let Some(ref categories) = self.categories {
+            return ticks.iter().map(|&t| categories.get(t as usize).cloned().unwrap_or_default()).collect();
+        }
+
+        let powers_of_ten = ticks.iter().all(|&t| t > 0.0 && t.log10() == t.log10().round());
+
+        if self.scale == Scale::Log && powers_of_ten && ticks.len() > 1 {
+            return ticks.iter().map(|&t| format!("10^{{{}}}", t.log10().round()).replace('-', "−")).collect();
+        }
+
+        let step = if ticks.len() > 1 { ticks[1] - ticks[0] } else { ticks.first().map_or(1.0, |t| t.abs()) };
+
+        ticks.iter().map(|&t| format_tick(t, step)).collect()
+    }
+}
+
+impl Default for Axis {
+    fn default() -> Axis {
+        Axis::new()
+    }
+}
+
+/// Picks a step of 1, 2 or 5 times a power of ten that divides `span` into about `count` parts
+///
+/// An empty span gets a step of 1, and a span that isn't finite has no step, so it gets NaN.
+///
+/// ```
+/// use rust_plot::graph::axis::tick_step;
+///
+/// assert_eq!(tick_step(10.0, 5), 2.0);
+/// assert_eq!(tick_step(0.7, 6), 0.1);
+/// assert!(tick_step(::std::f64::INFINITY, 6).is_nan());
+/// ```
+pub fn tick_step(span: f64, count: usize) -> f64 {
+    if !span.is_finite() {
+        return ::std::f64::NAN;
+    }
+
+    let raw = span.abs() / count.max(1) as f64;
+
+    if raw == 0.0 {
+        return 1.0;
+    }
+
+    let magnitude = 10f64.powf(raw.log10().floor());
+
+    let step = match raw / magnitude {
+        r if r < 1.5 => 1.0,
+        r if r < 3.0 => 2.0,
+        r if r < 7.0 => 5.0,
+        _ => 10.0,
+    };
+
+    step * magnitude
+}
+
+/// Positions of ticks at multiples of a round step within the range, in increasing order
+///
+/// Ranges too wide to measure get no ticks, and at most `MAX_TICKS` ticks are placed from the start.
+///
+/// ```
+/// use rust_plot::graph::axis::{ticks, MAX_TICKS};
+/// use std::f64::MAX;
+///
+/// assert_eq!(ticks(&(-0.3..1.0), 5), vec![-0.2, 0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+/// assert!(ticks(&(-MAX..MAX), 5).is_empty());
+/// assert_eq!(ticks(&(0.0..1.0), usize::max_value()).len(), MAX_TICKS);
+/// ```
+pub fn ticks(range: &Range<f64>, count: usize) -> Vec<f64> {
+    let (start, end) = (range.start.min(range.end), range.start.max(range.end));
+
+    if !start.is_finite() || !end.is_finite() {
+        return Vec::new();
+    }
+
+    if start == end {
+        return vec![start];
+    }
+
+    let step = tick_step(end - start, count);
+
+    if !step.is_finite() {
+        return Vec::new();
+    }
+
+    // Small tolerance so ticks right at the ends survive rounding errors
+    let epsilon = step * 1e-9;
+
+    let first = ((start - epsilon) / step).ceil() as i64;
+    let last = (((end + epsilon) / step).floor() as i64).min(first.saturating_add(MAX_TICKS as i64 - 1));
+
+    // Rounding to the precision of the step removes tails like 0.30000000000000004
+    let factor = 10f64.powi((-step.log10().floor()).max(0.0) as i32);
+
+    (first..last + 1).map(|i| (i as f64 * step * factor).round() / factor).collect()
+}
+
+/// Positions of minor ticks dividing the steps between the ticks from `ticks` into four or five parts
+///
+/// Like `ticks`, ranges too wide to measure get none and there are at most `MAX_TICKS` of them.
+///
+/// ```
+/// use rust_plot::graph::axis::minor_ticks;
+///
+/// assert_eq!(minor_ticks(&(0.0..1.0), 2), vec![0.1, 0.2, 0.3, 0.4, 0.6, 0.7, 0.8, 0.9]);
+/// assert!(minor_ticks(&(-::std::f64::MAX..::std::f64::MAX), 2).is_empty());
+/// ```
+pub fn minor_ticks(range: &Range<f64>, count: usize) -> Vec<f64> {
+    let (start, end) = (range.start.min(range.end), range.start.max(range.end));
+
+    if !start.is_finite() || !end.is_finite() || start == end {
+        return Vec::new();
+    }
+
+    let step = tick_step(end - start, count);
+
+    if !step.is_finite() {
+        return Vec::new();
+    }
+
+    // Steps of 2 divide evenly into quarters, the others into fifths
+    let mantissa = (step / 10f64.powf(step.log10().floor())).round();
+    let parts = if mantissa == 2.0 { 4 } else { 5 };
+
+    let minor = step / parts as f64;
+    let epsilon = minor * 1e-9;
+
+    let first = ((start - epsilon) / minor).ceil() as i64;
+    let last = (((end + epsilon) / minor).floor() as i64).min(first.saturating_add(MAX_TICKS as i64 - 1));
+
+    let factor = 10f64.powi((-minor.log10().floor()).max(0.0) as i32);
+
+    (first..last + 1).filter(|i| i % parts != 0).map(|i| (i as f64 * minor * factor).round() / factor).collect()
+}
+
+/// Positions of ticks along a logarithmic axis
+///
+/// Ticks are placed at powers of ten, skipping some to stay near `count` when the range covers many decades.
+/// Ranges spanning less than a decade or so get round values spaced like on a linear axis instead.
+///
+/// ```
+/// use rust_plot::graph::axis::log_ticks;
+///
+/// assert_eq!(log_ticks(&(0.5..2000.0), 6), vec![1.0, 10.0, 100.0, 1000.0]);
+/// assert_eq!(log_ticks(&(1e-8..1.0), 4), vec![1e-8, 1e-6, 1e-4, 1e-2, 1.0]);
+/// ```
+pub fn log_ticks(range: &Range<f64>, count: usize) -> Vec<f64> {
+    let range = Scale::Log.apply_domain(range);
+    let (start, end) = (range.start.min(range.end), range.start.max(range.end));
+
+    if !start.is_finite() || !end.is_finite() {
+        return Vec::new();
+    }
+
+    let first = (start - 1e-9).ceil() as i32;
+    let last = (end + 1e-9).floor() as i32;
+
+    if last - first < 1 {
+        return ticks(&(10f64.powf(start)..10f64.powf(end)), count);
+    }
+
+    let decades = ((last - first) as f64 / count.max(1) as f64).ceil().max(1.0) as i32;
+
+    // Keep the ticks on multiples of the decade step, so they include 10^0
+    let first = (first as f64 / decades as f64).ceil() as i32 * decades;
+
+    (0..).map(|i| first + i * decades).take_while(|&e| e <= last).map(|e| 10f64.powi(e)).collect()
+}
+
+/// Positions of minor ticks along a logarithmic axis, at 2 to 9 times each power of ten
+///
+/// When the major ticks skip decades, the skipped powers of ten become the minor ticks instead.
+pub fn log_minor_ticks(range: &Range<f64>, count: usize) -> Vec<f64> {
+    let major = log_ticks(range, count);
+
+    let range = Scale::Log.apply_domain(range);
+    let (start, end) = (range.start.min(range.end), range.start.max(range.end));
+
+    if !start.is_finite() || !end.is_finite() {
+        return Vec::new();
+    }
+
+    let (first, last) = ((start - 1e-9).floor() as i32, (end + 1e-9).ceil() as i32);
+
+    if last - first < 1 || major.len() < 2 {
+        return minor_ticks(&(10f64.powf(start)..10f64.powf(end)), count);
+    }
+
+    let (lo, hi) = (10f64.powf(start) * (1.0 - 1e-9), 10f64.powf(end) * (1.0 + 1e-9));
+
+    let within = |v: &f64| lo <= *v && *v <= hi;
+
+    if major[1] / major[0] > 10.5 {
+        (first..last + 1).map(|e| 10f64.powi(e)).filter(within)
+            .filter(|v| !major.iter().any(|m| (m / v - 1.0).abs() < 1e-9)).collect()
+    } else {
+        (first..last).flat_map(|e| (2..10).map(move |m| m as f64 * 10f64.powi(e))).filter(within).collect()
+    }
+}
+
+/// Formats a tick value as markup, with just enough decimals to tell apart ticks `step` apart
+///
+/// Ticks with very large or small steps are written in scientific notation.
+///
+/// ```
+/// use rust_plot::graph::axis::format_tick;
+///
+/// assert_eq!(format_tick(0.25, 0.05), "0.25");
+/// assert_eq!(format_tick(-3.0, 1.0), "−3");
+/// assert_eq!(format_tick(2.5e6, 5e5), "2.5×10^{6}");
+/// assert_eq!(format_tick(1e6, 5e5), "10^{6}");
+/// ```
+pub fn format_tick(value: f64, step: f64) -> String {
+    if !value.is_finite() {
+        return format!("{}", value);
+    }
+
+    let step_exponent = step.abs().log10().floor() as i32;
+
+    let text = if step_exponent >= 5 || step_exponent <= -4 {
+        if value == 0.0 {
+            return "0".to_string();
+        }
+
+        let exponent = value.abs().log10().floor() as i32;
+        let decimals = (exponent - step_exponent).max(0) as usize;
+
+        let mantissa = format!("{:.*}", decimals, value.abs() / 10f64.powi(exponent));
+        let exponent = format!("{}", exponent).replace('-', "−");
+
+        match trim_zeros(&mantissa) {
+            "1" => format!("10^{{{}}}", exponent),
+            mantissa => format!("{}×10^{{{}}}", mantissa, exponent),
+        }
+    } else {
+        let decimals = (-step_exponent).max(0) as usize;
+
+        format!("{:.*}", decimals, value.abs())
+    };
+
+    if value < 0.0 { format!("−{}", text) } else { text }
+}
+
+/// Removes trailing zeros after a decimal point
+fn trim_zeros(text: &str) -> &str {
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        text
+    }
+}
@@ -0,0 +1,155 @@
+//! Bar charts over categories
+//!
+//! Bar series hold one value per category, and the categories sit at the whole numbers `0, 1, 2, …` of the
+//! category axis, which is labeled with their names. When a graph renders several bar series, they are placed
+//! side by side within each category or stacked on top of each other, following its `BarOptions`. Stacked
+//! bars keep positive and negative values apart, each growing away from zero. As their places depend on each
+//! other, bar series are only drawn when the graph renders, after all of them have been added.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::bar::{BarMode, Orientation};
+//! use rust_plot::graph::series::Series;
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.set_categories(&["north", "east", "south", "west"], Orientation::Vertical);
+//! graph.bar_options_mut().mode = BarMode::Stacked;
+//!
+//! graph.add(Series::bars(vec![3.0, 5.0, 2.0, 4.0], Color::rgb(0.2, 0.4, 0.8), None)).label("2016");
+//! graph.add(Series::bars(vec![1.0, -2.0, 2.5, 3.0], Color::rgb(0.9, 0.5, 0.1), None)).label("2017");
+//!
+//! graph.render();
+//!
+//! assert_eq!((graph.x_domain(), graph.y_domain()), (-0.5..3.5, -4.0..8.0));
+//! ```
+
+use ::canvas::Canvas;
+use ::geometry::Rect;
+
+use super::Graph;
+use super::series::{Series, SeriesKind};
+
+/// How the bar series of a graph share the categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarMode {
+    /// Next to each other, splitting the width of a category between them
+    Grouped,
+    /// On top of each other, using the whole width of a category
+    Stacked,
+}
+
+/// Direction the bars grow in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Categories along the x axis, values along the y axis
+    Vertical,
+    /// Categories along the y axis, values along the x axis
+    Horizontal,
+}
+
+impl Orientation {
+    /// Graph coordinates of a position along the category axis and a value
+    pub fn point(&self, category: f64, value: f64) -> (f64, f64) {
+        match *self {
+            Orientation::Vertical => (category, value),
+            Orientation::Horizontal => (value, category),
+        }
+    }
+}
+
+/// Placement of bars within their categories
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BarOptions {
+    pub mode: BarMode,
+    pub orientation: Orientation,
+    /// Part of a category covered by its bars, the rest is the space between categories
+    pub width: f64,
+    /// Space between grouped bars, as a part of the width of a bar
+    pub gap: f64,
+}
+
+impl BarOptions {
+    /// Vertical grouped bars covering 80% of their category, without gaps between them
+    pub fn new() -> BarOptions {
+        BarOptions { mode: BarMode::Grouped, orientation: Orientation::Vertical, width: 0.8, gap: 0.0 }
+    }
+
+    /// Rectangles of the bars of every bar series among the given ones, in graph coordinates
+    ///
+    /// There is one list of rectangles for every bar series, in order, leaving out values that aren't finite.
+    pub fn layout(&self, series: &[Series]) -> Vec<Vec<Rect>> {
+        let bars: Vec<&[f64]> = series.iter().filter_map(|s| match s.kind {
+            SeriesKind::Bars { ref values, .. } => Some(&values[..]),
+            _ => None,
+        }).collect();
+
+        let categories = bars.iter().fold(0, |n, values| n.max(values.len()));
+
+        // Tops of the positive and bottoms of the negative stacks so far
+        let mut positive = vec![0.0; categories];
+        let mut negative = vec![0.0; categories];
+
+        let slots = match self.mode {
+            BarMode::Grouped => bars.len().max(1),
+            BarMode::Stacked => 1,
+        };
+
+        let slot = self.width / slots as f64;
+        let inset = slot * self.gap.max(0.0).min(1.0) * 0.5;
+
+        bars.iter().enumerate().map(|(i, values)| {
+            let index = match self.mode {
+                BarMode::Grouped => i,
+                BarMode::Stacked => 0,
+            };
+
+            values.iter().enumerate().filter(|&(_, v)| v.is_finite()).map(|(c, &value)| {
+                let start = c as f64 - self.width * 0.5 + slot * index as f64 + inset;
+                let end = start + slot - inset * 2.0;
+
+                let (base, top) = match self.mode {
+                    BarMode::Grouped => (0.0, value),
+                    BarMode::Stacked => {
+                        let stack = if value < 0.0 { &mut negative[c] } else { &mut positive[c] };
+                        let base = *stack;
+
+                        *stack += value;
+
+                        (base, *stack)
+                    }
+                };
+
+                match self.orientation {
+                    Orientation::Vertical => Rect::new(start, base, end, top),
+                    Orientation::Horizontal => Rect::new(base, start, top, end),
+                }
+            }).collect()
+        }).collect()
+    }
+}
+
+impl Default for BarOptions {
+    fn default() -> BarOptions {
+        BarOptions::new()
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    pub fn bar_options(&self) -> &BarOptions { &self.bar_options }
+
+    pub fn bar_options_mut(&mut self) -> &mut BarOptions { &mut self.bar_options }
+
+    /// Name the categories on the axis an orientation puts them on, the x axis for vertical bars, boxes and
+    /// violins, and the y axis for horizontal ones
+    pub fn set_categories(&mut self, names: &[&str], orientation: Orientation) {
+        let names = Some(names.iter().map(|name| name.to_string()).collect());
+
+        match orientation {
+            Orientation::Vertical => self.x_axis.categories = names,
+            Orientation::Horizontal => self.y_axis.categories = names,
+        }
+    }
+}
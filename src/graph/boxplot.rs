@@ -0,0 +1,207 @@
+//! Box plots and violin plots comparing groups of data
+//!
+//! Every group is drawn at its category, `0, 1, 2, …` along the category axis, like bars are. A box spans the
+//! quartiles with a line at the median, and whiskers reach out to the furthest values within Tukey's fences,
+//! beyond which values are drawn as outliers. A violin mirrors the density estimate of its group on both
+//! sides of the category, over the range of the data. Unlike bars, several series of boxes or violins are
+//! not placed side by side, so a narrow box can be drawn inside a violin by adding it after the violin.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::bar::Orientation;
+//! use rust_plot::graph::boxplot::{BoxStyle, ViolinStyle};
+//! use rust_plot::graph::series::Series;
+//! use rust_plot::stat::kde::{Bandwidth, Kernel};
+//!
+//! let groups: Vec<Vec<f64>> = (1..4).map(|g| {
+//!     (0..100).map(|i| ((i * 37 % 100) as f64 / 100.0 - 0.5).powi(3) * 8.0 * g as f64 + g as f64).collect()
+//! }).collect();
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.set_categories(&["control", "low dose", "high dose"], Orientation::Vertical);
+//!
+//! graph.add(Series::violins(&groups, Kernel::Gaussian, Bandwidth::Silverman, ViolinStyle::new(Color::rgb(0.7, 0.8, 0.95))));
+//! graph.add(Series::boxes(&groups, BoxStyle::new(Some(Color::white())).with_width(0.15)));
+//!
+//! graph.render();
+//!
+//! assert_eq!(graph.x_domain(), -0.5..2.5);
+//! ```
+
+use ::canvas::{Canvas, Stroke};
+use ::color::Color;
+use ::plot::LineStyle;
+use ::stat::kde::{Bandwidth, Kde, Kernel};
+use ::stat::summary::{BoxStats, finite_sorted};
+
+use super::{Graph, spaced};
+use super::bar::Orientation;
+use super::legend::LegendEntry;
+use super::marker::{Marker, MarkerStyle};
+use super::series::{Series, SeriesKind};
+
+/// Number of points along each side of a violin
+pub const VIOLIN_SAMPLES: usize = 100;
+
+/// How the boxes of a box plot are drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxStyle {
+    /// Width of a box, as a part of its category
+    pub width: f64,
+    pub fill: Option<Color>,
+    /// Stroke of the boxes, whiskers and caps
+    pub stroke: Stroke,
+    pub median: Stroke,
+    /// Length of the caps at the ends of the whiskers, as a part of the width of the box
+    pub caps: f64,
+    /// Markers of the outliers, or `None` to leave them out
+    pub outliers: Option<MarkerStyle>,
+    /// Narrow the box to the confidence interval of the median, so boxes whose notches don't overlap likely
+    /// have different medians
+    pub notch: bool,
+    pub orientation: Orientation,
+}
+
+impl BoxStyle {
+    /// Vertical boxes half as wide as their category, outlined in black with hollow circles as outliers
+    pub fn new(fill: Option<Color>) -> BoxStyle {
+        BoxStyle {
+            width: 0.5,
+            fill: fill,
+            stroke: Stroke::new(Color::black(), LineStyle::Thin),
+            median: Stroke::new(Color::black(), LineStyle::ThickAA { width: 2.0, hardness: 4.0 }),
+            caps: 0.5,
+            outliers: Some(MarkerStyle::outlined(Marker::Circle, 5.0, LineStyle::ThinAA)),
+            notch: false,
+            orientation: Orientation::Vertical,
+        }
+    }
+
+    pub fn with_width(self, width: f64) -> BoxStyle {
+        BoxStyle { width: width, ..self }
+    }
+
+    pub fn with_notch(self) -> BoxStyle {
+        BoxStyle { notch: true, ..self }
+    }
+
+    /// Same style with boxes lying along the x axis, for categories on the y axis
+    pub fn horizontal(self) -> BoxStyle {
+        BoxStyle { orientation: Orientation::Horizontal, ..self }
+    }
+
+    /// Outline of a box at a category in graph coordinates, notched if the style says so
+    ///
+    /// Notches are cut off at the quartiles, which the confidence interval of the median can reach past for
+    /// small groups.
+    ///
+    /// ```
+    /// use rust_plot::graph::boxplot::BoxStyle;
+    /// use rust_plot::stat::summary::BoxStats;
+    ///
+    /// let stats = BoxStats::new(&[1.0, 2.0, 4.0, 8.0]).unwrap();
+    /// let outline = BoxStyle::new(None).with_notch().outline(0.0, &stats);
+    ///
+    /// assert!(stats.notch().0 < stats.q1 && stats.notch().1 > stats.q3);
+    /// assert!(outline.iter().all(|&(_, v)| stats.q1 <= v && v <= stats.q3));
+    /// ```
+    pub fn outline(&self, category: f64, stats: &BoxStats) -> Vec<(f64, f64)> {
+        let half = self.width * 0.5;
+        let (start, end) = (category - half, category + half);
+
+        let values: Vec<(f64, f64)> = if self.notch {
+            let (low, high) = stats.notch();
+            let (low, high) = (low.max(stats.q1), high.min(stats.q3));
+            let inset = half * 0.5;
+
+            vec![
+                (start, stats.q1), (end, stats.q1), (end, low), (end - inset, stats.median), (end, high),
+                (end, stats.q3), (start, stats.q3), (start, high), (start + inset, stats.median), (start, low),
+            ]
+        } else {
+            vec![(start, stats.q1), (end, stats.q1), (end, stats.q3), (start, stats.q3)]
+        };
+
+        values.into_iter().map(|(c, v)| self.orientation.point(c, v)).collect()
+    }
+}
+
+/// How the violins of a violin plot are drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViolinStyle {
+    /// Width of a violin at its widest, as a part of its category
+    pub width: f64,
+    pub fill: Color,
+    pub stroke: Option<Stroke>,
+    pub orientation: Orientation,
+}
+
+impl ViolinStyle {
+    /// Vertical violins as wide as 80% of their category, outlined in a thin black line
+    pub fn new(fill: Color) -> ViolinStyle {
+        ViolinStyle { width: 0.8, fill: fill, stroke: Some(LineStyle::ThinAA.into()), orientation: Orientation::Vertical }
+    }
+
+    pub fn with_width(self, width: f64) -> ViolinStyle {
+        ViolinStyle { width: width, ..self }
+    }
+
+    /// Same style with violins lying along the x axis, for categories on the y axis
+    pub fn horizontal(self) -> ViolinStyle {
+        ViolinStyle { orientation: Orientation::Horizontal, ..self }
+    }
+}
+
+impl Series {
+    /// A box for every group of data, at the categories in order
+    pub fn boxes<G: AsRef<[f64]>>(groups: &[G], style: BoxStyle) -> Series {
+        let stats = groups.iter().map(|group| BoxStats::new(group.as_ref())).collect();
+
+        Series { kind: SeriesKind::Boxes { stats: stats, style: style }, label: None }
+    }
+
+    /// A violin for every group of data, at the categories in order, from density estimates with the given
+    /// kernel and bandwidth
+    ///
+    /// Every violin is scaled to the width of the style at its widest.
+    pub fn violins<G: AsRef<[f64]>>(groups: &[G], kernel: Kernel, bandwidth: Bandwidth, style: ViolinStyle) -> Series {
+        let outlines = groups.iter().map(|group| {
+            let sorted = finite_sorted(group.as_ref());
+            let kde = Kde::new(&sorted, kernel, bandwidth);
+
+            let range = match (sorted.first(), sorted.last()) {
+                (Some(&min), Some(&max)) if min < max => min..max,
+                (Some(_), _) => kde.support(),
+                _ => return Vec::new(),
+            };
+
+            let curve: Vec<(f64, f64)> = spaced(&range, VIOLIN_SAMPLES).into_iter().map(|v| (v, kde.density(v))).collect();
+            let peak = curve.iter().fold(0.0, |peak: f64, p| peak.max(p.1));
+
+            let scale = if peak > 0.0 { style.width * 0.5 / peak } else { 0.0 };
+
+            curve.into_iter().map(|(v, d)| (v, d * scale)).collect()
+        }).collect();
+
+        Series { kind: SeriesKind::Violins { outlines: outlines, style: style }, label: None }
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Draw a box for every group of data, at the categories in order
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn boxplot<G: AsRef<[f64]>>(&mut self, groups: &[G], style: BoxStyle) -> &mut LegendEntry {
+        self.draw_series(&Series::boxes(groups, style))
+    }
+
+    /// Draw a violin for every group of data, at the categories in order
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn violins<G: AsRef<[f64]>>(&mut self, groups: &[G], kernel: Kernel, bandwidth: Bandwidth, style: ViolinStyle) -> &mut LegendEntry {
+        self.draw_series(&Series::violins(groups, kernel, bandwidth, style))
+    }
+}
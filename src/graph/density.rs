@@ -0,0 +1,139 @@
+//! Density curves of data
+//!
+//! The estimate comes from `stat::kde`, this draws it as a line or a filled area. A density curve can stand
+//! on its own or go over a histogram of the same data, scaled to match the normalization of the histogram.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::density::DensityStyle;
+//! use rust_plot::graph::histogram::HistogramStyle;
+//! use rust_plot::graph::series::Series;
+//! use rust_plot::plot::LineStyle;
+//! use rust_plot::stat::bins::{Bins, Histogram, Normalization};
+//! use rust_plot::stat::kde::{Bandwidth, Kde, Kernel};
+//!
+//! let data: Vec<f64> = (1..400).map(|i| (i as f64 / 400.0 * 6.0 - 3.0).tanh() * 2.0 + 5.0).collect();
+//!
+//! let histogram = Histogram::new(&data, &Bins::Scott, Normalization::Count);
+//! let kde = Kde::new(&data, Kernel::Gaussian, Bandwidth::Silverman);
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.add(Series::density_over(&kde, &histogram, 200, DensityStyle::Line(LineStyle::ThinAA.into()))).label("density");
+//! graph.add(Series::from_histogram(histogram, HistogramStyle::filled(Color::rgb(0.4, 0.6, 0.9))));
+//!
+//! graph.render();
+//! ```
+
+use ::canvas::{Canvas, Stroke};
+use ::color::Color;
+use ::stat::bins::{Histogram, Normalization};
+use ::stat::kde::{Bandwidth, Kde, Kernel};
+use ::utils::clamp;
+
+use super::Graph;
+use super::legend::LegendEntry;
+use super::series::Series;
+
+/// Number of points a density curve is drawn through by `Graph::density`
+pub const DENSITY_SAMPLES: usize = 200;
+
+/// How a density curve is drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DensityStyle {
+    Line(Stroke),
+    /// The area below the curve filled, with an optional stroke along the curve
+    Filled(Color, Option<Stroke>),
+}
+
+impl Series {
+    /// Density curve of an estimate, through evenly spaced samples over its support
+    pub fn density(kde: &Kde, samples: usize, style: DensityStyle) -> Series {
+        Series::density_curve(kde.curve(samples), style)
+    }
+
+    /// Density curve of an estimate, scaled to go over a histogram of the same data
+    ///
+    /// Over counts, the density is multiplied by the number of values counted and the width of the bin under
+    /// every point, so over bins of unequal widths the curve steps at their edges, following the counts expected
+    /// in each bin. Over a cumulative histogram, the curve is the integral of the density.
+    ///
+    /// ```
+    /// use rust_plot::graph::density::DensityStyle;
+    /// use rust_plot::graph::series::Series;
+    /// use rust_plot::plot::LineStyle;
+    /// use rust_plot::stat::bins::{Bins, Histogram, Normalization};
+    /// use rust_plot::stat::kde::{Bandwidth, Kde, Kernel};
+    ///
+    /// // Evenly spread values, with a bin twice as wide as the other
+    /// let data: Vec<f64> = (0..300).map(|i| (i as f64 + 0.5) / 100.0).collect();
+    ///
+    /// let histogram = Histogram::new(&data, &Bins::Edges(vec![0.0, 1.0, 3.0]), Normalization::Count);
+    /// let kde = Kde::new(&data, Kernel::Epanechnikov, Bandwidth::Fixed(0.2));
+    ///
+    /// let series = Series::density_over(&kde, &histogram, 200, DensityStyle::Line(LineStyle::Thin.into()));
+    ///
+    /// // The wide bin expects 200 values, as many as it counts
+    /// assert_eq!(histogram.counts, vec![100, 200]);
+    /// assert!((series.extent().1.max - 200.0).abs() < 2.0);
+    /// ```
+    pub fn density_over(kde: &Kde, histogram: &Histogram, samples: usize, style: DensityStyle) -> Series {
+        let mut curve = kde.curve(samples);
+
+        match histogram.normalization {
+            Normalization::Density => {}
+            Normalization::Count => {
+                let total = histogram.counts.iter().sum::<usize>() as f64;
+                let edges = &histogram.edges;
+
+                for point in &mut curve {
+                    // Points beyond the outer edges take the width of the outer bins
+                    let width = if edges.len() < 2 {
+                        1.0
+                    } else {
+                        let i = clamp(edges.iter().take_while(|&&edge| edge <= point.0).count(), 1, edges.len() - 1);
+
+                        edges[i] - edges[i - 1]
+                    };
+
+                    point.1 *= total * width;
+                }
+            }
+            Normalization::Cumulative => {
+                let mut sum = 0.0;
+                let mut previous = None;
+
+                for point in &mut curve {
+                    if let Some((x, y)) = previous {
+                        sum += (point.0 - x) * (point.1 + y) * 0.5;
+                    }
+
+                    previous = Some(*point);
+                    point.1 = sum;
+                }
+            }
+        }
+
+        Series::density_curve(curve, style)
+    }
+
+    fn density_curve(curve: Vec<(f64, f64)>, style: DensityStyle) -> Series {
+        match style {
+            DensityStyle::Line(stroke) => Series::line(curve, stroke),
+            DensityStyle::Filled(fill, stroke) => Series::area(curve, fill, stroke),
+        }
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Draw the density curve of data, estimated with the given kernel and bandwidth
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn density(&mut self, data: &[f64], kernel: Kernel, bandwidth: Bandwidth, style: DensityStyle) -> &mut LegendEntry {
+        let kde = Kde::new(data, kernel, bandwidth);
+
+        self.draw_series(&Series::density(&kde, DENSITY_SAMPLES, style))
+    }
+}
@@ -0,0 +1,127 @@
+//! Error bars and confidence bands
+//!
+//! Error bars show the uncertainty of single points as lines reaching from the point to the bounds of its
+//! value, along x, y or both, with caps across their ends. Confidence bands show the uncertainty of a curve
+//! as the filled area between a lower and an upper bound, below the lines drawn over it.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::errorbar::{ErrorBarStyle, Uncertainty};
+//! use rust_plot::graph::marker::{Marker, MarkerStyle};
+//! use rust_plot::graph::series::Series;
+//! use rust_plot::plot::LineStyle;
+//!
+//! let points = vec![(1.0, 2.1), (2.0, 3.9), (3.0, 6.2), (4.0, 7.8)];
+//! let errors = vec![Uncertainty::Symmetric(0.4), Uncertainty::Symmetric(0.3), Uncertainty::Asymmetric(0.5, 1.0), Uncertainty::Symmetric(0.6)];
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.add(Series::band((0..41).map(|i| {
+//!     let x = 0.5 + i as f64 * 0.1;
+//!
+//!     (x, 2.0 * x - 0.5, 2.0 * x + 0.5)
+//! }).collect(), Color::rgba(0.2, 0.4, 0.9, 0.3), None)).label("95% band");
+//!
+//! let style = ErrorBarStyle::new(LineStyle::Thin).with_marker(MarkerStyle::new(Marker::Circle, 6.0, Color::black()));
+//! graph.add(Series::error_bars(points, Vec::new(), errors, style)).label("measured");
+//!
+//! graph.render();
+//!
+//! assert_eq!(graph.y_domain(), 0.0..10.0);
+//! ```
+
+use ::canvas::{Canvas, Stroke};
+use ::color::Color;
+
+use super::Graph;
+use super::legend::LegendEntry;
+use super::marker::MarkerStyle;
+use super::series::{Series, SeriesKind};
+
+/// How far the true value of a point may lie from it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Uncertainty {
+    /// The same distance below and above the value
+    Symmetric(f64),
+    /// A distance below and a distance above the value
+    Asymmetric(f64, f64),
+}
+
+impl Uncertainty {
+    /// Lower and upper bound around a value
+    pub fn bounds(&self, value: f64) -> (f64, f64) {
+        match *self {
+            Uncertainty::Symmetric(e) => (value - e.abs(), value + e.abs()),
+            Uncertainty::Asymmetric(below, above) => (value - below.abs(), value + above.abs()),
+        }
+    }
+}
+
+/// How error bars are drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorBarStyle {
+    pub stroke: Stroke,
+    /// Length of the caps across the ends of the bars in pixels, zero to leave them out
+    pub caps: f64,
+    /// Marker drawn at the points themselves, or `None` for the bars only
+    pub marker: Option<MarkerStyle>,
+}
+
+impl ErrorBarStyle {
+    /// Bars with caps 6 pixels long and no markers
+    pub fn new<S: Into<Stroke>>(stroke: S) -> ErrorBarStyle {
+        ErrorBarStyle { stroke: stroke.into(), caps: 6.0, marker: None }
+    }
+
+    pub fn with_caps(self, caps: f64) -> ErrorBarStyle {
+        ErrorBarStyle { caps: caps, ..self }
+    }
+
+    pub fn with_marker(self, marker: MarkerStyle) -> ErrorBarStyle {
+        ErrorBarStyle { marker: Some(marker), ..self }
+    }
+}
+
+impl Series {
+    /// Points with error bars along x and y
+    ///
+    /// Either list of uncertainties may be empty or shorter than the points, points without an uncertainty
+    /// along an axis get no bar along it.
+    pub fn error_bars(points: Vec<(f64, f64)>, x_errors: Vec<Uncertainty>, y_errors: Vec<Uncertainty>, style: ErrorBarStyle) -> Series {
+        Series { kind: SeriesKind::ErrorBars { points: points, x_errors: x_errors, y_errors: y_errors, style: style }, label: None }
+    }
+
+    /// A band filled between lower and upper bounds, given as `(x, lower, upper)`, with an optional stroke
+    /// along both bounds
+    pub fn band(points: Vec<(f64, f64, f64)>, fill: Color, stroke: Option<Stroke>) -> Series {
+        let lower = points.iter().map(|&(x, lower, _)| (x, lower)).collect();
+        let upper = points.iter().map(|&(x, _, upper)| (x, upper)).collect();
+
+        Series::fill_between(lower, upper, fill, stroke)
+    }
+
+    /// The area between two lines filled, pairing their points in order, with an optional stroke along both
+    ///
+    /// The lines may be sampled at different positions, as long as they have the same number of points.
+    pub fn fill_between(lower: Vec<(f64, f64)>, upper: Vec<(f64, f64)>, fill: Color, stroke: Option<Stroke>) -> Series {
+        Series { kind: SeriesKind::Band { lower: lower, upper: upper, fill: fill, stroke: stroke }, label: None }
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Draw points with error bars along x and y, see `Series::error_bars`
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn error_bars(&mut self, points: &[(f64, f64)], x_errors: &[Uncertainty], y_errors: &[Uncertainty], style: ErrorBarStyle) -> &mut LegendEntry {
+        self.draw_series(&Series::error_bars(points.to_vec(), x_errors.to_vec(), y_errors.to_vec(), style))
+    }
+
+    /// Draw a band filled between lower and upper bounds, given as `(x, lower, upper)`
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn band(&mut self, points: &[(f64, f64, f64)], fill: Color, stroke: Option<Stroke>) -> &mut LegendEntry {
+        self.draw_series(&Series::band(points.to_vec(), fill, stroke))
+    }
+}
@@ -0,0 +1,116 @@
+//! Grid lines behind the data of a graph
+//!
+//! Major grid lines run across the plot area at the major ticks of each axis, and minor grid lines at the
+//! minor ticks in between. Both follow the scale of their axis, so a logarithmic axis gets its lines bunched
+//! towards each power of ten. Draw the grid before the series, so the data stays on top.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::canvas::Stroke;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::axis::Scale;
+//! use rust_plot::graph::rectangular::RectangularGraph;
+//! use rust_plot::plot::LineStyle;
+//!
+//! let mut graph = Graph::with_plotter(BufferPlot::new(400, 300), 0.0..4.0, 1.0..1e4);
+//!
+//! graph.y_axis_mut().scale = Scale::Log;
+//! graph.grid_mut().minor = Some(Stroke::new(Color::rgb(0.9, 0.9, 0.9), LineStyle::Thin).dashed(2.0, 2.0));
+//!
+//! graph.draw_grid();
+//! graph.draw_axis(LineStyle::Thin);
+//! graph.linear_equation(100, LineStyle::ThinAA, |x| 10f64.powf(x));
+//! ```
+
+use ::canvas::{Canvas, Stroke};
+use ::color::Color;
+use ::plot::LineStyle;
+
+use super::Graph;
+
+/// Strokes of the grid lines and the axes they are drawn for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid {
+    /// Stroke of the lines at major ticks, or `None` to leave them out
+    pub major: Option<Stroke>,
+    /// Stroke of the lines at minor ticks, or `None` to leave them out
+    pub minor: Option<Stroke>,
+    /// Whether to draw vertical lines at the ticks of the x axis
+    pub x: bool,
+    /// Whether to draw horizontal lines at the ticks of the y axis
+    pub y: bool,
+    /// Whether `Graph::render` draws the grid, calling `Graph::draw_grid` draws it either way
+    pub visible: bool,
+}
+
+impl Grid {
+    /// Light gray major lines along both axes, without minor lines, hidden when rendering
+    pub fn new() -> Grid {
+        Grid {
+            major: Some(Stroke::new(Color::rgb(0.85, 0.85, 0.85), LineStyle::Thin)),
+            minor: None,
+            x: true,
+            y: true,
+            visible: false,
+        }
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Grid {
+        Grid::new()
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    pub fn grid(&self) -> &Grid { &self.grid }
+
+    pub fn grid_mut(&mut self) -> &mut Grid { &mut self.grid }
+
+    pub fn set_grid(&mut self, grid: Grid) {
+        self.grid = grid;
+    }
+
+    /// Draw the grid lines across the plot area, minor lines first so major lines cross over them
+    pub fn draw_grid(&mut self) {
+        let layout = self.layout();
+        let mapping = layout.mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale);
+
+        let area = layout.plot_area;
+        let grid = self.grid;
+
+        let layers = [
+            (grid.minor, self.x_axis.minor_ticks(&self.x_domain), self.y_axis.minor_ticks(&self.y_domain)),
+            (grid.major, layout.x_ticks.clone(), layout.y_ticks.clone()),
+        ];
+
+        for &(stroke, ref x_ticks, ref y_ticks) in &layers {
+            let stroke = match stroke {
+                Some(stroke) => stroke,
+                None => continue,
+            };
+
+            if grid.x {
+                for &tick in x_ticks {
+                    let x = mapping.map_x(tick).round();
+
+                    // Lines on the frame would only thicken it
+                    if x > area.x0 && x < area.x1 {
+                        self.plotter.line(x, area.y0, x, area.y1, stroke);
+                    }
+                }
+            }
+
+            if grid.y {
+                for &tick in y_ticks {
+                    let y = mapping.map_y(tick).round();
+
+                    if y > area.y0 && y < area.y1 {
+                        self.plotter.line(area.x0, y, area.x1, y, stroke);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Histograms of data
+//!
+//! The binning comes from `stat::bins`, this draws the binned values as adjacent bars or as the outline
+//! stepping along their tops.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::histogram::HistogramStyle;
+//! use rust_plot::graph::series::Series;
+//! use rust_plot::plot::LineStyle;
+//! use rust_plot::stat::bins::{Bins, Histogram, Normalization};
+//!
+//! let data: Vec<f64> = (0..500).map(|i| ((i * 7919) % 1000) as f64 / 100.0).collect();
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.add(Series::histogram(&data, Bins::FreedmanDiaconis, HistogramStyle::filled(Color::rgb(0.4, 0.6, 0.9))));
+//!
+//! let cumulative = Histogram::new(&data, &Bins::Width(0.5), Normalization::Cumulative);
+//! graph.add(Series::from_histogram(cumulative, HistogramStyle::Step(LineStyle::Thin.into()))).label("cumulative");
+//!
+//! graph.render();
+//! ```
+
+use ::canvas::{Canvas, Stroke};
+use ::color::Color;
+use ::plot::LineStyle;
+use ::stat::bins::{Bins, Histogram, Normalization};
+
+use super::Graph;
+use super::legend::LegendEntry;
+use super::series::{Series, SeriesKind};
+
+/// How the bins of a histogram are drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistogramStyle {
+    /// A filled bar for every bin, with an optional outline around each
+    Bars(Color, Option<Stroke>),
+    /// A line along the tops of the bins, down to zero at both ends
+    Step(Stroke),
+}
+
+impl HistogramStyle {
+    /// Filled bars outlined in a slightly darker color, so neighboring bins stay apart
+    pub fn filled(color: Color) -> HistogramStyle {
+        let edge = Color::rgba(color.r * 0.7, color.g * 0.7, color.b * 0.7, color.a);
+
+        HistogramStyle::Bars(color, Some(Stroke::new(edge, LineStyle::Thin)))
+    }
+}
+
+impl Series {
+    /// Histogram counting the data in the given bins
+    pub fn histogram(data: &[f64], bins: Bins, style: HistogramStyle) -> Series {
+        Series::from_histogram(Histogram::new(data, &bins, Normalization::Count), style)
+    }
+
+    /// Histogram of already binned data, with any normalization
+    pub fn from_histogram(histogram: Histogram, style: HistogramStyle) -> Series {
+        Series { kind: SeriesKind::Histogram { histogram: histogram, style: style }, label: None }
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Draw a histogram counting the data in the given bins
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn histogram(&mut self, data: &[f64], bins: Bins, style: HistogramStyle) -> &mut LegendEntry {
+        self.draw_series(&Series::histogram(data, bins, style))
+    }
+}
@@ -0,0 +1,203 @@
+//! Placing the plot area and the decorations of a graph
+//!
+//! The margins around the plot area are sized from the text drawn in them. Along the bottom they hold
+//! the tick marks, the x tick labels and the x axis label, along the left side the same for the y axis,
+//! with the label rotated to read upwards, and along the top the title. A legend placed outside of the
+//! plot area takes room from the right or bottom edge.
+
+use std::ops::Range;
+
+use ::canvas::{Canvas, TextExtent};
+use ::geometry::Rect;
+use ::text::markup;
+use ::transform::Affine2;
+
+use super::Graph;
+use super::axis::Scale;
+use super::legend::LegendPosition;
+
+/// Size of the title relative to the other labels
+pub const TITLE_SCALE: f64 = 1.25;
+/// Length of the tick marks relative to the font size
+pub const TICK_LENGTH: f64 = 0.5;
+/// Space between the parts of the margins relative to the font size
+pub const GAP: f64 = 0.4;
+
+/// Positions of the parts of a graph on its canvas
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    /// Area the domains are mapped onto, with its edges on whole pixels
+    pub plot_area: Rect,
+    /// Area inside the padding that holds the plot area, title and labels
+    pub bounds: Rect,
+    /// Position of a legend placed outside of the plot area
+    pub legend: Option<Rect>,
+    /// Length of the tick marks in pixels
+    pub tick_length: f64,
+    /// Space between the tick marks, tick labels and axis labels in pixels
+    pub gap: f64,
+    pub x_ticks: Vec<f64>,
+    pub y_ticks: Vec<f64>,
+    pub x_tick_labels: Vec<String>,
+    pub y_tick_labels: Vec<String>,
+}
+
+/// Mapping from graph coordinates to canvas coordinates
+///
+/// Values are first placed along their axis by its scale, then moved onto the plot area by an affine transform.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mapping {
+    /// Transform from scaled graph coordinates to canvas coordinates
+    pub transform: Affine2,
+    pub x_scale: Scale,
+    pub y_scale: Scale,
+}
+
+impl Mapping {
+    /// Map a point onto the canvas, giving coordinates that aren't finite for values a scale can't show
+    #[inline]
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        self.transform.apply(self.x_scale.apply(x), self.y_scale.apply(y))
+    }
+
+    /// Horizontal canvas position of an x value
+    #[inline]
+    pub fn map_x(&self, x: f64) -> f64 {
+        self.apply(x, 1.0).0
+    }
+
+    /// Vertical canvas position of a y value
+    #[inline]
+    pub fn map_y(&self, y: f64) -> f64 {
+        self.apply(1.0, y).1
+    }
+}
+
+impl Layout {
+    /// Mapping of the given domains onto the plot area
+    pub fn mapping(&self, x_domain: &Range<f64>, y_domain: &Range<f64>, x_scale: Scale, y_scale: Scale) -> Mapping {
+        let (sx, ex) = axis_mapping(&x_scale.apply_domain(x_domain), self.plot_area.x0, self.plot_area.x1);
+        let (sy, ey) = axis_mapping(&y_scale.apply_domain(y_domain), self.plot_area.y0, self.plot_area.y1);
+
+        Mapping {
+            transform: Affine2::new(sx, 0.0, 0.0, sy, ex, ey),
+            x_scale: x_scale,
+            y_scale: y_scale,
+        }
+    }
+}
+
+/// Scale and offset mapping a domain onto the pixels from `start` to `end`
+///
+/// An empty domain is widened by half a unit on either side.
+fn axis_mapping(domain: &Range<f64>, start: f64, end: f64) -> (f64, f64) {
+    let (d0, d1) = if domain.start == domain.end {
+        (domain.start - 0.5, domain.end + 0.5)
+    } else {
+        (domain.start, domain.end)
+    };
+
+    let scale = (end - start) / (d1 - d0);
+
+    (scale, start - d0 * scale)
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Measure the title and labels and place the plot area between them
+    pub fn layout(&self) -> Layout {
+        let (width, height) = self.plotter.dimensions();
+
+        let mut bounds = Rect::new(0.0, 0.0, width as f64, height as f64).inset(self.padding, self.padding, self.padding, self.padding);
+
+        let label_style = self.label_style();
+
+        let tick_length = self.font_size * TICK_LENGTH;
+        let gap = self.font_size * GAP;
+
+        let legend_size = if self.legend.position.is_inside() {
+            (0.0, 0.0)
+        } else {
+            self.legend.size(&self.plotter, &label_style)
+        };
+
+        if legend_size.0 > 0.0 {
+            match self.legend.position {
+                LegendPosition::OutsideRight => bounds.x1 = (bounds.x1 - legend_size.0 - gap).max(bounds.x0),
+                _ => bounds.y0 = (bounds.y0 + legend_size.1 + gap).min(bounds.y1),
+            }
+        }
+
+        let x_ticks = self.x_axis.ticks(&self.x_domain);
+        let y_ticks = self.y_axis.ticks(&self.y_domain);
+
+        let x_tick_labels = self.x_axis.tick_labels(&x_ticks);
+        let y_tick_labels = self.y_axis.tick_labels(&y_ticks);
+
+        let measure = |labels: &[String]| -> Vec<TextExtent> {
+            labels.iter().map(|label| markup::extent(&self.plotter, label, &label_style)).collect()
+        };
+
+        let x_tick_extents = measure(&x_tick_labels);
+        let y_tick_extents = measure(&y_tick_labels);
+
+        let x_tick_height = x_tick_extents.iter().fold(0.0f64, |h, e| h.max(e.height()));
+        let y_tick_width = y_tick_extents.iter().fold(0.0f64, |w, e| w.max(e.width));
+
+        let mut left = bounds.x0 + tick_length + gap + y_tick_width;
+        let mut bottom = bounds.y0 + tick_length + gap + x_tick_height;
+        let mut right = bounds.x1;
+        let mut top = bounds.y1;
+
+        if let Some(ref label) = self.y_axis.label {
+            left += markup::extent(&self.plotter, label, &label_style).height() + gap;
+        }
+
+        if let Some(ref label) = self.x_axis.label {
+            bottom += markup::extent(&self.plotter, label, &label_style).height() + gap;
+        }
+
+        if let Some(ref title) = self.title {
+            top -= markup::extent(&self.plotter, title, &self.title_style()).height() + gap;
+        }
+
+        // Labels of the ticks at the ends of the axes are centered on them, so half of them sticks out
+        if let Some(first) = x_tick_extents.first() {
+            left = left.max(bounds.x0 + first.width * 0.5);
+        }
+
+        if let Some(last) = x_tick_extents.last() {
+            right = right.min(bounds.x1 - last.width * 0.5);
+        }
+
+        if let Some(last) = y_tick_extents.last() {
+            top = top.min(bounds.y1 - last.height() * 0.5);
+        }
+
+        let (left, bottom) = (left.round(), bottom.round());
+
+        let plot_area = Rect::new(left, bottom, right.round().max(left + 1.0), top.round().max(bottom + 1.0));
+
+        let legend = if legend_size.0 > 0.0 {
+            let (w, h) = legend_size;
+
+            Some(match self.legend.position {
+                LegendPosition::OutsideRight => Rect::with_size(bounds.x1 + gap, plot_area.y1 - h, w, h),
+                _ => Rect::with_size(plot_area.center().0 - w * 0.5, bounds.y0 - gap - h, w, h),
+            })
+        } else {
+            None
+        };
+
+        Layout {
+            plot_area: plot_area,
+            bounds: bounds,
+            legend: legend,
+            tick_length: tick_length,
+            gap: gap,
+            x_ticks: x_ticks,
+            y_ticks: y_ticks,
+            x_tick_labels: x_tick_labels,
+            y_tick_labels: y_tick_labels,
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! Legends naming the series of a graph
+//!
+//! Every series drawn onto a graph registers an entry in its legend, with a swatch showing how the series
+//! is drawn. Only entries that are given a label appear in the legend box. Lines show a short stroke, filled
+//! series a patch of their fill, and scatter plots a single marker of the style from `graph::marker`.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::legend::Swatch;
+//! use rust_plot::graph::marker::{Marker, MarkerStyle};
+//! use rust_plot::graph::rectangular::RectangularGraph;
+//! use rust_plot::plot::LineStyle;
+//!
+//! let mut graph = Graph::with_plotter(BufferPlot::new(400, 300), 0.0..6.0, -1.0..1.0);
+//!
+//! let marker = MarkerStyle::new(Marker::Circle, 6.0, Color::rgb(0.8, 0.2, 0.1));
+//!
+//! graph.draw_axis(LineStyle::Thin);
+//! graph.linear_equation(200, LineStyle::ThinAA, f64::sin).label("sin x");
+//! graph.linear_equation(200, LineStyle::ThinAA, f64::cos);
+//! graph.scatter(&[(1.0, 0.5), (3.0, -0.5)], marker).label("samples");
+//! graph.draw_legend();
+//!
+//! assert_eq!(graph.legend().entries()[0].label, Some("sin x".to_string()));
+//! assert_eq!(graph.legend().entries()[1].label, None);
+//! assert_eq!(graph.legend().entries()[2].swatch, Swatch::Marker(marker));
+//! ```
+
+use ::canvas::{Canvas, Stroke, TextExtent, TextStyle, VAlign, HAlign};
+use ::color::Color;
+use ::geometry::{Rect, clip_line};
+use ::plot::LineStyle;
+use ::text::markup;
+
+use super::Graph;
+use super::marker::{MarkerStyle, draw_marker};
+
+/// Width of the swatches relative to the font size
+const SWATCH_WIDTH: f64 = 2.0;
+
+/// How a series is drawn, shown next to its label
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Swatch {
+    Line(Stroke),
+    /// A filled area with an optional outline
+    Fill(Color, Option<Stroke>),
+    /// A single marker, drawn no larger than the label text
+    Marker(MarkerStyle),
+}
+
+/// A series in the legend
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendEntry {
+    /// Name of the series, which may contain markup from `text::markup`, or `None` to leave it out of the legend
+    pub label: Option<String>,
+    pub swatch: Swatch,
+}
+
+impl LegendEntry {
+    /// Set the label of the series, to show it in the legend
+    pub fn label(&mut self, label: &str) -> &mut LegendEntry {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Replace the swatch shown for the series
+    pub fn swatch(&mut self, swatch: Swatch) -> &mut LegendEntry {
+        self.swatch = swatch;
+        self
+    }
+}
+
+/// Where the legend box is placed
+///
+/// The outside positions take room from the plot area when the layout is computed, so every labeled entry
+/// has to be known before anything is drawn. `Graph::render` takes care of that for added series, series drawn
+/// directly need their entries added with `Legend::add` up front.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendPosition {
+    /// Inside the plot area, in the corner covering the least data
+    Best,
+    UpperRight,
+    UpperLeft,
+    LowerLeft,
+    LowerRight,
+    /// To the right of the plot area, which is narrowed to make room
+    OutsideRight,
+    /// Below the x axis label, with the plot area shortened to make room
+    OutsideBottom,
+}
+
+impl LegendPosition {
+    /// Returns true for the positions inside the plot area
+    pub fn is_inside(&self) -> bool {
+        match *self {
+            LegendPosition::OutsideRight | LegendPosition::OutsideBottom => false,
+            _ => true,
+        }
+    }
+}
+
+/// Entries and placement of the legend of a graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Legend {
+    pub position: LegendPosition,
+    /// Fill color of the box, usually partially transparent so data behind it still shows
+    pub background: Option<Color>,
+    pub frame: Option<Stroke>,
+    entries: Vec<LegendEntry>,
+}
+
+impl Legend {
+    pub fn new() -> Legend {
+        Legend {
+            position: LegendPosition::Best,
+            background: Some(Color::rgba(1.0, 1.0, 1.0, 0.8)),
+            frame: Some(Stroke::new(Color::rgb(0.6, 0.6, 0.6), LineStyle::Thin)),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Register a series, returning its entry so it can be labeled
+    pub fn add(&mut self, swatch: Swatch) -> &mut LegendEntry {
+        self.entries.push(LegendEntry { label: None, swatch: swatch });
+        self.entries.last_mut().unwrap()
+    }
+
+    /// All registered entries, in the order the series were drawn
+    pub fn entries(&self) -> &[LegendEntry] {
+        &self.entries
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns true if there are no labeled entries to show
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(|entry| entry.label.is_none())
+    }
+
+    /// Size of the legend box drawn on the given canvas with the given label style
+    pub fn size<C: Canvas + ?Sized>(&self, canvas: &C, style: &TextStyle) -> (f64, f64) {
+        let rows = self.rows(canvas, style);
+
+        if rows.is_empty() {
+            return (0.0, 0.0);
+        }
+
+        let gap = style.size * super::layout::GAP;
+
+        let label_width = rows.iter().fold(0.0f64, |w, &(_, e)| w.max(e.width));
+        let height: f64 = rows.iter().map(|&(_, e)| row_height(e, style)).sum();
+
+        (gap * 3.0 + style.size * SWATCH_WIDTH + label_width, gap * (rows.len() + 1) as f64 + height)
+    }
+
+    /// Labeled entries with the measured extents of their labels
+    fn rows<C: Canvas + ?Sized>(&self, canvas: &C, style: &TextStyle) -> Vec<(&LegendEntry, TextExtent)> {
+        self.entries.iter().filter_map(|entry| {
+            entry.label.as_ref().map(|label| (entry, markup::extent(canvas, label, style)))
+        }).collect()
+    }
+
+    /// Draw the legend box with its top left corner at the given point
+    pub fn draw<C: Canvas + ?Sized>(&self, canvas: &mut C, x: f64, y: f64, style: &TextStyle) {
+        let (width, height) = self.size(canvas, style);
+
+        if width == 0.0 {
+            return;
+        }
+
+        if self.background.is_some() || self.frame.is_some() {
+            canvas.rect(x, y - height, x + width, y, self.background, self.frame);
+        }
+
+        let gap = style.size * super::layout::GAP;
+        let swatch_width = style.size * SWATCH_WIDTH;
+
+        let label_style = style.with_anchor(HAlign::Left, VAlign::Middle);
+
+        let mut top = y - gap;
+
+        for (entry, extent) in self.rows(canvas, style) {
+            let h = row_height(extent, style);
+            let cy = top - h * 0.5;
+            let (x0, x1) = (x + gap, x + gap + swatch_width);
+
+            match entry.swatch {
+                Swatch::Line(stroke) => canvas.line(x0, cy, x1, cy, stroke),
+                Swatch::Fill(color, stroke) => {
+                    let half = style.size * 0.35;
+
+                    canvas.rect(x0, cy - half, x1, cy + half, Some(color), stroke);
+                }
+                Swatch::Marker(marker) => {
+                    let marker = marker.with_size(marker.size.min(style.size));
+
+                    draw_marker(canvas, (x0 + x1) * 0.5, cy, &marker);
+                }
+            }
+
+            if let Some(ref label) = entry.label {
+                markup::draw(canvas, x1 + gap, cy, label, &label_style);
+            }
+
+            top -= h + gap;
+        }
+    }
+}
+
+impl Default for Legend {
+    fn default() -> Legend {
+        Legend::new()
+    }
+}
+
+/// Height of a legend row, enough for its label and its swatch
+fn row_height(extent: TextExtent, style: &TextStyle) -> f64 {
+    extent.height().max(style.size)
+}
+
+/// Total length of the drawn lines inside a rectangle, with every isolated point counting as one pixel
+fn coverage(footprint: &[Vec<(f64, f64)>], rect: &Rect) -> f64 {
+    let mut total = 0.0;
+
+    for points in footprint {
+        if points.len() == 1 {
+            if rect.contains(points[0].0, points[0].1) {
+                total += 1.0;
+            }
+
+            continue;
+        }
+
+        for w in points.windows(2) {
+            if let Some((x0, y0, x1, y1)) = clip_line(w[0].0, w[0].1, w[1].0, w[1].1, rect) {
+                total += (x1 - x0).hypot(y1 - y0);
+            }
+        }
+    }
+
+    total
+}
+
+impl<P: Canvas> Graph<P> {
+    pub fn legend(&self) -> &Legend { &self.legend }
+
+    pub fn legend_mut(&mut self) -> &mut Legend { &mut self.legend }
+
+    pub fn set_legend_position(&mut self, position: LegendPosition) {
+        self.legend.position = position;
+    }
+
+    /// Bounds of the legend box on the canvas, or `None` if there is nothing to show
+    pub fn legend_rect(&self) -> Option<Rect> {
+        let layout = self.layout();
+
+        if let Some(rect) = layout.legend {
+            return Some(rect);
+        }
+
+        let (width, height) = self.legend.size(&self.plotter, &self.label_style());
+
+        if width == 0.0 {
+            return None;
+        }
+
+        let area = layout.plot_area.inset(layout.gap, layout.gap, layout.gap, layout.gap);
+
+        let corner = |position: LegendPosition| {
+            let x = match position {
+                LegendPosition::UpperLeft | LegendPosition::LowerLeft => area.x0,
+                _ => area.x1 - width,
+            };
+
+            let y = match position {
+                LegendPosition::LowerLeft | LegendPosition::LowerRight => area.y0,
+                _ => area.y1 - height,
+            };
+
+            Rect::with_size(x, y, width, height)
+        };
+
+        let position = match self.legend.position {
+            LegendPosition::Best => {
+                let candidates = [LegendPosition::UpperRight, LegendPosition::UpperLeft,
+                                  LegendPosition::LowerLeft, LegendPosition::LowerRight];
+
+                let mut best = candidates[0];
+                let mut best_coverage = ::std::f64::INFINITY;
+
+                // Earlier candidates win ties
+                for &candidate in &candidates {
+                    let c = coverage(&self.footprint, &corner(candidate));
+
+                    if c < best_coverage {
+                        best = candidate;
+                        best_coverage = c;
+                    }
+                }
+
+                best
+            }
+            position => position,
+        };
+
+        Some(corner(position))
+    }
+
+    /// Draw the legend box with an entry for every labeled series
+    ///
+    /// With `LegendPosition::Best`, call this after drawing all series, so it can avoid covering them.
+    pub fn draw_legend(&mut self) {
+        if let Some(rect) = self.legend_rect() {
+            let style = self.label_style();
+
+            self.legend.draw(&mut self.plotter, rect.x0, rect.y1, &style);
+        }
+    }
+}
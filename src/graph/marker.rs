@@ -0,0 +1,187 @@
+//! Marker shapes for scatter plots
+//!
+//! Markers are drawn as polygons at the exact canvas position of their point, so they keep their shape and
+//! place at any size instead of snapping to whole pixels. Filled shapes are scaled to look about as heavy as a
+//! circle of the marker size, squares and diamonds covering the same area as it.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::color::Color;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::marker::{Marker, MarkerStyle};
+//!
+//! let points = vec![(0.5, 0.2), (1.5, 0.8), (2.5, 0.4)];
+//!
+//! let mut graph = Graph::with_plotter(BufferPlot::new(400, 300), 0.0..3.0, 0.0..1.0);
+//!
+//! graph.scatter(&points, MarkerStyle::new(Marker::Diamond, 8.0, Color::rgb(0.1, 0.3, 0.8))).label("samples");
+//! ```
+
+use std::f64::consts::PI;
+
+use ::canvas::{Canvas, Stroke, flatten_ellipse};
+use ::color::Color;
+use ::plot::LineStyle;
+
+use super::Graph;
+use super::legend::LegendEntry;
+use super::series::Series;
+
+/// Shape of a marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Marker {
+    Circle,
+    Square,
+    /// A square standing on a corner
+    Diamond,
+    /// An equilateral triangle pointing up
+    TriangleUp,
+    /// An equilateral triangle pointing down
+    TriangleDown,
+    /// Five pointed star
+    Star,
+    /// Horizontal and vertical line, which can't be filled
+    Plus,
+    /// Two diagonal lines, which can't be filled
+    Cross,
+}
+
+impl Marker {
+    /// Returns true for the shapes made of lines only, which are drawn with the fill color when they have no stroke
+    pub fn is_open(&self) -> bool {
+        match *self {
+            Marker::Plus | Marker::Cross => true,
+            _ => false,
+        }
+    }
+
+    /// Outline of the shape centered on a point, or the ends of its lines for the open shapes
+    pub fn outline(&self, x: f64, y: f64, size: f64) -> Vec<(f64, f64)> {
+        let r = size * 0.5;
+
+        // Radius of a square covering the same area as the circle
+        let square_radius = r * (0.5 * PI).sqrt();
+
+        let regular = |n: usize, radius: f64, rotation: f64| -> Vec<(f64, f64)> {
+            (0..n).map(|i| {
+                let a = rotation + 2.0 * PI * i as f64 / n as f64;
+
+                (x + radius * a.cos(), y + radius * a.sin())
+            }).collect()
+        };
+
+        match *self {
+            Marker::Circle => flatten_ellipse(x, y, r, r),
+            Marker::Square => regular(4, square_radius, PI * 0.25),
+            Marker::Diamond => regular(4, square_radius, 0.0),
+            // Pointed shapes at equal area look larger than the rest, so they are kept a bit smaller
+            Marker::TriangleUp => regular(3, r * 1.3, PI * 0.5),
+            Marker::TriangleDown => regular(3, r * 1.3, -PI * 0.5),
+            Marker::Star => {
+                let outer = r * 1.3;
+                let inner = outer * 0.4;
+
+                (0..10).map(|i| {
+                    let a = PI * 0.5 + PI * i as f64 / 5.0;
+                    let radius = if i % 2 == 0 { outer } else { inner };
+
+                    (x + radius * a.cos(), y + radius * a.sin())
+                }).collect()
+            }
+            Marker::Plus => vec![(x - r, y), (x + r, y), (x, y - r), (x, y + r)],
+            Marker::Cross => {
+                let d = r * 0.5f64.sqrt();
+
+                vec![(x - d, y - d), (x + d, y + d), (x - d, y + d), (x + d, y - d)]
+            }
+        }
+    }
+}
+
+/// Shape, size and colors of markers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkerStyle {
+    pub marker: Marker,
+    /// Diameter of a circle marker in pixels, other shapes are sized to look about as large
+    pub size: f64,
+    pub fill: Option<Color>,
+    pub stroke: Option<Stroke>,
+}
+
+impl MarkerStyle {
+    /// Filled markers without an outline
+    pub fn new(marker: Marker, size: f64, color: Color) -> MarkerStyle {
+        MarkerStyle { marker: marker, size: size, fill: Some(color), stroke: None }
+    }
+
+    /// Hollow markers drawn with a stroke
+    pub fn outlined<S: Into<Stroke>>(marker: Marker, size: f64, stroke: S) -> MarkerStyle {
+        MarkerStyle { marker: marker, size: size, fill: None, stroke: Some(stroke.into()) }
+    }
+
+    pub fn with_fill(self, fill: Option<Color>) -> MarkerStyle {
+        MarkerStyle { fill: fill, ..self }
+    }
+
+    pub fn with_stroke<S: Into<Stroke>>(self, stroke: S) -> MarkerStyle {
+        MarkerStyle { stroke: Some(stroke.into()), ..self }
+    }
+
+    /// Same style with another size
+    pub fn with_size(self, size: f64) -> MarkerStyle {
+        MarkerStyle { size: size, ..self }
+    }
+
+    /// Same style with another color, replacing the fill, or the stroke color of open shapes without a fill
+    pub fn with_color(self, color: Color) -> MarkerStyle {
+        if self.marker.is_open() || self.fill.is_none() {
+            MarkerStyle { stroke: Some(self.line_stroke().with_color(color)), ..self }
+        } else {
+            MarkerStyle { fill: Some(color), ..self }
+        }
+    }
+
+    /// Stroke of an open shape, falling back to a thin line in the fill color
+    fn line_stroke(&self) -> Stroke {
+        self.stroke.unwrap_or_else(|| Stroke::new(self.fill.unwrap_or(Color::black()), LineStyle::ThinAA))
+    }
+}
+
+/// Draw a marker centered on a point of the canvas
+pub fn draw_marker<C: Canvas + ?Sized>(canvas: &mut C, x: f64, y: f64, style: &MarkerStyle) {
+    let outline = style.marker.outline(x, y, style.size);
+
+    if style.marker.is_open() {
+        let stroke = style.line_stroke();
+
+        for line in outline.chunks(2) {
+            canvas.polyline(line, stroke);
+        }
+    } else {
+        canvas.polygon(&outline, style.fill, style.stroke);
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Draw a marker at every point, leaving out points outside of the plot area
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    pub fn scatter(&mut self, points: &[(f64, f64)], style: MarkerStyle) -> &mut LegendEntry {
+        self.draw_series(&Series::scatter(points.to_vec(), style))
+    }
+
+    /// Draw a marker at every point with its own size in pixels, and optionally its own color
+    ///
+    /// Points without a size or color of their own use those of the style.
+    pub fn bubbles(&mut self, points: &[(f64, f64)], sizes: &[f64], colors: Option<&[Color]>, style: MarkerStyle) -> &mut LegendEntry {
+        let mut series = Series::scatter(points.to_vec(), style);
+
+        series.sizes(sizes.to_vec());
+
+        if let Some(colors) = colors {
+            series.colors(colors.to_vec());
+        }
+
+        self.draw_series(&series)
+    }
+}
@@ -1,25 +1,87 @@
+//! Graphs of functions and data
+//!
+//! A `Graph` maps its x and y domains onto the plot area of a canvas, which is what remains after
+//! leaving room around it for the title, the axis labels and the tick labels. The margins are measured
+//! from the text as the active text renderer draws it, so they always fit.
+
+use std::mem;
 use std::ops::{Range, Deref, DerefMut};
 
 pub mod axis;
+pub mod bar;
+pub mod boxplot;
+pub mod density;
+pub mod errorbar;
+pub mod grid;
+pub mod histogram;
+pub mod layout;
+pub mod legend;
+pub mod marker;
+pub mod range;
 pub mod rectangular;
+pub mod series;
 pub mod polar;
 
-use ::plot::Plotter;
+use ::canvas::{Canvas, Stroke, TextStyle};
+use ::color::Color;
+use ::geometry::{Rect, clip_polygon, clip_polyline};
+use ::plot::LineStyle;
+
+use self::axis::Axis;
+use self::bar::BarOptions;
+use self::grid::Grid;
+use self::histogram::HistogramStyle;
+use self::layout::Mapping;
+use self::legend::{Legend, LegendEntry};
+use self::marker::draw_marker;
+use self::range::Extent;
+use self::series::{Series, SeriesKind};
 
-pub struct Graph<P: Plotter> {
+pub struct Graph<P: Canvas> {
     plotter: P,
     x_domain: Range<f64>,
     y_domain: Range<f64>,
     break_discontinuous: bool,
+    title: Option<String>,
+    x_axis: Axis,
+    y_axis: Axis,
+    /// Font size of the axis and tick labels in pixels, the title is drawn larger
+    font_size: f64,
+    text_color: Color,
+    /// Empty space around the edges of the canvas, in pixels
+    padding: f64,
+    legend: Legend,
+    grid: Grid,
+    /// Everything drawn in the plot area so far, as polylines in canvas coordinates, for placing the legend
+    footprint: Vec<Vec<(f64, f64)>>,
+    /// Extents of the data passed to `fit`, for the axes that fit their domains to it
+    data_extent: (Extent, Extent),
+    /// Series waiting to be drawn by `render`
+    series: Vec<Series>,
+    axis_stroke: Stroke,
+    bar_options: BarOptions,
 }
 
-impl<P: Plotter> Graph<P> {
+impl<P: Canvas> Graph<P> {
     pub fn with_plotter(plotter: P, x_domain: Range<f64>, y_domain: Range<f64>) -> Graph<P> {
         Graph {
             plotter: plotter,
             x_domain: x_domain,
             y_domain: y_domain,
             break_discontinuous: true,
+            title: None,
+            x_axis: Axis::new(),
+            y_axis: Axis::new(),
+            font_size: 12.0,
+            text_color: Color::black(),
+            padding: 8.0,
+            legend: Legend::new(),
+            grid: Grid::new(),
+            footprint: Vec::new(),
+            data_extent: (Extent::new(), Extent::new()),
+            series: Vec::new(),
+            axis_stroke: Stroke::new(Color::black(), LineStyle::Thin),
+            bar_options: BarOptions::new(),
         }
     }
 
@@ -35,6 +97,16 @@ impl<P: Plotter> Graph<P> {
         self.plotter
     }
 
+    /// Replace the canvas, returning the one drawn onto so far
+    ///
+    /// Nothing drawn onto the old canvas is left to avoid when placing the legend, so the added series can be
+    /// rendered onto the new one from scratch.
+    pub fn set_plotter(&mut self, plotter: P) -> P {
+        self.footprint.clear();
+
+        mem::replace(&mut self.plotter, plotter)
+    }
+
     pub fn break_discontinuous(&mut self) {
         self.break_discontinuous = true;
     }
@@ -42,10 +114,367 @@ impl<P: Plotter> Graph<P> {
     pub fn bridge_discontinuous(&mut self) {
         self.break_discontinuous = false;
     }
+
+    /// Set the title drawn above the plot area, which may contain markup from `text::markup`
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.to_string());
+    }
+
+    /// Set the label drawn below the x axis, which may contain markup from `text::markup`
+    pub fn set_x_label(&mut self, label: &str) {
+        self.x_axis.label = Some(label.to_string());
+    }
+
+    /// Set the label drawn along the y axis, which may contain markup from `text::markup`
+    pub fn set_y_label(&mut self, label: &str) {
+        self.y_axis.label = Some(label.to_string());
+    }
+
+    pub fn x_axis(&self) -> &Axis { &self.x_axis }
+
+    pub fn x_axis_mut(&mut self) -> &mut Axis { &mut self.x_axis }
+
+    pub fn y_axis(&self) -> &Axis { &self.y_axis }
+
+    pub fn y_axis_mut(&mut self) -> &mut Axis { &mut self.y_axis }
+
+    /// Set the font size of the axis and tick labels in pixels
+    pub fn set_font_size(&mut self, size: f64) {
+        self.font_size = size;
+    }
+
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Set the empty space left around the edges of the canvas, in pixels
+    pub fn set_padding(&mut self, padding: f64) {
+        self.padding = padding;
+    }
+
+    /// Style of the axis and tick labels
+    pub fn label_style(&self) -> TextStyle {
+        TextStyle::new(self.font_size).with_color(self.text_color)
+    }
+
+    /// Style of the title
+    pub fn title_style(&self) -> TextStyle {
+        TextStyle::new(self.font_size * layout::TITLE_SCALE).with_color(self.text_color)
+    }
+
+    /// The area of the canvas the domains are mapped onto
+    pub fn plot_area(&self) -> Rect {
+        self.layout().plot_area
+    }
+
+    /// Mapping from graph coordinates to canvas coordinates, following the scales of the axes
+    pub fn mapping(&self) -> Mapping {
+        self.layout().mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale)
+    }
+
+    /// Map a point from graph coordinates to canvas coordinates
+    pub fn map(&self, x: f64, y: f64) -> (f64, f64) {
+        self.mapping().apply(x, y)
+    }
+
+    /// Draw a series with the current layout and register it in the legend
+    fn draw_series(&mut self, series: &Series) -> &mut LegendEntry {
+        let layout = self.layout();
+        let mapping = layout.mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale);
+
+        self.plot_series(series, &mapping, &layout.plot_area);
+
+        let entry = self.legend.add(series.swatch());
+        entry.label = series.label.clone();
+        entry
+    }
+
+    /// Draw a series onto the plot area without touching the legend
+    fn plot_series(&mut self, series: &Series, mapping: &Mapping, area: &Rect) {
+        match series.kind {
+            SeriesKind::Line { ref points, stroke } => self.draw_polyline(points, mapping, area, stroke),
+            SeriesKind::Scatter { ref points, style, ref sizes, ref colors } => {
+                for (i, &(x, y)) in points.iter().enumerate() {
+                    let (x, y) = mapping.apply(x, y);
+
+                    if !x.is_finite() || !y.is_finite() || !area.contains(x, y) {
+                        continue;
+                    }
+
+                    let mut style = style;
+
+                    if let Some(&size) = sizes.as_ref().and_then(|sizes| sizes.get(i)) {
+                        style = style.with_size(size);
+                    }
+
+                    if let Some(&color) = colors.as_ref().and_then(|colors| colors.get(i)) {
+                        style = style.with_color(color);
+                    }
+
+                    draw_marker(&mut self.plotter, x, y, &style);
+                    self.footprint.push(vec![(x, y)]);
+                }
+            }
+            SeriesKind::Bars { fill, stroke, .. } => {
+                // Bars are drawn by `render` together with the other bar series, this only places them alone
+                let rects = self.bar_options.layout(::std::slice::from_ref(series)).pop().unwrap_or_default();
+
+                self.draw_bars(&rects, fill, stroke, mapping, area);
+            }
+            SeriesKind::Histogram { ref histogram, style } => {
+                let bins = histogram.bins();
+
+                match style {
+                    HistogramStyle::Bars(fill, stroke) => {
+                        let rects: Vec<Rect> = bins.iter().map(|&(x0, x1, v)| Rect::new(x0, 0.0, x1, v)).collect();
+
+                        self.draw_bars(&rects, fill, stroke, mapping, area);
+                    }
+                    HistogramStyle::Step(stroke) => {
+                        let mut points = Vec::with_capacity(bins.len() * 2 + 2);
+
+                        for &(x0, x1, v) in &bins {
+                            if points.is_empty() {
+                                points.push((x0, 0.0));
+                            }
+
+                            points.push((x0, v));
+                            points.push((x1, v));
+                        }
+
+                        if let Some(&(x, _)) = points.last() {
+                            points.push((x, 0.0));
+                        }
+
+                        self.draw_polyline(&points, mapping, area, stroke);
+                    }
+                }
+            }
+            SeriesKind::Area { ref points, fill, stroke } => {
+                let zero: Vec<(f64, f64)> = points.iter().map(|&(x, _)| (x, 0.0)).collect();
+
+                self.fill_between(points, &zero, fill, mapping, area);
+
+                if let Some(stroke) = stroke {
+                    self.draw_polyline(points, mapping, area, stroke);
+                }
+            }
+            SeriesKind::Boxes { ref stats, style } => {
+                for (i, stats) in stats.iter().enumerate() {
+                    let stats = match *stats {
+                        Some(ref stats) => stats,
+                        None => continue,
+                    };
+
+                    let c = i as f64;
+                    let cap = style.width * style.caps * 0.5;
+                    let point = |c: f64, v: f64| style.orientation.point(c, v);
+
+                    for &(from, to) in &[(stats.q1, stats.lower_whisker), (stats.q3, stats.upper_whisker)] {
+                        self.draw_polyline(&[point(c, from), point(c, to)], mapping, area, style.stroke);
+                        self.draw_polyline(&[point(c - cap, to), point(c + cap, to)], mapping, area, style.stroke);
+                    }
+
+                    self.draw_polygon(&style.outline(c, stats), style.fill, Some(style.stroke), mapping, area);
+
+                    // The median line ends at the notches, not at the sides of the box
+                    let half = if style.notch { style.width * 0.25 } else { style.width * 0.5 };
+                    self.draw_polyline(&[point(c - half, stats.median), point(c + half, stats.median)], mapping, area, style.median);
+
+                    if let Some(marker) = style.outliers {
+                        for &v in &stats.outliers {
+                            let (x, y) = point(c, v);
+                            let (x, y) = mapping.apply(x, y);
+
+                            if x.is_finite() && y.is_finite() && area.contains(x, y) {
+                                draw_marker(&mut self.plotter, x, y, &marker);
+                                self.footprint.push(vec![(x, y)]);
+                            }
+                        }
+                    }
+                }
+            }
+            SeriesKind::Violins { ref outlines, style } => {
+                for (i, outline) in outlines.iter().enumerate() {
+                    let c = i as f64;
+
+                    let polygon: Vec<(f64, f64)> = outline.iter().map(|&(v, w)| style.orientation.point(c + w, v))
+                        .chain(outline.iter().rev().map(|&(v, w)| style.orientation.point(c - w, v)))
+                        .collect();
+
+                    self.draw_polygon(&polygon, Some(style.fill), style.stroke, mapping, area);
+                }
+            }
+            SeriesKind::ErrorBars { ref points, ref x_errors, ref y_errors, style } => {
+                let cap = style.caps * 0.5;
+
+                for (i, &(x, y)) in points.iter().enumerate() {
+                    let (cx, cy) = mapping.apply(x, y);
+
+                    if !cx.is_finite() || !cy.is_finite() {
+                        continue;
+                    }
+
+                    let bars = [(x_errors.get(i), true), (y_errors.get(i), false)];
+
+                    for &(error, along_x) in &bars {
+                        let (low, high) = match error {
+                            Some(error) if along_x => error.bounds(x),
+                            Some(error) => error.bounds(y),
+                            None => continue,
+                        };
+
+                        let ends = if along_x { [(low, y), (high, y)] } else { [(x, low), (x, high)] };
+
+                        self.draw_polyline(&ends, mapping, area, style.stroke);
+
+                        if cap > 0.0 {
+                            for &(ex, ey) in &ends {
+                                let (ex, ey) = mapping.apply(ex, ey);
+
+                                if !ex.is_finite() || !ey.is_finite() || !area.contains(ex, ey) {
+                                    continue;
+                                }
+
+                                let cap = if along_x { [(ex, ey - cap), (ex, ey + cap)] } else { [(ex - cap, ey), (ex + cap, ey)] };
+
+                                self.plotter.polyline(&cap, style.stroke);
+                                self.footprint.push(cap.to_vec());
+                            }
+                        }
+                    }
+
+                    if let Some(marker) = style.marker {
+                        if area.contains(cx, cy) {
+                            draw_marker(&mut self.plotter, cx, cy, &marker);
+                            self.footprint.push(vec![(cx, cy)]);
+                        }
+                    }
+                }
+            }
+            SeriesKind::Band { ref lower, ref upper, fill, stroke } => {
+                self.fill_between(upper, lower, fill, mapping, area);
+
+                if let Some(stroke) = stroke {
+                    self.draw_polyline(lower, mapping, area, stroke);
+                    self.draw_polyline(upper, mapping, area, stroke);
+                }
+            }
+        }
+    }
+
+    /// Draw a polygon in graph coordinates, clipped to the area, or nothing if a scale can't show one of its points
+    fn draw_polygon(&mut self, points: &[(f64, f64)], fill: Option<Color>, stroke: Option<Stroke>, mapping: &Mapping, area: &Rect) {
+        let clip = [(area.x0, area.y0), (area.x1, area.y0), (area.x1, area.y1), (area.x0, area.y1)];
+
+        let points: Vec<(f64, f64)> = points.iter().map(|&(x, y)| mapping.apply(x, y)).collect();
+
+        if points.iter().any(|p| !p.0.is_finite() || !p.1.is_finite()) {
+            return;
+        }
+
+        let polygon = clip_polygon(&points, &clip);
+
+        if polygon.len() > 2 {
+            self.plotter.polygon(&polygon, fill, stroke);
+            self.footprint.push(polygon);
+        }
+    }
+
+    /// Fill the area between two lines through points in graph coordinates, clipped to the area
+    ///
+    /// The lines are paired point by point, and the fill is broken wherever either point isn't finite. Points
+    /// below what a logarithmic axis can show are moved to the edge of the area instead.
+    fn fill_between(&mut self, upper: &[(f64, f64)], lower: &[(f64, f64)], fill: Color, mapping: &Mapping, area: &Rect) {
+        let clip = [(area.x0, area.y0), (area.x1, area.y0), (area.x1, area.y1), (area.x0, area.y1)];
+
+        let map = |x: f64, y: f64| {
+            let (x, y) = mapping.apply(x, y);
+
+            if y.is_infinite() { (x, y.max(area.y0).min(area.y1)) } else { (x, y) }
+        };
+
+        let mut runs = vec![Vec::new()];
+
+        for (&(x0, y0), &(x1, y1)) in upper.iter().zip(lower) {
+            let (a, b) = (map(x0, y0), map(x1, y1));
+
+            if a.0.is_finite() && a.1.is_finite() && b.0.is_finite() && b.1.is_finite() {
+                runs.last_mut().unwrap().push((a, b));
+            } else if !runs.last().unwrap().is_empty() {
+                runs.push(Vec::new());
+            }
+        }
+
+        for run in runs.into_iter().filter(|run| run.len() > 1) {
+            let outline: Vec<(f64, f64)> = run.iter().map(|p| p.0).chain(run.iter().rev().map(|p| p.1)).collect();
+            let polygon = clip_polygon(&outline, &clip);
+
+            if polygon.len() > 2 {
+                self.plotter.polygon(&polygon, Some(fill), None);
+                self.footprint.push(polygon);
+            }
+        }
+    }
+
+    /// Fill rectangles in graph coordinates, clipped to the area
+    fn draw_bars(&mut self, rects: &[Rect], fill: Color, stroke: Option<Stroke>, mapping: &Mapping, area: &Rect) {
+        for rect in rects {
+            let (x0, y0) = mapping.apply(rect.x0, rect.y0);
+            let (x1, y1) = mapping.apply(rect.x1, rect.y1);
+
+            // Bars from zero on a logarithmic axis start at minus infinity, which clips to the edge of the area
+            if x0.is_nan() || y0.is_nan() || x1.is_nan() || y1.is_nan() {
+                continue;
+            }
+
+            if let Some(r) = Rect::new(x0, y0, x1, y1).intersection(area) {
+                self.plotter.rect(r.x0, r.y0, r.x1, r.y1, Some(fill), stroke);
+                self.footprint.push(vec![(r.x0, r.y0), (r.x1, r.y0), (r.x1, r.y1), (r.x0, r.y1), (r.x0, r.y0)]);
+            }
+        }
+    }
+
+    /// Draw a line through points in graph coordinates, split where it is discontinuous and clipped to the area
+    fn draw_polyline(&mut self, points: &[(f64, f64)], mapping: &Mapping, area: &Rect, stroke: Stroke) {
+        let points: Vec<(f64, f64)> = points.iter().map(|&(x, y)| mapping.apply(x, y)).collect();
+
+        for run in self.connected_runs(&points) {
+            for piece in clip_polyline(&run, area) {
+                self.plotter.polyline(&piece, stroke);
+                self.footprint.push(piece);
+            }
+        }
+    }
+
+    /// Split points into the runs that should be connected by lines, leaving out points that aren't finite
+    ///
+    /// Points are checked after mapping them onto the canvas, so values a logarithmic scale can't show count
+    /// as discontinuities too.
+    fn connected_runs(&self, samples: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+        let mut runs = vec![Vec::new()];
+
+        for &(x, y) in samples {
+            if x.is_finite() && y.is_finite() {
+                runs.last_mut().unwrap().push((x, y));
+            } else if self.break_discontinuous && !runs.last().unwrap().is_empty() {
+                runs.push(Vec::new());
+            }
+        }
+
+        runs.retain(|run| !run.is_empty());
+        runs
+    }
 }
 
+/// Evenly spaced values covering a range, including both ends
+fn spaced(range: &Range<f64>, samples: usize) -> Vec<f64> {
+    let samples = samples.max(2);
 
-impl<P: Plotter> Deref for Graph<P> {
+    (0..samples).map(|i| range.start + (range.end - range.start) * i as f64 / (samples - 1) as f64).collect()
+}
+
+impl<P: Canvas> Deref for Graph<P> {
     type Target = P;
 
     fn deref(&self) -> &P {
@@ -53,8 +482,8 @@ impl<P: Plotter> Deref for Graph<P> {
     }
 }
 
-impl<P: Plotter> DerefMut for Graph<P> {
+impl<P: Canvas> DerefMut for Graph<P> {
     fn deref_mut(&mut self) -> &mut P {
         &mut self.plotter
     }
-}
\ No newline at end of file
+}
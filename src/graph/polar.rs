@@ -1,21 +1,213 @@
+//! Polar graphs drawn onto the rectangular domains of a graph
+//!
+//! A point at angle `a` and radius `r` is placed at `(r cos a, r sin a)`, so the origin sits wherever the
+//! domains put it. The polar grid and axis assume both axes are linear.
+
+use std::f64::consts::PI;
 use std::ops::Range;
 
-use ::plot::{LineStyle, Plotter};
+use ::canvas::{Canvas, HAlign, Stroke, VAlign, flatten_ellipse};
+use ::geometry::{Rect, clip_line, clip_polyline};
+use ::text::markup;
+
+use super::spaced;
+use super::axis::{minor_ticks, ticks, format_tick};
+use super::layout::Mapping;
+use super::legend::LegendEntry;
+use super::rectangular::RectangularGraph;
 
 pub trait PolarGraph {
     /// Graphs a set of samples from a polar function
     ///
     /// The samples are composed of two-element tuples, where the first element is the polar angle,
     /// and the second element is the radius given by the function.
-    fn polar_samples(&mut self, samples: Vec<(f64, f64)>, style: LineStyle);
-    fn polar_equation<F>(&mut self, a_domain: Range<f64>, samples: usize, style: LineStyle, f: F) where F: Fn(f64) -> f64;
-    fn draw_polar_axis(&mut self, divisions: usize, style: LineStyle);
+    ///
+    /// Returns the legend entry of the series, so it can be given a label.
+    fn polar_samples<S: Into<Stroke>>(&mut self, samples: Vec<(f64, f64)>, stroke: S) -> &mut LegendEntry;
+    fn polar_equation<S: Into<Stroke>, F>(&mut self, a_domain: Range<f64>, samples: usize, stroke: S, f: F) -> &mut LegendEntry where F: Fn(f64) -> f64;
+    /// Draws circles at the radius ticks and `divisions` evenly spaced spokes, with labels for both, and the title
+    fn draw_polar_axis<S: Into<Stroke>>(&mut self, divisions: usize, stroke: S);
+    /// Draws circles at the major and minor radius ticks and `divisions` spokes with the strokes of the grid
+    ///
+    /// Spokes use the major stroke. The `x` and `y` switches of the grid turn off the spokes and circles
+    /// respectively.
+    fn draw_polar_grid(&mut self, divisions: usize);
+}
+
+/// Range of radii of the circles around the origin that pass through the domains
+fn radius_range(x_domain: &Range<f64>, y_domain: &Range<f64>) -> Range<f64> {
+    let (x0, x1) = (x_domain.start.min(x_domain.end), x_domain.start.max(x_domain.end));
+    let (y0, y1) = (y_domain.start.min(y_domain.end), y_domain.start.max(y_domain.end));
+
+    // Nearest and farthest points of the domains from the origin
+    let nx = if x0 > 0.0 { x0 } else if x1 < 0.0 { x1 } else { 0.0 };
+    let ny = if y0 > 0.0 { y0 } else if y1 < 0.0 { y1 } else { 0.0 };
+
+    let fx = x0.abs().max(x1.abs());
+    let fy = y0.abs().max(y1.abs());
+
+    nx.hypot(ny)..fx.hypot(fy)
+}
+
+/// Radius of the largest circle around the origin that fits inside the domains, if they contain the origin
+fn inscribed_radius(x_domain: &Range<f64>, y_domain: &Range<f64>) -> Option<f64> {
+    let contains = |d: &Range<f64>| d.start.min(d.end) <= 0.0 && d.start.max(d.end) >= 0.0;
+
+    if contains(x_domain) && contains(y_domain) {
+        Some(x_domain.start.abs().min(x_domain.end.abs()).min(y_domain.start.abs()).min(y_domain.end.abs()))
+    } else {
+        None
+    }
+}
+
+/// Number of radius ticks to aim for, circles fill the area much faster than lines along one axis do
+fn radius_tick_count(tick_count: usize) -> usize {
+    tick_count / 2 + tick_count % 2
 }
 
-impl<P: Plotter> PolarGraph for super::Graph<P> {
-    fn polar_samples(&mut self, samples: Vec<(f64, f64)>, style: LineStyle) {}
+/// Circle of the given radius around the origin, clipped to the plot area
+fn circle(mapping: &Mapping, radius: f64, area: &Rect) -> Vec<Vec<(f64, f64)>> {
+    let (cx, cy) = mapping.apply(0.0, 0.0);
+    let (rx, ry) = mapping.transform.apply_vector(radius, radius);
+
+    let mut points = flatten_ellipse(cx, cy, rx.abs(), ry.abs());
+    let first = points[0];
+    points.push(first);
+
+    clip_polyline(&points, area)
+}
+
+/// Direction of a spoke as a unit vector
+fn spoke_direction(index: usize, divisions: usize) -> (f64, f64) {
+    let angle = 2.0 * PI * index as f64 / divisions as f64;
+
+    (angle.cos(), angle.sin())
+}
+
+impl<P: Canvas> PolarGraph for super::Graph<P> {
+    fn polar_samples<S: Into<Stroke>>(&mut self, samples: Vec<(f64, f64)>, stroke: S) -> &mut LegendEntry {
+        let samples = samples.into_iter().map(|(a, r)| (r * a.cos(), r * a.sin())).collect();
+
+        self.parametric_samples(samples, stroke)
+    }
+
+    fn polar_equation<S: Into<Stroke>, F>(&mut self, a_domain: Range<f64>, samples: usize, stroke: S, f: F) -> &mut LegendEntry where F: Fn(f64) -> f64 {
+        let samples = spaced(&a_domain, samples).into_iter().map(|a| (a, f(a))).collect();
+
+        self.polar_samples(samples, stroke)
+    }
+
+    fn draw_polar_axis<S: Into<Stroke>>(&mut self, divisions: usize, stroke: S) {
+        let stroke = stroke.into();
+
+        let layout = self.layout();
+        let mapping = layout.mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale);
+        let area = layout.plot_area;
 
-    fn polar_equation<F>(&mut self, a_domain: Range<f64>, samples: usize, style: LineStyle, f: F) where F: Fn(f64) -> f64 {}
+        let radii = radius_range(&self.x_domain, &self.y_domain);
+        let count = radius_tick_count(self.x_axis.tick_count);
+        let radius_ticks: Vec<f64> = ticks(&radii, count).into_iter().filter(|&r| r > 0.0).collect();
 
-    fn draw_polar_axis(&mut self, divisions: usize, style: LineStyle) {}
-}
\ No newline at end of file
+        for &radius in &radius_ticks {
+            for piece in circle(&mapping, radius, &area) {
+                self.plotter.polyline(&piece, stroke);
+            }
+        }
+
+        let (ox, oy) = mapping.apply(0.0, 0.0);
+
+        for i in 0..divisions {
+            let (dx, dy) = spoke_direction(i, divisions);
+            let (ex, ey) = mapping.apply(dx * radii.end, dy * radii.end);
+
+            if let Some((x0, y0, x1, y1)) = clip_line(ox, oy, ex, ey, &area) {
+                self.plotter.line(x0, y0, x1, y1, stroke);
+            }
+        }
+
+        let label_style = self.label_style();
+
+        // Radii are labeled along the spoke at angle zero, up to the largest circle that is drawn whole
+        let inscribed = inscribed_radius(&self.x_domain, &self.y_domain);
+        let outer = inscribed.and_then(|r| radius_ticks.iter().cloned().rev().find(|&t| t <= r * (1.0 + 1e-9)));
+
+        let step = if radius_ticks.len() > 1 { radius_ticks[1] - radius_ticks[0] } else { 1.0 };
+
+        for &radius in &radius_ticks {
+            let (x, y) = mapping.apply(radius, 0.0);
+
+            let shown = match outer {
+                Some(outer) => radius <= outer,
+                None => area.contains(x, y),
+            };
+
+            if shown {
+                let style = label_style.with_anchor(HAlign::Center, VAlign::Top);
+
+                markup::draw(&mut self.plotter, x, y - layout.gap, &format_tick(radius, step), &style);
+            }
+        }
+
+        // Angles are labeled just outside that circle, which needs the whole circle on the canvas
+        if let Some(outer) = outer {
+            for i in 0..divisions {
+                let (dx, dy) = spoke_direction(i, divisions);
+                let (x, y) = mapping.apply(dx * outer, dy * outer);
+
+                let (x, y) = (x + dx * layout.gap, y + dy * layout.gap);
+
+                let horizontal = if dx > 0.3 { HAlign::Left } else if dx < -0.3 { HAlign::Right } else { HAlign::Center };
+                let vertical = if dy > 0.3 { VAlign::Bottom } else if dy < -0.3 { VAlign::Top } else { VAlign::Middle };
+
+                let degrees = format_tick(360.0 * i as f64 / divisions as f64, 1.0);
+
+                markup::draw(&mut self.plotter, x, y, &format!("{}°", degrees), &label_style.with_anchor(horizontal, vertical));
+            }
+        }
+
+        if let Some(ref title) = self.title {
+            let style = self.title_style().with_anchor(HAlign::Center, VAlign::Top);
+
+            markup::draw(&mut self.plotter, area.center().0, layout.bounds.y1, title, &style);
+        }
+    }
+
+    fn draw_polar_grid(&mut self, divisions: usize) {
+        let layout = self.layout();
+        let mapping = layout.mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale);
+        let area = layout.plot_area;
+        let grid = self.grid;
+
+        let radii = radius_range(&self.x_domain, &self.y_domain);
+
+        let count = radius_tick_count(self.x_axis.tick_count);
+
+        let major: Vec<f64> = ticks(&radii, count).into_iter().filter(|&r| r > 0.0).collect();
+        let minor: Vec<f64> = minor_ticks(&radii, count).into_iter().filter(|&r| r > 0.0).collect();
+
+        if grid.y {
+            for &(stroke, ref radius_ticks) in &[(grid.minor, minor), (grid.major, major)] {
+                if let Some(stroke) = stroke {
+                    for &radius in radius_ticks {
+                        for piece in circle(&mapping, radius, &area) {
+                            self.plotter.polyline(&piece, stroke);
+                        }
+                    }
+                }
+            }
+        }
+
+        if let (true, Some(stroke)) = (grid.x, grid.major) {
+            let (ox, oy) = mapping.apply(0.0, 0.0);
+
+            for i in 0..divisions {
+                let (dx, dy) = spoke_direction(i, divisions);
+                let (ex, ey) = mapping.apply(dx * radii.end, dy * radii.end);
+
+                if let Some((x0, y0, x1, y1)) = clip_line(ox, oy, ex, ey, &area) {
+                    self.plotter.line(x0, y0, x1, y1, stroke);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Fitting the domains of a graph to its data
+//!
+//! An axis with an `AutoRange` gets its domain from the values of the data fitted to the graph, widened by
+//! some padding and rounded out to the next ticks so the ends of the axis get labels.
+//!
+//! ```
+//! use rust_plot::graph::axis::Axis;
+//! use rust_plot::graph::range::{AutoRange, Extent};
+//!
+//! let extent = Extent::from_values(vec![0.3, 4.2, 2.5, ::std::f64::NAN]);
+//!
+//! assert_eq!(AutoRange::new().fit(&extent, &Axis::new()), 0.0..5.0);
+//!
+//! let constant = Extent::from_values(vec![7.0, 7.0]);
+//!
+//! assert_eq!(AutoRange::new().fit(&constant, &Axis::new()), 6.2..7.8);
+//! assert_eq!(AutoRange::new().include_zero(true).fit(&constant, &Axis::new()), 0.0..8.0);
+//! ```
+//!
+//! A graph fits its domains to everything passed to `Graph::fit` before the series are drawn:
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::rectangular::RectangularGraph;
+//! use rust_plot::plot::LineStyle;
+//!
+//! let samples: Vec<(f64, f64)> = (0..50).map(|i| (i as f64 * 0.2, (i as f64 * 0.2).sqrt() * 3.0)).collect();
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.fit(&samples);
+//! assert_eq!((graph.x_domain(), graph.y_domain()), (0.0..12.0, 0.0..10.0));
+//!
+//! graph.draw_axis(LineStyle::Thin);
+//! graph.parametric_samples(samples, LineStyle::ThinAA);
+//! ```
+
+use std::f64;
+use std::ops::Range;
+
+use super::axis::{Axis, Scale, tick_step};
+use super::Graph;
+use ::canvas::Canvas;
+
+/// Smallest and largest finite values of some data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extent {
+    pub min: f64,
+    pub max: f64,
+    /// Smallest value above zero, which is what a logarithmic axis can show
+    pub min_positive: f64,
+}
+
+impl Extent {
+    /// An extent without any values
+    pub fn new() -> Extent {
+        Extent { min: f64::INFINITY, max: f64::NEG_INFINITY, min_positive: f64::INFINITY }
+    }
+
+    pub fn from_values<I: IntoIterator<Item = f64>>(values: I) -> Extent {
+        let mut extent = Extent::new();
+
+        for value in values {
+            extent.include(value);
+        }
+
+        extent
+    }
+
+    /// Widen the extent to cover a value, ignoring values that aren't finite
+    pub fn include(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+
+        if value > 0.0 {
+            self.min_positive = self.min_positive.min(value);
+        }
+    }
+
+    /// Widen the extent to cover another one
+    pub fn union(&mut self, other: &Extent) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.min_positive = self.min_positive.min(other.min_positive);
+    }
+
+    /// Returns true if no finite values were included
+    pub fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+}
+
+impl Default for Extent {
+    fn default() -> Extent {
+        Extent::new()
+    }
+}
+
+/// How an axis fits its domain to the data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoRange {
+    /// Space added beyond the data on both ends, as a fraction of its span
+    pub padding: f64,
+    /// Whether to round the ends out to the next tick
+    pub nice: bool,
+    /// Whether the domain always covers zero, as bars and counts want
+    pub include_zero: bool,
+}
+
+impl AutoRange {
+    /// Padding of 5%, nice bounds and no forced zero
+    pub fn new() -> AutoRange {
+        AutoRange { padding: 0.05, nice: true, include_zero: false }
+    }
+
+    pub fn padding(self, padding: f64) -> AutoRange {
+        AutoRange { padding: padding, ..self }
+    }
+
+    pub fn nice(self, nice: bool) -> AutoRange {
+        AutoRange { nice: nice, ..self }
+    }
+
+    pub fn include_zero(self, include_zero: bool) -> AutoRange {
+        AutoRange { include_zero: include_zero, ..self }
+    }
+
+    /// Domain covering the extent along the given axis
+    ///
+    /// An axis with categories always shows all of them, with half a unit on either side. Without any data
+    /// the domain is `0..1`, or `1..10` on a logarithmic axis. A single value or constant
+    /// data gets a domain centered on it, a tenth of its magnitude wide on either side, or one unit around zero.
+    /// Ends at zero are not padded past it when the data doesn't cross it.
+    pub fn fit(&self, extent: &Extent, axis: &Axis) -> Range<f64> {
+        if let Some(ref categories) = axis.categories {
+            return -0.5..categories.len().max(1) as f64 - 0.5;
+        }
+
+        match axis.scale {
+            Scale::Linear => self.fit_linear(extent, axis.tick_count),
+            Scale::Log => self.fit_log(extent, axis.tick_count),
+        }
+    }
+
+    fn fit_linear(&self, extent: &Extent, tick_count: usize) -> Range<f64> {
+        if extent.is_empty() && !self.include_zero {
+            return 0.0..1.0;
+        }
+
+        let (mut min, mut max) = if extent.is_empty() { (0.0, 0.0) } else { (extent.min, extent.max) };
+
+        if self.include_zero {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+
+        if min == max {
+            let half = if min == 0.0 { 1.0 } else { min.abs() * 0.1 };
+
+            return self.round(min - half, max + half, tick_count);
+        }
+
+        let pad = (max - min) * self.padding;
+
+        let start = if min == 0.0 { min } else { min - pad };
+        let end = if max == 0.0 { max } else { max + pad };
+
+        self.round(start, end, tick_count)
+    }
+
+    /// Round the ends out to multiples of the tick step, if nice bounds are wanted
+    fn round(&self, start: f64, end: f64, tick_count: usize) -> Range<f64> {
+        if !self.nice {
+            return start..end;
+        }
+
+        let step = tick_step(end - start, tick_count);
+
+        if !step.is_finite() {
+            return start..end;
+        }
+
+        // Small tolerance so ends already on a tick don't grow by another step
+        let epsilon = step * 1e-9;
+
+        let start = ((start + epsilon) / step).floor() * step;
+        let end = ((end - epsilon) / step).ceil() * step;
+
+        // Rounding to the precision of the step removes tails like 0.30000000000000004
+        let factor = 10f64.powi((-step.log10().floor()).max(0.0) as i32);
+
+        (start * factor).round() / factor..(end * factor).round() / factor
+    }
+
+    fn fit_log(&self, extent: &Extent, tick_count: usize) -> Range<f64> {
+        if !extent.min_positive.is_finite() {
+            return 1.0..10.0;
+        }
+
+        let (min, max) = (extent.min_positive.log10(), extent.max.log10());
+
+        let (start, end) = if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            let pad = (max - min) * self.padding;
+
+            (min - pad, max + pad)
+        };
+
+        if !self.nice {
+            return 10f64.powf(start)..10f64.powf(end);
+        }
+
+        // Whole decades when the range spans a few of them, round values within the decade otherwise
+        if end - start >= 1.0 {
+            10f64.powf((start + 1e-9).floor())..10f64.powf((end - 1e-9).ceil())
+        } else {
+            let linear = self.round(10f64.powf(start), 10f64.powf(end), tick_count);
+
+            if linear.start > 0.0 { linear } else { 10f64.powf(start)..linear.end }
+        }
+    }
+}
+
+impl Default for AutoRange {
+    fn default() -> AutoRange {
+        AutoRange::new()
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Create a graph with both axes fitted to the data passed to `fit`
+    pub fn auto_ranged(plotter: P) -> Graph<P> {
+        let mut graph = Graph::with_plotter(plotter, 0.0..1.0, 0.0..1.0);
+
+        graph.x_axis.auto_range = Some(AutoRange::new());
+        graph.y_axis.auto_range = Some(AutoRange::new());
+        graph.refit();
+
+        graph
+    }
+
+    /// Set the x domain, turning off automatic fitting of the x axis
+    pub fn set_x_domain(&mut self, domain: Range<f64>) {
+        self.x_axis.auto_range = None;
+        self.x_domain = domain;
+    }
+
+    /// Set the y domain, turning off automatic fitting of the y axis
+    pub fn set_y_domain(&mut self, domain: Range<f64>) {
+        self.y_axis.auto_range = None;
+        self.y_domain = domain;
+    }
+
+    /// Extents of all data fitted so far and all added series, along the x and y axes
+    pub fn data_extent(&self) -> (Extent, Extent) {
+        let (mut x, mut y) = self.data_extent;
+
+        for series in &self.series {
+            let (sx, sy) = series.extent();
+
+            x.union(&sx);
+            y.union(&sy);
+        }
+
+        // Bars cover their whole rectangles, which depend on how they are stacked
+        for rect in self.bar_options.layout(&self.series).iter().flatten() {
+            x.union(&Extent::from_values(vec![rect.x0, rect.x1]));
+            y.union(&Extent::from_values(vec![rect.y0, rect.y1]));
+        }
+
+        (x, y)
+    }
+
+    /// Widen the domains of the axes with an `AutoRange` to cover the given points
+    ///
+    /// Series are mapped with the domains at the time they are drawn, so fit all data before drawing anything.
+    /// Series added with `Graph::add` don't need this, as `Graph::render` fits to them.
+    pub fn fit(&mut self, points: &[(f64, f64)]) {
+        for &(x, y) in points {
+            self.data_extent.0.include(x);
+            self.data_extent.1.include(y);
+        }
+
+        self.refit();
+    }
+
+    /// Recompute the domains of the axes with an `AutoRange` from the data fitted so far and the added series
+    pub fn refit(&mut self) {
+        let (x, y) = self.data_extent();
+
+        if let Some(auto_range) = self.x_axis.auto_range {
+            self.x_domain = auto_range.fit(&x, &self.x_axis);
+        }
+
+        if let Some(auto_range) = self.y_axis.auto_range {
+            self.y_domain = auto_range.fit(&y, &self.y_axis);
+        }
+    }
+}
@@ -1,33 +1,113 @@
+use std::f64::consts::PI;
 use std::ops::Range;
 
-use ::plot::{LineStyle, Plotter};
+use ::canvas::{Canvas, HAlign, Stroke, VAlign};
+use ::text::markup;
+
+use super::spaced;
+use super::legend::LegendEntry;
+use super::series::Series;
 
 pub trait RectangularGraph {
     /// Graphs a set of samples from a linear or parametric equation, where a linear equation is
     /// simply a subset of parametric equations where the x term is known ahead of time.
     ///
     /// The samples are composed of two-element tuples, where the first element is the x-axis coordinate,
-    /// and the second element is the y-axis coordinate.
-    fn parametric_samples(&mut self, samples: Vec<(f64, f64)>, style: LineStyle);
-    fn linear_equation<F>(&mut self, samples: usize, style: LineStyle, f: F) where F: Fn(f64) -> f64;
-    fn parametric_equation<F>(&mut self, t_domain: Range<f64>, samples: usize, style: LineStyle, f: F) where F: Fn(f64) -> (f64, f64);
-    fn draw_axis(&mut self, style: LineStyle);
+    /// and the second element is the y-axis coordinate. Lines are clipped to the plot area, and samples
+    /// that aren't finite either break the line or are skipped, see `Graph::bridge_discontinuous`.
+    ///
+    /// Returns the legend entry of the series, so it can be given a label, like `.label("sin x")`.
+    fn parametric_samples<S: Into<Stroke>>(&mut self, samples: Vec<(f64, f64)>, stroke: S) -> &mut LegendEntry;
+    fn linear_equation<S: Into<Stroke>, F>(&mut self, samples: usize, stroke: S, f: F) -> &mut LegendEntry where F: Fn(f64) -> f64;
+    fn parametric_equation<S: Into<Stroke>, F>(&mut self, t_domain: Range<f64>, samples: usize, stroke: S, f: F) -> &mut LegendEntry where F: Fn(f64) -> (f64, f64);
+    /// Draws the frame around the plot area with tick marks and labels, the axis labels and the title
+    ///
+    /// Minor tick marks are drawn at half length on axes with `Axis::show_minor_ticks` set.
+    fn draw_axis<S: Into<Stroke>>(&mut self, stroke: S);
 }
 
-impl<P: Plotter> RectangularGraph for super::Graph<P> {
-    fn parametric_samples(&mut self, samples: Vec<(f64, f64)>, style: LineStyle) {
-
+impl<P: Canvas> RectangularGraph for super::Graph<P> {
+    fn parametric_samples<S: Into<Stroke>>(&mut self, samples: Vec<(f64, f64)>, stroke: S) -> &mut LegendEntry {
+        self.draw_series(&Series::line(samples, stroke))
     }
 
-    fn linear_equation<F>(&mut self, samples: usize, style: LineStyle, f: F) where F: Fn(f64) -> f64 {
+    fn linear_equation<S: Into<Stroke>, F>(&mut self, samples: usize, stroke: S, f: F) -> &mut LegendEntry where F: Fn(f64) -> f64 {
+        let samples = spaced(&self.x_domain, samples).into_iter().map(|x| (x, f(x))).collect();
 
+        self.parametric_samples(samples, stroke)
     }
 
-    fn parametric_equation<F>(&mut self, t_domain: Range<f64>, samples: usize, style: LineStyle, f: F) where F: Fn(f64) -> (f64, f64) {
+    fn parametric_equation<S: Into<Stroke>, F>(&mut self, t_domain: Range<f64>, samples: usize, stroke: S, f: F) -> &mut LegendEntry where F: Fn(f64) -> (f64, f64) {
+        let samples = spaced(&t_domain, samples).into_iter().map(f).collect();
 
+        self.parametric_samples(samples, stroke)
     }
 
-    fn draw_axis(&mut self, style: LineStyle) {
+    fn draw_axis<S: Into<Stroke>>(&mut self, stroke: S) {
+        let stroke = stroke.into();
+
+        let layout = self.layout();
+        let mapping = layout.mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale);
+
+        let area = layout.plot_area;
+
+        self.plotter.rect(area.x0, area.y0, area.x1, area.y1, None, Some(stroke));
+
+        let minor_length = layout.tick_length * 0.5;
+
+        if self.x_axis.show_minor_ticks {
+            for tick in self.x_axis.minor_ticks(&self.x_domain) {
+                let x = mapping.map_x(tick).round();
+
+                self.plotter.line(x, area.y0, x, area.y0 - minor_length, stroke);
+            }
+        }
+
+        if self.y_axis.show_minor_ticks {
+            for tick in self.y_axis.minor_ticks(&self.y_domain) {
+                let y = mapping.map_y(tick).round();
+
+                self.plotter.line(area.x0, y, area.x0 - minor_length, y, stroke);
+            }
+        }
+
+        let label_style = self.label_style();
+
+        let x_tick_style = label_style.with_anchor(HAlign::Center, VAlign::Top);
+        let y_tick_style = label_style.with_anchor(HAlign::Right, VAlign::Middle);
+
+        for (&tick, label) in layout.x_ticks.iter().zip(&layout.x_tick_labels) {
+            let x = mapping.map_x(tick).round();
+
+            self.plotter.line(x, area.y0, x, area.y0 - layout.tick_length, stroke);
+
+            markup::draw(&mut self.plotter, x, area.y0 - layout.tick_length - layout.gap, label, &x_tick_style);
+        }
+
+        for (&tick, label) in layout.y_ticks.iter().zip(&layout.y_tick_labels) {
+            let y = mapping.map_y(tick).round();
+
+            self.plotter.line(area.x0, y, area.x0 - layout.tick_length, y, stroke);
+
+            markup::draw(&mut self.plotter, area.x0 - layout.tick_length - layout.gap, y, label, &y_tick_style);
+        }
+
+        let (cx, cy) = area.center();
+
+        if let Some(ref label) = self.x_axis.label {
+            markup::draw(&mut self.plotter, cx, layout.bounds.y0, label, &label_style.with_anchor(HAlign::Center, VAlign::Bottom));
+        }
+
+        if let Some(ref label) = self.y_axis.label {
+            let style = label_style.with_anchor(HAlign::Center, VAlign::Top).with_rotation(PI * 0.5);
+
+            markup::draw(&mut self.plotter, layout.bounds.x0, cy, label, &style);
+        }
+
+        if let Some(ref title) = self.title {
+            let style = self.title_style().with_anchor(HAlign::Center, VAlign::Top);
 
+            markup::draw(&mut self.plotter, cx, layout.bounds.y1, title, &style);
+        }
     }
 }
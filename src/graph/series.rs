@@ -0,0 +1,412 @@
+//! Series collected by a graph and drawn together
+//!
+//! Instead of drawing each series as it is given, like the methods of `RectangularGraph` do, a graph can
+//! collect them with `Graph::add` and draw everything with `Graph::render`. Rendering first fits the axes
+//! with an `AutoRange` to all series, then lays out the graph with every label known, and draws the grid,
+//! the series, the axes and the legend in that order. Filled series go below lines, which go below markers,
+//! and series of the same kind are drawn in the order they were added.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::legend::LegendPosition;
+//! use rust_plot::graph::series::Series;
+//! use rust_plot::plot::LineStyle;
+//!
+//! let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+//!
+//! graph.set_legend_position(LegendPosition::OutsideRight);
+//! graph.add(Series::function(0.0..6.0, 200, LineStyle::ThinAA, f64::sin)).label("sin x");
+//! graph.add(Series::line(vec![(0.0, 0.0), (3.0, 1.5), (6.0, 0.5)], LineStyle::Thin)).label("data");
+//!
+//! graph.render();
+//!
+//! assert_eq!(graph.y_domain(), -1.5..2.0);
+//! ```
+
+use std::mem;
+use std::ops::Range;
+
+use ::canvas::{Canvas, Stroke};
+use ::color::Color;
+use ::geometry::Rect;
+use ::stat::bins::Histogram;
+use ::stat::summary::BoxStats;
+
+use super::{Graph, spaced};
+use super::bar::Orientation;
+use super::boxplot::{BoxStyle, ViolinStyle};
+use super::errorbar::{ErrorBarStyle, Uncertainty};
+use super::histogram::HistogramStyle;
+use super::legend::Swatch;
+use super::marker::MarkerStyle;
+use super::range::Extent;
+use super::rectangular::RectangularGraph;
+
+/// The data of a series and how it is drawn
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeriesKind {
+    /// Points connected by straight lines, broken or bridged where they aren't finite
+    Line { points: Vec<(f64, f64)>, stroke: Stroke },
+    /// A marker at every point, with optional sizes and colors per point replacing those of the style
+    Scatter { points: Vec<(f64, f64)>, style: MarkerStyle, sizes: Option<Vec<f64>>, colors: Option<Vec<Color>> },
+    /// A bar for every category, placed by the `BarOptions` of the graph
+    Bars { values: Vec<f64>, fill: Color, stroke: Option<Stroke> },
+    /// Binned data, see `graph::histogram`
+    Histogram { histogram: Histogram, style: HistogramStyle },
+    /// The area between a line and zero filled, with an optional stroke along the line
+    Area { points: Vec<(f64, f64)>, fill: Color, stroke: Option<Stroke> },
+    /// A box for every category, or nothing for groups without finite values, see `graph::boxplot`
+    Boxes { stats: Vec<Option<BoxStats>>, style: BoxStyle },
+    /// The outline of a violin for every category, as values with the half width of the violin there
+    Violins { outlines: Vec<Vec<(f64, f64)>>, style: ViolinStyle },
+    /// Points with error bars along x and y, see `graph::errorbar`
+    ErrorBars { points: Vec<(f64, f64)>, x_errors: Vec<Uncertainty>, y_errors: Vec<Uncertainty>, style: ErrorBarStyle },
+    /// The area between two lines filled, with an optional stroke along both
+    Band { lower: Vec<(f64, f64)>, upper: Vec<(f64, f64)>, fill: Color, stroke: Option<Stroke> },
+}
+
+impl SeriesKind {
+    /// Drawing order of the kind, lower layers are drawn first
+    fn layer(&self) -> u8 {
+        match *self {
+            SeriesKind::Bars { .. } | SeriesKind::Histogram { style: HistogramStyle::Bars(..), .. } => 0,
+            SeriesKind::Area { .. } | SeriesKind::Band { .. } | SeriesKind::Boxes { .. } | SeriesKind::Violins { .. } => 0,
+            SeriesKind::Histogram { .. } => 1,
+            SeriesKind::Line { .. } => 1,
+            SeriesKind::Scatter { .. } | SeriesKind::ErrorBars { .. } => 2,
+        }
+    }
+}
+
+/// A series with its label in the legend
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub kind: SeriesKind,
+    /// Name of the series, which may contain markup from `text::markup`, or `None` to leave it out of the legend
+    pub label: Option<String>,
+}
+
+impl Series {
+    /// A line through points in graph coordinates
+    pub fn line<S: Into<Stroke>>(points: Vec<(f64, f64)>, stroke: S) -> Series {
+        Series { kind: SeriesKind::Line { points: points, stroke: stroke.into() }, label: None }
+    }
+
+    /// A line through samples of `f` evenly spaced along the x domain
+    pub fn function<S: Into<Stroke>, F>(x_domain: Range<f64>, samples: usize, stroke: S, f: F) -> Series where F: Fn(f64) -> f64 {
+        Series::line(spaced(&x_domain, samples).into_iter().map(|x| (x, f(x))).collect(), stroke)
+    }
+
+    /// A line through samples of a parametric equation evenly spaced along the t domain
+    pub fn parametric<S: Into<Stroke>, F>(t_domain: Range<f64>, samples: usize, stroke: S, f: F) -> Series where F: Fn(f64) -> (f64, f64) {
+        Series::line(spaced(&t_domain, samples).into_iter().map(f).collect(), stroke)
+    }
+
+    /// A line through points given as angle and radius
+    pub fn polar<S: Into<Stroke>>(samples: Vec<(f64, f64)>, stroke: S) -> Series {
+        Series::line(samples.into_iter().map(|(a, r)| (r * a.cos(), r * a.sin())).collect(), stroke)
+    }
+
+    /// Markers at points in graph coordinates
+    pub fn scatter(points: Vec<(f64, f64)>, style: MarkerStyle) -> Series {
+        Series { kind: SeriesKind::Scatter { points: points, style: style, sizes: None, colors: None }, label: None }
+    }
+
+    /// Bars with one value per category, see `graph::bar`
+    ///
+    /// Bars are placed together with the other bar series of a graph, so they are only drawn by `render`, once
+    /// all of them have been added.
+    ///
+    /// ```
+    /// use rust_plot::buffer_plot::BufferPlot;
+    /// use rust_plot::color::Color;
+    /// use rust_plot::graph::Graph;
+    /// use rust_plot::graph::series::Series;
+    ///
+    /// let red = Color::rgb(1.0, 0.0, 0.0);
+    /// let graph = || {
+    ///     let mut graph = Graph::auto_ranged(BufferPlot::new(200, 200));
+    ///     graph.add(Series::bars(vec![1.0, 2.0], red, None));
+    ///     graph
+    /// };
+    ///
+    /// let is_red = |p: &[f32; 4]| *p == red.to_linear();
+    ///
+    /// assert!(!graph().into_plotter().pixels().iter().any(is_red));
+    ///
+    /// let mut rendered = graph();
+    /// rendered.render();
+    ///
+    /// assert!(rendered.into_plotter().pixels().iter().any(is_red));
+    /// ```
+    pub fn bars(values: Vec<f64>, fill: Color, stroke: Option<Stroke>) -> Series {
+        Series { kind: SeriesKind::Bars { values: values, fill: fill, stroke: stroke }, label: None }
+    }
+
+    /// The area between a line through points and zero, filled and optionally stroked along the line
+    pub fn area(points: Vec<(f64, f64)>, fill: Color, stroke: Option<Stroke>) -> Series {
+        Series { kind: SeriesKind::Area { points: points, fill: fill, stroke: stroke }, label: None }
+    }
+
+    /// Returns true for bar series, which are placed together with the other bar series of a graph
+    pub fn is_bars(&self) -> bool {
+        match self.kind {
+            SeriesKind::Bars { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Give every marker of a scatter series its own size in pixels, for bubble charts
+    ///
+    /// Does nothing for other kinds of series.
+    pub fn sizes(&mut self, values: Vec<f64>) -> &mut Series {
+        if let SeriesKind::Scatter { ref mut sizes, .. } = self.kind {
+            *sizes = Some(values);
+        }
+
+        self
+    }
+
+    /// Give every marker of a scatter series its own color, see `MarkerStyle::with_color`
+    ///
+    /// Does nothing for other kinds of series.
+    pub fn colors(&mut self, values: Vec<Color>) -> &mut Series {
+        if let SeriesKind::Scatter { ref mut colors, .. } = self.kind {
+            *colors = Some(values);
+        }
+
+        self
+    }
+
+    /// Set the label of the series, to show it in the legend
+    pub fn label(&mut self, label: &str) -> &mut Series {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Extents of the data along the x and y axes
+    ///
+    /// Filled areas include zero along y. Boxes and violins give the positions of their categories and their
+    /// values along the axes their orientation puts them on. Error bars include the bounds of their points.
+    ///
+    /// Bars give empty extents, as where their rectangles end depends on the `BarOptions` of the graph and on
+    /// the bar series stacked or grouped with them. `Graph::data_extent` covers the rectangles of all bars.
+    ///
+    /// ```
+    /// use rust_plot::buffer_plot::BufferPlot;
+    /// use rust_plot::color::Color;
+    /// use rust_plot::graph::Graph;
+    /// use rust_plot::graph::bar::{BarMode, Orientation};
+    /// use rust_plot::graph::series::Series;
+    ///
+    /// let mut graph = Graph::auto_ranged(BufferPlot::new(400, 300));
+    ///
+    /// graph.bar_options_mut().mode = BarMode::Stacked;
+    /// graph.bar_options_mut().orientation = Orientation::Horizontal;
+    ///
+    /// graph.add(Series::bars(vec![1.0, 2.0], Color::black(), None));
+    /// graph.add(Series::bars(vec![3.0, 1.0], Color::white(), None));
+    ///
+    /// assert!(graph.series()[0].extent().0.is_empty());
+    ///
+    /// let (x, y) = graph.data_extent();
+    /// assert_eq!((x.min, x.max), (0.0, 4.0));
+    /// assert_eq!((y.min, y.max), (-0.4, 1.4));
+    /// ```
+    pub fn extent(&self) -> (Extent, Extent) {
+        match self.kind {
+            SeriesKind::Line { ref points, .. } | SeriesKind::Scatter { ref points, .. } => {
+                (Extent::from_values(points.iter().map(|p| p.0)), Extent::from_values(points.iter().map(|p| p.1)))
+            }
+            SeriesKind::Bars { .. } => (Extent::new(), Extent::new()),
+            SeriesKind::Histogram { ref histogram, .. } => {
+                let mut y = Extent::from_values(histogram.values.iter().cloned());
+                y.include(0.0);
+
+                (Extent::from_values(histogram.edges.iter().cloned()), y)
+            }
+            SeriesKind::Area { ref points, .. } => {
+                let mut y = Extent::from_values(points.iter().map(|p| p.1));
+                y.include(0.0);
+
+                (Extent::from_values(points.iter().map(|p| p.0)), y)
+            }
+            SeriesKind::Boxes { ref stats, style } => {
+                let (mut categories, mut values) = (Extent::new(), Extent::new());
+
+                for (i, stats) in stats.iter().enumerate() {
+                    if let Some(ref stats) = *stats {
+                        let (min, max) = stats.range();
+
+                        categories.include(i as f64);
+                        values.include(min);
+                        values.include(max);
+                    }
+                }
+
+                match style.orientation {
+                    Orientation::Vertical => (categories, values),
+                    Orientation::Horizontal => (values, categories),
+                }
+            }
+            SeriesKind::ErrorBars { ref points, ref x_errors, ref y_errors, .. } => {
+                let (mut x, mut y) = (Extent::new(), Extent::new());
+
+                for (i, &(px, py)) in points.iter().enumerate() {
+                    let (x0, x1) = x_errors.get(i).map_or((px, px), |e| e.bounds(px));
+                    let (y0, y1) = y_errors.get(i).map_or((py, py), |e| e.bounds(py));
+
+                    // The bars of points that aren't finite aren't drawn
+                    if px.is_finite() && py.is_finite() {
+                        x.union(&Extent::from_values(vec![x0, x1]));
+                        y.union(&Extent::from_values(vec![y0, y1]));
+                    }
+                }
+
+                (x, y)
+            }
+            SeriesKind::Band { ref lower, ref upper, .. } => {
+                let points = || lower.iter().chain(upper);
+
+                (Extent::from_values(points().map(|p| p.0)), Extent::from_values(points().map(|p| p.1)))
+            }
+            SeriesKind::Violins { ref outlines, style } => {
+                let categories = Extent::from_values(outlines.iter().enumerate().filter(|&(_, o)| !o.is_empty()).map(|(i, _)| i as f64));
+                let values = Extent::from_values(outlines.iter().flat_map(|o| o.iter().map(|p| p.0)));
+
+                match style.orientation {
+                    Orientation::Vertical => (categories, values),
+                    Orientation::Horizontal => (values, categories),
+                }
+            }
+        }
+    }
+
+    /// How the series is shown in the legend
+    pub fn swatch(&self) -> Swatch {
+        match self.kind {
+            SeriesKind::Line { stroke, .. } => Swatch::Line(stroke),
+            SeriesKind::Scatter { style, .. } => Swatch::Marker(style),
+            SeriesKind::Bars { fill, stroke, .. } => Swatch::Fill(fill, stroke),
+            SeriesKind::Histogram { style: HistogramStyle::Bars(fill, stroke), .. } => Swatch::Fill(fill, stroke),
+            SeriesKind::Histogram { style: HistogramStyle::Step(stroke), .. } => Swatch::Line(stroke),
+            SeriesKind::Area { fill, stroke, .. } => Swatch::Fill(fill, stroke),
+            SeriesKind::Boxes { style, .. } => match style.fill {
+                Some(fill) => Swatch::Fill(fill, Some(style.stroke)),
+                None => Swatch::Line(style.stroke),
+            },
+            SeriesKind::Violins { style, .. } => Swatch::Fill(style.fill, style.stroke),
+            SeriesKind::ErrorBars { style, .. } => match style.marker {
+                Some(marker) => Swatch::Marker(marker),
+                None => Swatch::Line(style.stroke),
+            },
+            SeriesKind::Band { fill, stroke, .. } => Swatch::Fill(fill, stroke),
+        }
+    }
+}
+
+impl<P: Canvas> Graph<P> {
+    /// Add a series to draw with `render`, returning it so it can be labeled
+    pub fn add(&mut self, series: Series) -> &mut Series {
+        self.series.push(series);
+        self.series.last_mut().unwrap()
+    }
+
+    /// All series added so far, in the order they were added
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    pub fn clear_series(&mut self) {
+        self.series.clear();
+    }
+
+    /// Set the stroke of the frame and ticks drawn by `render`
+    pub fn set_axis_stroke<S: Into<Stroke>>(&mut self, stroke: S) {
+        self.axis_stroke = stroke.into();
+    }
+
+    /// Fit the axes, then draw the grid if it is visible, all added series, the axes and the legend
+    ///
+    /// Series drawn directly before rendering stay in the legend. The added series are kept, so more can be
+    /// added and the graph rendered again. Rendering draws over whatever the canvas shows already, so render
+    /// again onto a fresh canvas from `set_plotter`.
+    ///
+    /// ```
+    /// use rust_plot::buffer_plot::BufferPlot;
+    /// use rust_plot::graph::Graph;
+    /// use rust_plot::graph::series::Series;
+    /// use rust_plot::plot::LineStyle;
+    ///
+    /// let rising = || Series::line(vec![(0.0, 0.0), (1.0, 1.0)], LineStyle::Thin);
+    /// let falling = || Series::line(vec![(0.0, 1.0), (1.0, 0.0)], LineStyle::Thin);
+    ///
+    /// let mut graph = Graph::auto_ranged(BufferPlot::new(300, 200));
+    ///
+    /// graph.add(rising()).label("rising");
+    /// graph.render();
+    ///
+    /// graph.clear_series();
+    /// graph.add(falling()).label("falling");
+    /// graph.set_plotter(BufferPlot::new(300, 200));
+    /// graph.render();
+    ///
+    /// // The legend avoids only the falling line, as if it had been drawn alone
+    /// let mut alone = Graph::auto_ranged(BufferPlot::new(300, 200));
+    ///
+    /// alone.add(falling()).label("falling");
+    /// alone.render();
+    ///
+    /// assert!(graph.into_plotter().pixels() == alone.into_plotter().pixels());
+    /// ```
+    pub fn render(&mut self) {
+        self.refit();
+
+        let series = mem::replace(&mut self.series, Vec::new());
+
+        // The legend and the footprint get the added series only while rendering, so rendering twice doesn't
+        // repeat them, while series drawn directly are kept
+        let legend = self.legend.clone();
+        let footprint = self.footprint.clone();
+
+        for s in &series {
+            self.legend.add(s.swatch()).label = s.label.clone();
+        }
+
+        if self.grid.visible {
+            self.draw_grid();
+        }
+
+        let layout = self.layout();
+        let mapping = layout.mapping(&self.x_domain, &self.y_domain, self.x_axis.scale, self.y_axis.scale);
+
+        // Bars are placed together, so they are drawn from the rectangles of all of them
+        let mut bars = self.bar_options.layout(&series).into_iter();
+
+        let mut order: Vec<(&Series, Option<Vec<Rect>>)> = series.iter().map(|s| {
+            (s, if s.is_bars() { bars.next() } else { None })
+        }).collect();
+
+        order.sort_by_key(|&(s, _)| s.kind.layer());
+
+        for (s, rects) in order {
+            match (&s.kind, rects) {
+                (&SeriesKind::Bars { fill, stroke, .. }, Some(rects)) => {
+                    self.draw_bars(&rects, fill, stroke, &mapping, &layout.plot_area)
+                }
+                _ => self.plot_series(s, &mapping, &layout.plot_area),
+            }
+        }
+
+        let axis_stroke = self.axis_stroke;
+
+        self.draw_axis(axis_stroke);
+
+        self.draw_legend();
+
+        self.legend = legend;
+        self.footprint = footprint;
+        self.series = series;
+    }
+}
@@ -1,78 +1,150 @@
 use image::{ImageBuffer, Rgba, RgbaImage};
 
 use ::utils::clamp;
+use ::color::{srgb_to_linear, linear_to_srgb, quantize_u8, blend_over_linear, Dither, Color};
 use ::plot::Plotter;
+use ::plot::clip::ClipStack;
 
 pub type RgbaFloatImage = ImageBuffer<Rgba<f32>, Vec<f32>>;
 
+/// Plotter that draws onto a floating point RGBA image.
+///
+/// Colors given to and returned from the plotter are sRGB encoded, but the image itself
+/// is stored and blended in linear light, so anti-aliased edges keep their apparent weight.
+/// The linear image is encoded back to sRGB when converted with `into_u8_component_image`.
 pub struct ImagePlot {
     image: RgbaFloatImage,
     foreground: Rgba<f32>,
+    foreground_linear: Rgba<f32>,
+    clip: ClipStack,
+}
+
+/// Converts an sRGB encoded color to linear light, leaving alpha untouched.
+pub fn srgb_to_linear_rgba(color: Rgba<f32>) -> Rgba<f32> {
+    Rgba {
+        data: [
+            srgb_to_linear(color.data[0]),
+            srgb_to_linear(color.data[1]),
+            srgb_to_linear(color.data[2]),
+            color.data[3]
+        ]
+    }
+}
+
+/// Converts a linear light color to the sRGB encoding, leaving alpha untouched.
+pub fn linear_to_srgb_rgba(color: Rgba<f32>) -> Rgba<f32> {
+    Rgba {
+        data: [
+            linear_to_srgb(color.data[0]),
+            linear_to_srgb(color.data[1]),
+            linear_to_srgb(color.data[2]),
+            color.data[3]
+        ]
+    }
+}
+
+impl From<Color> for Rgba<f32> {
+    fn from(color: Color) -> Rgba<f32> {
+        Rgba { data: [color.r, color.g, color.b, color.a] }
+    }
+}
+
+impl From<Rgba<f32>> for Color {
+    fn from(color: Rgba<f32>) -> Color {
+        Color::rgba(color.data[0], color.data[1], color.data[2], color.data[3])
+    }
 }
 
 impl ImagePlot {
     pub fn new(width: u32, height: u32) -> ImagePlot {
-        ImagePlot {
-            image: RgbaFloatImage::from_pixel(width, height, Rgba {
-                data: [1.0, 1.0, 1.0, 1.0]
-            }),
-            foreground: Rgba { data: [0.0, 0.0, 0.0, 1.0] },
-        }
+        ImagePlot::with_background(width, height, Rgba { data: [1.0, 1.0, 1.0, 1.0] })
     }
 
+    /// Create a new plot filled with the given sRGB background color
     pub fn with_background(width: u32, height: u32, background: Rgba<f32>) -> ImagePlot {
+        let foreground = Rgba { data: [0.0, 0.0, 0.0, 1.0] };
+
         ImagePlot {
-            image: RgbaFloatImage::from_pixel(width, height, background),
-            foreground: Rgba { data: [0.0, 0.0, 0.0, 1.0] },
+            image: RgbaFloatImage::from_pixel(width, height, srgb_to_linear_rgba(background)),
+            foreground: foreground,
+            foreground_linear: srgb_to_linear_rgba(foreground),
+            clip: ClipStack::new(),
         }
     }
 
+    /// Set the sRGB color used for drawing, returning the previous color
     pub fn set_foreground(&mut self, foreground: Rgba<f32>) -> Rgba<f32> {
+        self.foreground_linear = srgb_to_linear_rgba(foreground);
+
         ::std::mem::replace(&mut self.foreground, foreground)
     }
 
+    /// Get the sRGB color used for drawing
     pub fn foreground(&self) -> Rgba<f32> {
         self.foreground
     }
 
+    /// The underlying image, in linear light
     pub fn image(&self) -> &RgbaFloatImage { &self.image }
 
+    /// The underlying image, in linear light
     pub fn image_mut(&mut self) -> &mut RgbaFloatImage { &mut self.image }
 
+    /// Consumes the plot and returns the underlying image, in linear light
     pub fn into_image(self) -> RgbaFloatImage {
         self.image
     }
 
-    pub fn into_u8_component_image(self) -> RgbaImage {
+    /// Consumes the plot and returns the image encoded as sRGB
+    pub fn into_srgb_image(self) -> RgbaFloatImage {
         let (w, h) = self.image.dimensions();
-        RgbaImage::from_raw(w, h, self.image.into_raw().into_iter().map(|subpixel| (subpixel * 255.0) as u8).collect()).unwrap()
-    }
-}
 
-fn blend_over(top: Rgba<f32>, bottom: Rgba<f32>) -> Rgba<f32> {
-    fn over_component(x: f32, y: f32, a: f32, b: f32) -> f32 {
-        let a1 = 1.0 - a;
-        (x * a + y * b * a1) / (a + b * a1)
+        RgbaFloatImage::from_fn(w, h, |x, y| linear_to_srgb_rgba(*self.image.get_pixel(x, y)))
     }
 
-    let top_alpha = top.data[3];
-    let bottom_alpha = bottom.data[3];
+    /// Consumes the plot and returns an 8-bit sRGB image, rounding and clamping each component
+    pub fn into_u8_component_image(self) -> RgbaImage {
+        self.into_u8_component_image_dithered(Dither::None)
+    }
 
-    Rgba {
-        data: [
-            over_component(top.data[0], bottom.data[0], top_alpha, bottom_alpha),
-            over_component(top.data[1], bottom.data[1], top_alpha, bottom_alpha),
-            over_component(top.data[2], bottom.data[2], top_alpha, bottom_alpha),
-            top_alpha + bottom_alpha * (1.0 - top_alpha)
-        ]
+    /// Consumes the plot and returns an 8-bit sRGB image, dithering each component before quantization
+    pub fn into_u8_component_image_dithered(self, dither: Dither) -> RgbaImage {
+        linear_image_to_u8_srgb(&self.image, dither)
     }
 }
 
+/// Encodes a linear light float image to an 8-bit sRGB image
+pub fn linear_image_to_u8_srgb(image: &RgbaFloatImage, dither: Dither) -> RgbaImage {
+    let (w, h) = image.dimensions();
+
+    RgbaImage::from_fn(w, h, |x, y| {
+        let p = image.get_pixel(x, y);
+        let d = dither.offset(x, y);
+
+        Rgba {
+            data: [
+                quantize_u8(linear_to_srgb(clamp(p.data[0], 0.0, 1.0)), d),
+                quantize_u8(linear_to_srgb(clamp(p.data[1], 0.0, 1.0)), d),
+                quantize_u8(linear_to_srgb(clamp(p.data[2], 0.0, 1.0)), d),
+                quantize_u8(p.data[3], d),
+            ]
+        }
+    })
+}
+
+fn blend_over(top: Rgba<f32>, bottom: Rgba<f32>) -> Rgba<f32> {
+    Rgba { data: blend_over_linear(top.data, bottom.data) }
+}
+
 impl Plotter for ImagePlot {
     fn width(&self) -> u32 { self.image.width() }
 
     fn height(&self) -> u32 { self.image.height() }
 
+    fn set_color(&mut self, color: Color) {
+        self.set_foreground(color.into());
+    }
+
     fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
         let (w, h) = (self.width() as i64, self.height() as i64);
 
@@ -80,14 +152,18 @@ impl Plotter for ImagePlot {
             let x = x as u32;
             let y = (h - y - 1) as u32;
 
-            let mut p = self.image.get_pixel_mut(x, y);
+            let p = self.image.get_pixel_mut(x, y);
+
+            let foreground = self.foreground_linear;
 
             *p = blend_over(Rgba {
                 data: [
-                    self.foreground.data[0], self.foreground.data[1], self.foreground.data[2],
-                    clamp(alpha, 0.0, 1.0) as f32 * self.foreground[3]
+                    foreground.data[0], foreground.data[1], foreground.data[2],
+                    clamp(alpha, 0.0, 1.0) as f32 * foreground.data[3]
                 ]
-            }, p.clone());
+            }, *p);
         }
     }
-}
\ No newline at end of file
+
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> { Some(&mut self.clip) }
+}
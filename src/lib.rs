@@ -1,18 +1,40 @@
-extern crate nalgebra;
 extern crate num_traits;
 extern crate rand;
 
 #[cfg(feature = "image_compat")]
 extern crate image;
 
+#[cfg(feature = "3d_graph")]
+extern crate nalgebra;
+
+#[cfg(feature = "truetype")]
+extern crate rusttype;
+
 pub mod utils;
+pub mod color;
 pub mod stat;
 pub mod geometry;
+pub mod transform;
 pub mod bezier;
 pub mod plot;
+pub mod canvas;
+pub mod text;
+pub mod vector;
+pub mod svg;
+pub mod pdf;
+pub mod eps;
+pub mod terminal;
+pub mod pnm;
+pub mod png;
+pub mod buffer_plot;
 pub mod sampling;
 pub mod graph;
+
+#[cfg(feature = "3d_graph")]
 pub mod graph_3d;
 
 #[cfg(feature = "image_compat")]
-pub mod image_plot;
\ No newline at end of file
+pub mod image_plot;
+
+#[cfg(feature = "image_compat")]
+pub mod supersample;
@@ -0,0 +1,373 @@
+//! PDF vector output
+//!
+//! `PdfPlot` is a `Canvas` that writes a single page PDF document without any compression or
+//! embedded font programs, so the output stays small and easy to inspect. Text uses the standard
+//! fonts every PDF reader provides, in the WinAnsi encoding, with Greek letters and mathematical
+//! symbols taken from the Symbol font. Every font used gets its own resource, so changing the font
+//! only affects text drawn afterwards.
+//!
+//! One pixel of the canvas is one PostScript point in the document.
+//!
+//! ```
+//! use rust_plot::canvas::{Canvas, TextStyle};
+//! use rust_plot::pdf::PdfPlot;
+//! use rust_plot::vector::StandardFont;
+//!
+//! let mut plot = PdfPlot::new(300, 200);
+//!
+//! plot.push_clip_rect(10.0, 10.0, 290.0, 190.0);
+//! plot.push_clip_rect(20.0, 20.0, 280.0, 180.0);
+//! plot.text(30.0, 30.0, "−1.5", &TextStyle::new(12.0));
+//! plot.pop_clip();
+//! plot.set_font(StandardFont::Courier);
+//! plot.text(30.0, 50.0, "σ = 2", &TextStyle::new(12.0));
+//!
+//! let document = plot.document();
+//! let text = String::from_utf8_lossy(&document).into_owned();
+//!
+//! // Negative numbers keep their sign, Greek letters come from the Symbol font
+//! assert!(text.contains("/F1 12 Tf\n(-1.5) Tj"));
+//! assert!(text.contains("/F2 12 Tf\n(s) Tj\n/F3 12 Tf\n( = 2) Tj"));
+//! assert!(text.contains("/F1 5 0 R /F2 6 0 R /F3 7 0 R"));
+//! assert!(text.contains("/BaseFont /Helvetica /Encoding /WinAnsiEncoding"));
+//! assert!(text.contains("/BaseFont /Symbol >>"));
+//! assert!(text.contains("/BaseFont /Courier /Encoding /WinAnsiEncoding"));
+//!
+//! // Every object starts where the cross-reference table says it does
+//! let xref: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+//! let table = String::from_utf8_lossy(&document[xref..]).into_owned();
+//! let entries: Vec<&str> = table.lines().skip(3).take_while(|line| line.ends_with(" n ")).collect();
+//!
+//! for (i, entry) in entries.iter().enumerate() {
+//!     let offset: usize = entry[..10].parse().unwrap();
+//!
+//!     assert!(document[offset..].starts_with(format!("{} 0 obj\n", i + 1).as_bytes()));
+//! }
+//!
+//! assert_eq!(entries.len(), text.matches(" 0 obj\n").count());
+//! assert!(table.starts_with(&format!("xref\n0 {}\n", entries.len() + 1)));
+//! assert!(table.contains(&format!("/Size {} ", entries.len() + 1)));
+//!
+//! // Clips left open are closed, so saving and restoring the graphics state is balanced
+//! let count = |operator: &str| text.lines().filter(|line| *line == operator).count();
+//! assert_eq!(count("q"), count("Q"));
+//! ```
+//!
+//! Negative tick labels of graphs are written with a minus sign, which comes out as a hyphen:
+//!
+//! ```
+//! use rust_plot::graph::Graph;
+//! use rust_plot::graph::rectangular::RectangularGraph;
+//! use rust_plot::pdf::PdfPlot;
+//! use rust_plot::plot::LineStyle;
+//!
+//! let mut graph = Graph::with_plotter(PdfPlot::new(400, 300), -2.0..2.0, -1.0..1.0);
+//!
+//! graph.draw_axis(LineStyle::Thin);
+//!
+//! let document = String::from_utf8_lossy(&graph.into_plotter().document()).into_owned();
+//!
+//! assert!(document.contains("(-2.0) Tj") && document.contains("(-0.5) Tj"));
+//! assert!(!document.contains("?"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use ::color::Color;
+use ::canvas::{Canvas, Stroke, TextStyle, TextExtent};
+use ::vector::{format_number, ellipse_beziers, string_literal, StandardFont};
+
+/// Canvas that records primitives as PDF content stream operators
+pub struct PdfPlot {
+    width: u32,
+    height: u32,
+    font: StandardFont,
+    content: String,
+    /// Graphics states for each combination of fill and stroke opacity, in thousandths
+    opacities: BTreeMap<(u16, u16), usize>,
+    /// Fonts used by text so far, in the order of their resources
+    fonts: Vec<StandardFont>,
+    clip_depth: usize,
+}
+
+/// Formats a point as a pair of operands
+fn point(x: f64, y: f64) -> String {
+    format!("{} {}", format_number(x), format_number(y))
+}
+
+fn color_operands(color: Color) -> String {
+    format!("{} {} {}", format_number(color.r as f64), format_number(color.g as f64), format_number(color.b as f64))
+}
+
+fn opacity_key(alpha: f32) -> u16 {
+    (::utils::clamp(alpha, 0.0, 1.0) * 1000.0).round() as u16
+}
+
+impl PdfPlot {
+    /// Create a new document with a transparent page
+    pub fn new(width: u32, height: u32) -> PdfPlot {
+        PdfPlot {
+            width: width,
+            height: height,
+            font: StandardFont::Helvetica,
+            content: String::new(),
+            opacities: BTreeMap::new(),
+            fonts: Vec::new(),
+            clip_depth: 0,
+        }
+    }
+
+    /// Create a new document with the page filled by a background color
+    pub fn with_background(width: u32, height: u32, background: Color) -> PdfPlot {
+        let mut plot = PdfPlot::new(width, height);
+
+        plot.rect(0.0, 0.0, width as f64, height as f64, Some(background), None);
+
+        plot
+    }
+
+    /// Set the font used for text drawn from now on
+    pub fn set_font(&mut self, font: StandardFont) {
+        self.font = font;
+    }
+
+    /// Sets the fill and stroke opacity, if either is translucent
+    fn set_opacity(&mut self, fill: f32, stroke: f32) {
+        let key = (opacity_key(fill), opacity_key(stroke));
+
+        if key != (1000, 1000) {
+            let next = self.opacities.len();
+            let index = *self.opacities.entry(key).or_insert(next);
+
+            writeln!(self.content, "/GS{} gs", index).unwrap();
+        }
+    }
+
+    /// Name of the font resource of a font, adding it if it is used for the first time
+    fn font_resource(&mut self, font: StandardFont) -> String {
+        let index = match self.fonts.iter().position(|&f| f == font) {
+            Some(index) => index,
+            None => {
+                self.fonts.push(font);
+                self.fonts.len() - 1
+            }
+        };
+
+        format!("/F{}", index + 1)
+    }
+
+    fn set_stroke(&mut self, stroke: Stroke) {
+        writeln!(self.content, "{} RG {} w 1 j", color_operands(stroke.color), format_number(stroke.width())).unwrap();
+
+        match stroke.dash {
+            Some((on, off)) => writeln!(self.content, "0 J [{} {}] 0 d", format_number(on), format_number(off)).unwrap(),
+            None => self.content.push_str("1 J\n"),
+        }
+    }
+
+    fn path(&mut self, contours: &[&[(f64, f64)]]) {
+        for contour in contours {
+            for (i, &(x, y)) in contour.iter().enumerate() {
+                writeln!(self.content, "{} {}", point(x, y), if i == 0 { "m" } else { "l" }).unwrap();
+            }
+
+            if !contour.is_empty() {
+                self.content.push_str("h\n");
+            }
+        }
+    }
+
+    /// Paints the current path with the given fill and stroke, and restores the graphics state
+    fn paint(&mut self, fill: Option<Color>, stroke: Option<Stroke>) {
+        let operator = match (fill.is_some(), stroke.is_some()) {
+            (true, true) => "B",
+            (true, false) => "f",
+            (false, true) => "S",
+            (false, false) => "n",
+        };
+
+        writeln!(self.content, "{}\nQ", operator).unwrap();
+    }
+
+    /// Saves the graphics state and sets up colors for a filled and/or stroked path
+    fn begin(&mut self, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.content.push_str("q\n");
+
+        self.set_opacity(fill.map_or(1.0, |c| c.a), stroke.map_or(1.0, |s| s.color.a));
+
+        if let Some(color) = fill {
+            writeln!(self.content, "{} rg", color_operands(color)).unwrap();
+        }
+
+        if let Some(stroke) = stroke {
+            self.set_stroke(stroke);
+        }
+    }
+
+    /// The complete PDF document
+    pub fn document(&self) -> Vec<u8> {
+        let mut content = self.content.clone();
+
+        for _ in 0..self.clip_depth {
+            content.push_str("Q\n");
+        }
+
+        let mut states = String::new();
+
+        for (&(fill, stroke), index) in &self.opacities {
+            write!(states, "/GS{} << /Type /ExtGState /ca {} /CA {} >> ", index,
+                   format_number(fill as f64 / 1000.0), format_number(stroke as f64 / 1000.0)).unwrap();
+        }
+
+        // The fonts follow the four fixed objects
+        let mut fonts = String::new();
+
+        for i in 0..self.fonts.len() {
+            write!(fonts, "/F{} {} 0 R ", i + 1, i + 5).unwrap();
+        }
+
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_owned(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_owned(),
+            format!("<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Contents 4 0 R \
+                     /Resources << /Font << {}>> /ExtGState << {}>> >> >>",
+                    self.width, self.height, fonts, states),
+            format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content),
+        ];
+
+        for &font in &self.fonts {
+            objects.push(match font {
+                // The Symbol font has its own encoding
+                StandardFont::Symbol => format!("<< /Type /Font /Subtype /Type1 /BaseFont /{} >>", font.name()),
+                _ => format!("<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>", font.name()),
+            });
+        }
+
+        let mut document: Vec<u8> = Vec::new();
+
+        // The binary comment marks the file as containing 8-bit data for transfer programs
+        document.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+        let mut offsets = Vec::with_capacity(objects.len());
+
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(document.len());
+            write!(document, "{} 0 obj\n{}\nendobj\n", i + 1, object).unwrap();
+        }
+
+        let xref = document.len();
+
+        write!(document, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).unwrap();
+
+        for offset in offsets {
+            write!(document, "{:010} 00000 n \n", offset).unwrap();
+        }
+
+        write!(document, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", objects.len() + 1, xref).unwrap();
+
+        document
+    }
+
+    /// Write the PDF document to a writer
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.document())
+    }
+
+    /// Write the PDF document to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(&mut File::create(path)?)
+    }
+}
+
+impl Canvas for PdfPlot {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke) {
+        if points.len() < 2 { return; }
+
+        self.begin(None, Some(stroke));
+
+        for (i, &(x, y)) in points.iter().enumerate() {
+            writeln!(self.content, "{} {}", point(x, y), if i == 0 { "m" } else { "l" }).unwrap();
+        }
+
+        self.paint(None, Some(stroke));
+    }
+
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>) {
+        self.begin(fill, stroke);
+        self.path(contours);
+        self.paint(fill, stroke);
+    }
+
+    fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.begin(fill, stroke);
+        writeln!(self.content, "{} {} {} re", point(x0, y0), format_number(x1 - x0), format_number(y1 - y0)).unwrap();
+        self.paint(fill, stroke);
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        let (start, curves) = ellipse_beziers(cx, cy, rx, ry);
+
+        self.begin(fill, stroke);
+
+        writeln!(self.content, "{} m", point(start.0, start.1)).unwrap();
+
+        for curve in &curves {
+            writeln!(self.content, "{} {} {} c", point(curve[0].0, curve[0].1),
+                     point(curve[1].0, curve[1].1), point(curve[2].0, curve[2].1)).unwrap();
+        }
+
+        self.content.push_str("h\n");
+        self.paint(fill, stroke);
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle) {
+        let extent = self.text_extent(text, style);
+        let (dx, dy) = extent.anchor_offset(style.anchor);
+
+        let (sin, cos) = style.rotation.sin_cos();
+
+        // Offset from the anchor to the start of the baseline, rotated along with the text
+        let (tx, ty) = (x + dx * cos - dy * sin, y + dx * sin + dy * cos);
+
+        self.content.push_str("q\n");
+        self.set_opacity(style.color.a, 1.0);
+
+        writeln!(self.content, "{} rg\nBT\n{} {} {} {} {} Tm", color_operands(style.color),
+                 format_number(cos), format_number(sin), format_number(-sin), format_number(cos), point(tx, ty)).unwrap();
+
+        // Showing a string moves to its end, so the runs follow each other
+        for (font, bytes) in self.font.runs(text) {
+            let resource = self.font_resource(font);
+
+            writeln!(self.content, "{} {} Tf\n{} Tj", resource, format_number(style.size), string_literal(&bytes)).unwrap();
+        }
+
+        self.content.push_str("ET\nQ\n");
+    }
+
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        self.font.extent(text, style.size)
+    }
+
+    fn push_clip_polygon(&mut self, points: &[(f64, f64)]) {
+        self.content.push_str("q\n");
+        self.path(&[points]);
+        self.content.push_str("W n\n");
+        self.clip_depth += 1;
+    }
+
+    fn pop_clip(&mut self) {
+        if self.clip_depth > 0 {
+            self.content.push_str("Q\n");
+            self.clip_depth -= 1;
+        }
+    }
+}
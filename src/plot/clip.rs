@@ -0,0 +1,268 @@
+//! Restricting drawing to a region of a plotter
+//!
+//! Regions are pushed through `Canvas::push_clip_rect` and `Canvas::push_clip_polygon`, and every pushed region
+//! is intersected with the ones below it. Plotters clip canvas primitives geometrically to a `ClipStack` of
+//! convex regions before rasterizing them, widening concave regions to their bounding boxes. `ClipPlot` wraps a
+//! plotter to clip its pixels instead: rectangles clip to whole pixels, while polygons are rasterized into an
+//! anti-aliased coverage mask, so concave, curved or diagonal clip edges stay smooth, and text is cut off at the
+//! edge rather than left out.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::canvas::{Canvas, Stroke};
+//! use rust_plot::color::Color;
+//! use rust_plot::plot::{LineStyle, Plotter};
+//!
+//! let mut plot = BufferPlot::new(100, 100);
+//!
+//! plot.push_clip_rect(20.0, 20.0, 80.0, 80.0);
+//! plot.line(0.0, 50.0, 100.0, 50.0, Stroke::new(Color::black(), LineStyle::Thin));
+//! plot.pop_clip();
+//!
+//! assert_eq!(plot.clip_stack().unwrap().depth(), 0);
+//! // Rows are stored from the top
+//! let row = &plot.pixels()[49 * 100..50 * 100];
+//!
+//! assert_eq!(row[10], [1.0, 1.0, 1.0, 1.0]);
+//! assert_eq!(row[50], [0.0, 0.0, 0.0, 1.0]);
+//! ```
+
+use std::f64::{INFINITY, NEG_INFINITY};
+
+use ::color::Color;
+use ::geometry::{Rect, clip_line, clip_polygon, is_convex};
+
+use super::{fill, line, LineStyle, Plotter};
+
+/// Stack of convex clipping regions, which plotters clip canvas primitives to geometrically
+///
+/// Every region is stored intersected with the ones below it. Regions are kept convex by widening concave or
+/// self-intersecting polygons to their bounding boxes, use `ClipPlot` to clip to those exactly.
+///
+/// ```
+/// use rust_plot::plot::clip::ClipStack;
+///
+/// let mut stack = ClipStack::new();
+/// stack.push(&[(0.0, 0.0), (4.0, 0.0), (2.0, 1.0), (4.0, 2.0), (0.0, 2.0)]);
+///
+/// assert_eq!(stack.region(), Some(&[(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)][..]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClipStack {
+    regions: Vec<Vec<(f64, f64)>>,
+}
+
+impl ClipStack {
+    pub fn new() -> ClipStack {
+        ClipStack { regions: Vec::new() }
+    }
+
+    /// Push a region, intersected with the current one
+    pub fn push(&mut self, points: &[(f64, f64)]) {
+        let points = if is_convex(points) { points.to_vec() } else { bounding_box(points) };
+
+        let region = match self.regions.last() {
+            Some(current) => clip_polygon(&points, current),
+            None => points,
+        };
+
+        self.regions.push(region);
+    }
+
+    /// Remove the most recently pushed region
+    pub fn pop(&mut self) {
+        self.regions.pop();
+    }
+
+    /// Number of regions currently pushed
+    pub fn depth(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// The current region, or `None` if nothing is clipped
+    ///
+    /// An empty intersection leaves a region of fewer than three points, which contains nothing.
+    pub fn region(&self) -> Option<&[(f64, f64)]> {
+        self.regions.last().map(|region| &region[..])
+    }
+
+    /// Whether a point lies inside the current region, which every point does if nothing is clipped
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let region = match self.region() {
+            Some(region) => region,
+            None => return true,
+        };
+
+        if region.len() < 3 {
+            return false;
+        }
+
+        let mut sides = region.iter().zip(region.iter().cycle().skip(1))
+            .map(|(&(x0, y0), &(x1, y1))| (x1 - x0) * (y - y0) - (y1 - y0) * (x - x0));
+
+        // Inside a convex polygon, the point lies on the same side of every edge
+        sides.clone().all(|side| side >= 0.0) || sides.all(|side| side <= 0.0)
+    }
+}
+
+/// Corners of the smallest axis-aligned rectangle around the points
+fn bounding_box(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let (x0, y0, x1, y1) = points.iter().fold((INFINITY, INFINITY, NEG_INFINITY, NEG_INFINITY), |(x0, y0, x1, y1), &(x, y)| {
+        (x0.min(x), y0.min(y), x1.max(x), y1.max(y))
+    });
+
+    vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1)]
+}
+
+struct ClipRegion {
+    /// Pixels outside of this rectangle are never drawn
+    bounds: Rect,
+    /// Coverage of every pixel of the plotter, row by row from the bottom
+    mask: Option<Vec<f32>>,
+}
+
+/// Plotter adapter that clips drawing to a stack of rectangles and polygons
+pub struct ClipPlot<P: Plotter> {
+    plotter: P,
+    stack: Vec<ClipRegion>,
+}
+
+impl<P: Plotter> ClipPlot<P> {
+    pub fn new(plotter: P) -> ClipPlot<P> {
+        ClipPlot { plotter: plotter, stack: Vec::new() }
+    }
+
+    pub fn inner(&self) -> &P { &self.plotter }
+
+    pub fn inner_mut(&mut self) -> &mut P { &mut self.plotter }
+
+    pub fn into_inner(self) -> P { self.plotter }
+
+    /// Number of clipping regions currently pushed
+    pub fn clip_depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Bounding rectangle of the current clipping region, or `None` if nothing is clipped
+    pub fn clip_bounds(&self) -> Option<Rect> {
+        self.stack.last().map(|region| region.bounds)
+    }
+
+    /// Bounds of the current region, or of the whole plotter
+    fn current_bounds(&self) -> Rect {
+        self.clip_bounds().unwrap_or_else(|| {
+            Rect::new(0.0, 0.0, self.plotter.width() as f64 - 1.0, self.plotter.height() as f64 - 1.0)
+        })
+    }
+
+    /// Restrict all following drawing to a polygon made of several contours, filled with the nonzero winding rule,
+    /// until the matching `Canvas::pop_clip`
+    pub fn push_clip_compound_polygon(&mut self, contours: &[&[(f64, f64)]]) {
+        let (w, h) = (self.plotter.width(), self.plotter.height());
+
+        let mut mask = vec![0.0f32; w as usize * h as usize];
+
+        fill::fill_compound_polygon(contours, w, h, |x, y, alpha| {
+            if 0 <= x && x < w as i64 && 0 <= y && y < h as i64 {
+                let i = (y * w as i64 + x) as usize;
+
+                mask[i] = (mask[i] + alpha as f32).min(1.0);
+            }
+        });
+
+        let (mut x0, mut y0, mut x1, mut y1) = (::std::f64::INFINITY, ::std::f64::INFINITY,
+                                                ::std::f64::NEG_INFINITY, ::std::f64::NEG_INFINITY);
+
+        for &(x, y) in contours.iter().flat_map(|contour| contour.iter()) {
+            x0 = x0.min(x);
+            y0 = y0.min(y);
+            x1 = x1.max(x);
+            y1 = y1.max(y);
+        }
+
+        // Partially covered pixels along the edges are left to the mask
+        let bounds = if x0 <= x1 && y0 <= y1 {
+            self.current_bounds().intersection(&Rect::new(x0.floor(), y0.floor(), x1.ceil(), y1.ceil()))
+        } else {
+            None
+        };
+
+        let bounds = bounds.unwrap_or(Rect { x0: 0.0, y0: 0.0, x1: -1.0, y1: -1.0 });
+
+        if let Some(previous) = self.stack.last().and_then(|region| region.mask.as_ref()) {
+            for (m, p) in mask.iter_mut().zip(previous.iter()) {
+                *m *= *p;
+            }
+        }
+
+        self.stack.push(ClipRegion { bounds: bounds, mask: Some(mask) });
+    }
+}
+
+impl<P: Plotter> Plotter for ClipPlot<P> {
+    fn width(&self) -> u32 { self.plotter.width() }
+
+    fn height(&self) -> u32 { self.plotter.height() }
+
+    fn set_color(&mut self, color: Color) {
+        self.plotter.set_color(color)
+    }
+
+    fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
+        let alpha = match self.stack.last() {
+            None => alpha,
+            Some(region) => {
+                if !region.bounds.contains(x as f64, y as f64) {
+                    return;
+                }
+
+                match region.mask {
+                    Some(ref mask) => alpha * mask[(y * self.plotter.width() as i64 + x) as usize] as f64,
+                    None => alpha,
+                }
+            }
+        };
+
+        if alpha > 0.0 {
+            self.plotter.draw_pixel(x, y, alpha)
+        }
+    }
+
+    /// Clips the line to the bounds of the current region before rasterizing it
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
+        let margin = line::clip_margin(style);
+
+        let bounds = self.current_bounds();
+        let bounds = Rect::new(bounds.x0 - margin, bounds.y0 - margin, bounds.x1 + margin, bounds.y1 + margin);
+
+        if let Some((x0, y0, x1, y1)) = clip_line(x0, y0, x1, y1, &bounds) {
+            line::draw_line_styled(self, x0, y0, x1, y1, style)
+        }
+    }
+
+    /// Axis-aligned rectangles clip to the pixels with their centers inside them, other polygons to a mask
+    fn push_clip_region(&mut self, points: &[(f64, f64)]) {
+        let rectangle = points.len() == 4 && (0..4).all(|i| {
+            let (a, b) = (points[i], points[(i + 1) % 4]);
+
+            (a.0 == b.0) != (a.1 == b.1)
+        });
+
+        if !rectangle {
+            return self.push_clip_compound_polygon(&[points]);
+        }
+
+        let bounds = self.current_bounds().intersection(&Rect::new(points[0].0, points[0].1, points[2].0, points[2].1));
+
+        // An empty intersection is kept as an inverted rectangle, which contains no pixels
+        let bounds = bounds.unwrap_or(Rect { x0: 0.0, y0: 0.0, x1: -1.0, y1: -1.0 });
+
+        let mask = self.stack.last().and_then(|region| region.mask.clone());
+
+        self.stack.push(ClipRegion { bounds: bounds, mask: mask });
+    }
+
+    fn pop_clip_region(&mut self) {
+        self.stack.pop();
+    }
+}
@@ -0,0 +1,131 @@
+//! Anti-aliased polygon filling
+//!
+//! Polygons are filled with the nonzero winding rule using a scanline algorithm. Each pixel row is
+//! sampled at several sub-scanlines, and the horizontal coverage of each span is computed exactly,
+//! so edges are smooth at any slope and vertices may lie at fractional pixels.
+//!
+//! Pixel centers lie on whole coordinates, the same as for lines and shapes.
+
+/// Number of vertical samples taken per pixel row
+const SUBSCANLINES: usize = 5;
+
+/// Fill a single polygon.
+///
+/// The polygon is implicitly closed. Only pixels within `0..width` and `0..height` are plotted.
+pub fn fill_polygon<F>(points: &[(f64, f64)], width: u32, height: u32, plot: F) where F: FnMut(i64, i64, f64) {
+    fill_compound_polygon(&[points], width, height, plot)
+}
+
+/// Fill a polygon made of several contours, such as a shape with holes.
+///
+/// Overlapping contours are combined with the nonzero winding rule, so holes must wind
+/// in the opposite direction to the outer contour.
+pub fn fill_compound_polygon<F>(contours: &[&[(f64, f64)]], width: u32, height: u32, mut plot: F) where F: FnMut(i64, i64, f64) {
+    // Edges as (x0, y0, x1, y1, winding), offset so that pixel `i` covers `[i, i + 1)`
+    let mut edges: Vec<(f64, f64, f64, f64, i32)> = Vec::new();
+
+    let (mut xmin, mut xmax) = (::std::f64::INFINITY, ::std::f64::NEG_INFINITY);
+    let (mut ymin, mut ymax) = (::std::f64::INFINITY, ::std::f64::NEG_INFINITY);
+
+    for contour in contours {
+        let n = contour.len();
+
+        if n < 3 { continue; }
+
+        for i in 0..n {
+            let (x0, y0) = contour[i];
+            let (x1, y1) = contour[(i + 1) % n];
+
+            if !(x0.is_finite() && y0.is_finite() && x1.is_finite() && y1.is_finite()) {
+                return;
+            }
+
+            xmin = xmin.min(x0);
+            xmax = xmax.max(x0);
+            ymin = ymin.min(y0);
+            ymax = ymax.max(y0);
+
+            if y0 < y1 {
+                edges.push((x0 + 0.5, y0 + 0.5, x1 + 0.5, y1 + 0.5, 1));
+            } else if y0 > y1 {
+                edges.push((x1 + 0.5, y1 + 0.5, x0 + 0.5, y0 + 0.5, -1));
+            }
+        }
+    }
+
+    if edges.is_empty() { return; }
+
+    let px0 = (xmin + 0.5).floor().max(0.0) as i64;
+    let px1 = ((xmax + 0.5).ceil() as i64).min(width as i64);
+    let py0 = (ymin + 0.5).floor().max(0.0) as i64;
+    let py1 = ((ymax + 0.5).ceil() as i64).min(height as i64);
+
+    if px0 >= px1 || py0 >= py1 { return; }
+
+    let row_width = (px1 - px0) as usize;
+    let mut coverage = vec![0.0f64; row_width];
+    let mut crossings: Vec<(f64, i32)> = Vec::new();
+
+    let sample_weight = 1.0 / SUBSCANLINES as f64;
+
+    for py in py0..py1 {
+        for c in coverage.iter_mut() {
+            *c = 0.0;
+        }
+
+        for s in 0..SUBSCANLINES {
+            let sy = py as f64 + (s as f64 + 0.5) * sample_weight;
+
+            crossings.clear();
+
+            for &(x0, y0, x1, y1, winding) in &edges {
+                if y0 <= sy && sy < y1 {
+                    crossings.push((x0 + (sy - y0) * (x1 - x0) / (y1 - y0), winding));
+                }
+            }
+
+            crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            let mut winding = 0;
+
+            for i in 0..crossings.len() {
+                winding += crossings[i].1;
+
+                if winding != 0 && i + 1 < crossings.len() {
+                    let xa = crossings[i].0.max(px0 as f64);
+                    let xb = crossings[i + 1].0.min(px1 as f64);
+
+                    if xa < xb {
+                        add_span(&mut coverage, xa - px0 as f64, xb - px0 as f64, sample_weight);
+                    }
+                }
+            }
+        }
+
+        for (i, &c) in coverage.iter().enumerate() {
+            if c > 0.0 {
+                plot(px0 + i as i64, py, c.min(1.0));
+            }
+        }
+    }
+}
+
+/// Adds the exact horizontal coverage of the span `[xa, xb)` to a row of pixels
+fn add_span(coverage: &mut [f64], xa: f64, xb: f64, weight: f64) {
+    let ia = xa.floor() as usize;
+    let ib = xb.floor() as usize;
+
+    if ia == ib {
+        coverage[ia] += (xb - xa) * weight;
+    } else {
+        coverage[ia] += (1.0 - xa.fract()) * weight;
+
+        for c in &mut coverage[ia + 1..ib] {
+            *c += weight;
+        }
+
+        if ib < coverage.len() {
+            coverage[ib] += xb.fract() * weight;
+        }
+    }
+}
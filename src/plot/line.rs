@@ -1,5 +1,7 @@
 //! Line drawing routines
 
+use super::{LineStyle, Plotter};
+
 /// Uses Bresenham's algorithm to draw a line.
 ///
 /// [https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm)
@@ -117,4 +119,33 @@ pub fn draw_line_xiaolin_wu<F>(mut x0: f64, mut y0: f64, mut x1: f64, mut y1: f6
             x += 1.0;
         }
     }
-}
\ No newline at end of file
+}
+
+/// Draws a line onto a plotter in the given style, without any clipping.
+///
+/// Thin lines are plotted pixel by pixel, while thick lines place a dot at every pixel along the line.
+pub fn draw_line_styled<P: Plotter + ?Sized>(plot: &mut P, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
+    match style {
+        LineStyle::Thin => {
+            draw_line_bresenham(x0.round() as i64, y0.round() as i64, x1.round() as i64, y1.round() as i64,
+                                |x, y, alpha| plot.draw_pixel(x, y, alpha))
+        }
+        LineStyle::ThinAA => {
+            draw_line_xiaolin_wu(x0, y0, x1, y1,
+                                 |x, y, alpha| plot.draw_pixel(x, y, alpha))
+        }
+        LineStyle::Thick { width, hardness } => {
+            draw_line_bresenham(x0.round() as i64, y0.round() as i64, x1.round() as i64, y1.round() as i64,
+                                |x, y, alpha| plot.draw_dot(x, y, alpha, width, hardness))
+        }
+        LineStyle::ThickAA { width, hardness } => {
+            draw_line_xiaolin_wu(x0, y0, x1, y1,
+                                 |x, y, alpha| plot.draw_dot(x, y, alpha, width, hardness))
+        }
+    }
+}
+
+/// Space to keep around a clipping rectangle so that clipped line ends, and the dots of thick lines, stay out of sight
+pub fn clip_margin(style: LineStyle) -> f64 {
+    style.width() + 2.0
+}
@@ -3,6 +3,16 @@
 pub mod dot;
 pub mod line;
 pub mod shape;
+pub mod fill;
+pub mod clip;
+pub mod transform;
+pub mod viewport;
+
+use ::canvas::{Anchor, TextExtent};
+use ::color::Color;
+use ::geometry::{Rect, clip_line};
+
+use self::clip::ClipStack;
 
 /// Different styles of lines that can be drawn.
 ///
@@ -39,6 +49,24 @@ impl LineStyle {
             _ => self
         }
     }
+
+    /// The approximate width of the line in pixels
+    ///
+    /// Thin lines are one pixel wide, while thick lines are as wide as their dots.
+    pub fn width(&self) -> f64 {
+        match *self {
+            LineStyle::Thin | LineStyle::ThinAA => 1.0,
+            LineStyle::Thick { width, .. } | LineStyle::ThickAA { width, .. } => width,
+        }
+    }
+
+    /// Returns true if the line style is anti-aliased
+    pub fn is_aa(&self) -> bool {
+        match *self {
+            LineStyle::ThinAA | LineStyle::ThickAA { .. } => true,
+            _ => false
+        }
+    }
 }
 
 /// Common methods for plotters
@@ -55,6 +83,12 @@ pub trait Plotter {
     /// Usually negative coordinates would just be ignored.
     fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64);
 
+    /// Set the color used by subsequent drawing calls
+    ///
+    /// Plotters without color support may ignore this, which is the default.
+    #[inline]
+    fn set_color(&mut self, _color: Color) {}
+
     /// Draw a dot at the given coordinates
     ///
     /// By default, this draws a dot using a Gaussian function distribution, giving it a nice smooth falloff.
@@ -69,25 +103,16 @@ pub trait Plotter {
     ///
     /// Note that antialiased lines accept fractional pixel values,
     /// while non-AA lines are rounded to the nearest whole pixel.
+    ///
+    /// The line is clipped to the plotter first, so lines reaching far outside of it aren't walked pixel by pixel.
     #[inline]
     fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
-        match style {
-            LineStyle::Thin => {
-                line::draw_line_bresenham(x0.round() as i64, y0.round() as i64, x1.round() as i64, y1.round() as i64,
-                                          |x, y, alpha| self.draw_pixel(x, y, alpha))
-            }
-            LineStyle::ThinAA => {
-                line::draw_line_xiaolin_wu(x0, y0, x1, y1,
-                                           |x, y, alpha| self.draw_pixel(x, y, alpha))
-            }
-            LineStyle::Thick { width, hardness } => {
-                line::draw_line_bresenham(x0.round() as i64, y0.round() as i64, x1.round() as i64, y1.round() as i64,
-                                          |x, y, alpha| self.draw_dot(x, y, alpha, width, hardness))
-            }
-            LineStyle::ThickAA { width, hardness } => {
-                line::draw_line_xiaolin_wu(x0, y0, x1, y1,
-                                           |x, y, alpha| self.draw_dot(x, y, alpha, width, hardness))
-            }
+        let margin = line::clip_margin(style);
+
+        let bounds = Rect::new(-margin, -margin, self.width() as f64 - 1.0 + margin, self.height() as f64 - 1.0 + margin);
+
+        if let Some((x0, y0, x1, y1)) = clip_line(x0, y0, x1, y1, &bounds) {
+            line::draw_line_styled(self, x0, y0, x1, y1, style)
         }
     }
 
@@ -128,6 +153,67 @@ pub trait Plotter {
             }
         }
     }
+
+    /// Fill a polygon with anti-aliased edges.
+    ///
+    /// The polygon is implicitly closed, and vertices may lie at fractional pixels.
+    #[inline]
+    fn fill_polygon(&mut self, points: &[(f64, f64)]) {
+        self.fill_compound_polygon(&[points])
+    }
+
+    /// Fill a polygon made of several contours using the nonzero winding rule.
+    #[inline]
+    fn fill_compound_polygon(&mut self, contours: &[&[(f64, f64)]]) {
+        let (w, h) = (self.width(), self.height());
+
+        fill::fill_compound_polygon(contours, w, h, |x, y, alpha| self.draw_pixel(x, y, alpha))
+    }
+
+    /// Draw a line of text anchored at the given point, with the built-in bitmap font closest to `size` pixels.
+    #[inline]
+    fn draw_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64) {
+        self.draw_rotated_text(x, y, text, anchor, size, 0.0)
+    }
+
+    /// Draw a line of text rotated counter-clockwise around its anchor point, in radians.
+    #[inline]
+    fn draw_rotated_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) {
+        ::text::bitmap::draw_text(x, y, text, anchor, size, rotation, |x, y, alpha| self.draw_pixel(x, y, alpha))
+    }
+
+    /// Measure a line of text as it would be drawn by `draw_text`
+    #[inline]
+    fn measure_text(&self, text: &str, size: f64) -> TextExtent {
+        ::text::bitmap::text_extent(text, size)
+    }
+
+    /// Clipping regions of drawing through `Canvas`, which canvas primitives are clipped to geometrically
+    ///
+    /// Plotters without a stack ignore canvas clipping. All plotters of this crate keep one, and adapters keep
+    /// their own in their own coordinates.
+    #[inline]
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> {
+        None
+    }
+
+    /// Restrict drawing through `Canvas` to a polygon, until the matching `pop_clip_region`
+    ///
+    /// Pushes onto the `clip_stack` by default, plotters that clip their pixels by themselves push their own regions.
+    #[inline]
+    fn push_clip_region(&mut self, points: &[(f64, f64)]) {
+        if let Some(stack) = self.clip_stack() {
+            stack.push(points);
+        }
+    }
+
+    /// Remove the most recently pushed clipping region
+    #[inline]
+    fn pop_clip_region(&mut self) {
+        if let Some(stack) = self.clip_stack() {
+            stack.pop();
+        }
+    }
 }
 
 impl<'a, P: Plotter> Plotter for &'a mut P {
@@ -145,6 +231,66 @@ impl<'a, P: Plotter> Plotter for &'a mut P {
     fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
         (**self).draw_pixel(x, y, alpha)
     }
+
+    #[inline(always)]
+    fn set_color(&mut self, color: Color) {
+        (**self).set_color(color)
+    }
+
+    #[inline(always)]
+    fn draw_dot(&mut self, x: i64, y: i64, alpha: f64, width: f64, hardness: f64) {
+        (**self).draw_dot(x, y, alpha, width, hardness)
+    }
+
+    #[inline(always)]
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
+        (**self).draw_line(x0, y0, x1, y1, style)
+    }
+
+    #[inline(always)]
+    fn draw_circle(&mut self, x: i64, y: i64, radius: i64, style: LineStyle) {
+        (**self).draw_circle(x, y, radius, style)
+    }
+
+    #[inline(always)]
+    fn draw_ellipse(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, style: LineStyle) {
+        (**self).draw_ellipse(x0, y0, x1, y1, style)
+    }
+
+    #[inline(always)]
+    fn fill_compound_polygon(&mut self, contours: &[&[(f64, f64)]]) {
+        (**self).fill_compound_polygon(contours)
+    }
+
+    #[inline(always)]
+    fn draw_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64) {
+        (**self).draw_text(x, y, text, anchor, size)
+    }
+
+    #[inline(always)]
+    fn draw_rotated_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) {
+        (**self).draw_rotated_text(x, y, text, anchor, size, rotation)
+    }
+
+    #[inline(always)]
+    fn measure_text(&self, text: &str, size: f64) -> TextExtent {
+        (**self).measure_text(text, size)
+    }
+
+    #[inline(always)]
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> {
+        (**self).clip_stack()
+    }
+
+    #[inline(always)]
+    fn push_clip_region(&mut self, points: &[(f64, f64)]) {
+        (**self).push_clip_region(points)
+    }
+
+    #[inline(always)]
+    fn pop_clip_region(&mut self) {
+        (**self).pop_clip_region()
+    }
 }
 
 impl<P: Plotter> Plotter for Box<P> {
@@ -162,4 +308,64 @@ impl<P: Plotter> Plotter for Box<P> {
     fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
         (**self).draw_pixel(x, y, alpha)
     }
+
+    #[inline(always)]
+    fn set_color(&mut self, color: Color) {
+        (**self).set_color(color)
+    }
+
+    #[inline(always)]
+    fn draw_dot(&mut self, x: i64, y: i64, alpha: f64, width: f64, hardness: f64) {
+        (**self).draw_dot(x, y, alpha, width, hardness)
+    }
+
+    #[inline(always)]
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
+        (**self).draw_line(x0, y0, x1, y1, style)
+    }
+
+    #[inline(always)]
+    fn draw_circle(&mut self, x: i64, y: i64, radius: i64, style: LineStyle) {
+        (**self).draw_circle(x, y, radius, style)
+    }
+
+    #[inline(always)]
+    fn draw_ellipse(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, style: LineStyle) {
+        (**self).draw_ellipse(x0, y0, x1, y1, style)
+    }
+
+    #[inline(always)]
+    fn fill_compound_polygon(&mut self, contours: &[&[(f64, f64)]]) {
+        (**self).fill_compound_polygon(contours)
+    }
+
+    #[inline(always)]
+    fn draw_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64) {
+        (**self).draw_text(x, y, text, anchor, size)
+    }
+
+    #[inline(always)]
+    fn draw_rotated_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) {
+        (**self).draw_rotated_text(x, y, text, anchor, size, rotation)
+    }
+
+    #[inline(always)]
+    fn measure_text(&self, text: &str, size: f64) -> TextExtent {
+        (**self).measure_text(text, size)
+    }
+
+    #[inline(always)]
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> {
+        (**self).clip_stack()
+    }
+
+    #[inline(always)]
+    fn push_clip_region(&mut self, points: &[(f64, f64)]) {
+        (**self).push_clip_region(points)
+    }
+
+    #[inline(always)]
+    fn pop_clip_region(&mut self) {
+        (**self).pop_clip_region()
+    }
 }
@@ -197,4 +197,68 @@ pub fn draw_ellipse_aa<P>(mut x0: i64, mut y0: i64, mut x1: i64, mut y1: i64, mu
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Draws an ellipse at any orientation, given its center and the vectors from the center to the ends of two
+/// conjugate semi-diameters, such as the images of the radii of a transformed circle.
+///
+/// Every pixel in the ellipse's bounding box is tested against the implicit equation of the curve,
+/// and pixels within half a pixel of it are plotted. With anti-aliasing, their alpha falls off with the distance.
+pub fn draw_ellipse_conjugate<P>(cx: f64, cy: f64, u: (f64, f64), v: (f64, f64), aa: bool, mut plot: P) where P: FnMut(i64, i64, f64) {
+    let det = u.0 * v.1 - v.0 * u.1;
+
+    // Half the size of the bounding box
+    let ex = u.0.hypot(v.0);
+    let ey = u.1.hypot(v.1);
+
+    if det.abs() < 1e-9 {
+        // Collapsed onto a line through the center
+        let (dx, dy) = if u.0.hypot(u.1) >= v.0.hypot(v.1) { u } else { v };
+        let length = dx.hypot(dy);
+
+        if length == 0.0 {
+            plot(cx.round() as i64, cy.round() as i64, 1.0);
+        } else {
+            let (dx, dy) = (dx / length * ex.hypot(ey), dy / length * ex.hypot(ey));
+
+            ::plot::line::draw_line_xiaolin_wu(cx - dx, cy - dy, cx + dx, cy + dy, plot);
+        }
+
+        return;
+    }
+
+    // Inverse of the matrix with columns u and v, mapping the ellipse onto the unit circle
+    let (ia, ib, ic, id) = (v.1 / det, -u.1 / det, -v.0 / det, u.0 / det);
+
+    let (x0, x1) = ((cx - ex - 1.0).floor() as i64, (cx + ex + 1.0).ceil() as i64);
+    let (y0, y1) = ((cy - ey - 1.0).floor() as i64, (cy + ey + 1.0).ceil() as i64);
+
+    for y in y0..y1 + 1 {
+        for x in x0..x1 + 1 {
+            let (px, py) = (x as f64 - cx, y as f64 - cy);
+
+            let (qx, qy) = (ia * px + ic * py, ib * px + id * py);
+
+            // Implicit function and its gradient, giving a first order estimate of the distance to the curve
+            let f = qx * qx + qy * qy - 1.0;
+
+            let (gx, gy) = (2.0 * (ia * qx + ib * qy), 2.0 * (ic * qx + id * qy));
+
+            let gradient = gx.hypot(gy);
+
+            if gradient == 0.0 {
+                continue;
+            }
+
+            let distance = (f / gradient).abs();
+
+            if aa {
+                if distance < 1.0 {
+                    plot(x, y, 1.0 - distance);
+                }
+            } else if distance < 0.5 {
+                plot(x, y, 1.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Drawing in transformed coordinates
+//!
+//! `TransformPlot` wraps a plotter and maps the coordinates of every drawing call through an `Affine2`
+//! before handing it on, so shapes can be drawn in their own local coordinates and then moved, rotated,
+//! scaled or skewed into place. Line widths stay in pixels of the wrapped plotter, while single pixels and
+//! dots are shapes in the local coordinates and grow and turn with the transform.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::plot::Plotter;
+//! use rust_plot::plot::transform::TransformPlot;
+//! use rust_plot::transform::Affine2;
+//!
+//! let mut plot = TransformPlot::new(BufferPlot::new(10, 10));
+//!
+//! plot.push_transform(Affine2::scale(3.0, 3.0));
+//! plot.draw_pixel(1, 1, 1.0);
+//!
+//! let plot = plot.into_inner();
+//!
+//! // The pixel covers the 3 by 3 pixels around (3, 3), rows are stored from the top
+//! let covered = plot.pixels().iter().filter(|p| p[0] < 0.5).count();
+//! assert_eq!(covered, 9);
+//! assert_eq!(plot.pixels()[(10 - 3 - 1) * 10 + 3], [0.0, 0.0, 0.0, 1.0]);
+//! ```
+
+use ::canvas::{Anchor, TextExtent};
+use ::color::Color;
+use ::transform::Affine2;
+use ::utils::clamp;
+
+use super::shape::ellipse::draw_ellipse_conjugate;
+use super::{LineStyle, Plotter};
+use super::clip::ClipStack;
+
+/// Plotter adapter that applies a stack of affine transforms to all coordinates
+pub struct TransformPlot<P: Plotter> {
+    plotter: P,
+    transform: Affine2,
+    stack: Vec<Affine2>,
+    clip: ClipStack,
+    /// Color set on the wrapped plotter, to draw translucent pixels with
+    color: Color,
+}
+
+impl<P: Plotter> TransformPlot<P> {
+    pub fn new(plotter: P) -> TransformPlot<P> {
+        TransformPlot {
+            plotter: plotter,
+            transform: Affine2::identity(),
+            stack: Vec::new(),
+            clip: ClipStack::new(),
+            color: Color::black(),
+        }
+    }
+
+    pub fn inner(&self) -> &P { &self.plotter }
+
+    pub fn inner_mut(&mut self) -> &mut P { &mut self.plotter }
+
+    pub fn into_inner(self) -> P { self.plotter }
+
+    /// The current transform from drawing coordinates to the wrapped plotter's pixels
+    pub fn transform(&self) -> Affine2 {
+        self.transform
+    }
+
+    /// Replace the current transform
+    pub fn set_transform(&mut self, transform: Affine2) {
+        self.transform = transform;
+    }
+
+    /// Apply a transform to everything drawn until the matching `pop_transform`
+    ///
+    /// The new transform works in the current coordinates, so it's applied before any transforms pushed earlier.
+    pub fn push_transform(&mut self, transform: Affine2) {
+        self.stack.push(self.transform);
+        self.transform = self.transform * transform;
+    }
+
+    /// Restore the transform from before the most recent `push_transform`
+    pub fn pop_transform(&mut self) {
+        if let Some(transform) = self.stack.pop() {
+            self.transform = transform;
+        }
+    }
+
+    /// Whether the transform maps whole pixels onto whole pixels, up to rounding of the translation
+    #[inline]
+    fn keeps_pixels(&self) -> bool {
+        let t = self.transform;
+
+        t.is_axis_aligned() && t.a.abs() == 1.0 && t.d.abs() == 1.0
+    }
+
+    #[inline]
+    fn apply_rounded(&self, x: i64, y: i64) -> (i64, i64) {
+        let (x, y) = self.transform.apply(x as f64, y as f64);
+
+        (x.round() as i64, y.round() as i64)
+    }
+
+    /// Draws a transformed ellipse onto the wrapped plotter, which may be rotated or sheared
+    fn draw_conjugate(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, style: LineStyle) {
+        let (cx, cy) = self.transform.apply(cx, cy);
+
+        let u = self.transform.apply_vector(rx, 0.0);
+        let v = self.transform.apply_vector(0.0, ry);
+
+        let plotter = &mut self.plotter;
+
+        match style {
+            LineStyle::Thin | LineStyle::ThinAA => {
+                draw_ellipse_conjugate(cx, cy, u, v, style.is_aa(), |x, y, alpha| plotter.draw_pixel(x, y, alpha))
+            }
+            LineStyle::Thick { width, hardness } | LineStyle::ThickAA { width, hardness } => {
+                draw_ellipse_conjugate(cx, cy, u, v, style.is_aa(), |x, y, alpha| plotter.draw_dot(x, y, alpha, width, hardness))
+            }
+        }
+    }
+}
+
+impl<P: Plotter> Plotter for TransformPlot<P> {
+    fn width(&self) -> u32 { self.plotter.width() }
+
+    fn height(&self) -> u32 { self.plotter.height() }
+
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+        self.plotter.set_color(color)
+    }
+
+    /// Fills the transformed square of the pixel, or plots the pixel nearest to it if the transform only moves
+    /// or flips pixels
+    fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
+        if self.keeps_pixels() {
+            let (x, y) = self.apply_rounded(x, y);
+
+            return self.plotter.draw_pixel(x, y, alpha);
+        }
+
+        let (x, y) = (x as f64, y as f64);
+        let transform = self.transform;
+
+        let square: Vec<(f64, f64)> = [(x - 0.5, y - 0.5), (x + 0.5, y - 0.5), (x + 0.5, y + 0.5), (x - 0.5, y + 0.5)]
+            .iter().map(|&(x, y)| transform.apply(x, y)).collect();
+
+        let color = self.color;
+
+        self.plotter.set_color(color.with_alpha(color.a * clamp(alpha, 0.0, 1.0) as f32));
+        self.plotter.fill_polygon(&square);
+        self.plotter.set_color(color);
+    }
+
+    /// Draws the dot at the transformed position, with its width scaled by the average scale of the transform
+    fn draw_dot(&mut self, x: i64, y: i64, alpha: f64, width: f64, hardness: f64) {
+        let (x, y) = self.apply_rounded(x, y);
+
+        self.plotter.draw_dot(x, y, alpha, width * self.transform.scale_factor(), hardness)
+    }
+
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
+        let (x0, y0) = self.transform.apply(x0, y0);
+        let (x1, y1) = self.transform.apply(x1, y1);
+
+        self.plotter.draw_line(x0, y0, x1, y1, style)
+    }
+
+    fn draw_circle(&mut self, x: i64, y: i64, radius: i64, style: LineStyle) {
+        if self.transform.is_similarity() {
+            let (x, y) = self.apply_rounded(x, y);
+            let radius = (radius as f64 * self.transform.scale_factor()).round() as i64;
+
+            self.plotter.draw_circle(x, y, radius, style)
+        } else {
+            self.draw_conjugate(x as f64, y as f64, radius as f64, radius as f64, style)
+        }
+    }
+
+    fn draw_ellipse(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, style: LineStyle) {
+        if self.transform.is_axis_aligned() {
+            let (x0, y0) = self.apply_rounded(x0, y0);
+            let (x1, y1) = self.apply_rounded(x1, y1);
+
+            self.plotter.draw_ellipse(x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1), style)
+        } else {
+            let (cx, cy) = ((x0 + x1) as f64 * 0.5, (y0 + y1) as f64 * 0.5);
+
+            self.draw_conjugate(cx, cy, (x1 - x0).abs() as f64 * 0.5, (y1 - y0).abs() as f64 * 0.5, style)
+        }
+    }
+
+    fn fill_compound_polygon(&mut self, contours: &[&[(f64, f64)]]) {
+        let transform = self.transform;
+
+        let transformed: Vec<Vec<(f64, f64)>> = contours.iter().map(|contour| {
+            contour.iter().map(|&(x, y)| transform.apply(x, y)).collect()
+        }).collect();
+
+        let transformed: Vec<&[(f64, f64)]> = transformed.iter().map(|c| &c[..]).collect();
+
+        self.plotter.fill_compound_polygon(&transformed)
+    }
+
+    /// Moves, scales and rotates the text with the transform, though it can't be sheared
+    fn draw_rotated_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) {
+        let (x, y) = self.transform.apply(x, y);
+        let (ux, uy) = self.transform.apply_vector(1.0, 0.0);
+
+        let size = size * self.transform.scale_factor();
+
+        self.plotter.draw_rotated_text(x, y, text, anchor, size, rotation + uy.atan2(ux))
+    }
+
+    fn measure_text(&self, text: &str, size: f64) -> TextExtent {
+        let scale = self.transform.scale_factor();
+
+        if scale == 0.0 {
+            return TextExtent::default();
+        }
+
+        let extent = self.plotter.measure_text(text, size * scale);
+
+        TextExtent { width: extent.width / scale, ascent: extent.ascent / scale, descent: extent.descent / scale }
+    }
+
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> { Some(&mut self.clip) }
+}
@@ -0,0 +1,125 @@
+//! Drawing into part of a plotter
+//!
+//! A `Viewport` wraps a plotter and exposes a rectangular region of it as a plotter of its own, with its
+//! own origin and size. Anything drawn outside the region is clipped, so a `Graph` or any other drawing
+//! can be placed into one panel of a larger figure.
+
+use ::color::Color;
+use ::geometry::Rect;
+
+use super::Plotter;
+use super::clip::ClipStack;
+
+/// Plotter adapter for a rectangular region of another plotter
+pub struct Viewport<P: Plotter> {
+    plotter: P,
+    x: i64,
+    y: i64,
+    width: u32,
+    height: u32,
+    clip: ClipStack,
+}
+
+impl<P: Plotter> Viewport<P> {
+    /// Create a viewport with its bottom left corner at `x` and `y` of the wrapped plotter
+    pub fn new(plotter: P, x: i64, y: i64, width: u32, height: u32) -> Viewport<P> {
+        Viewport { plotter: plotter, x: x, y: y, width: width, height: height, clip: ClipStack::new() }
+    }
+
+    /// Create a viewport covering a rectangle of the wrapped plotter, rounded to whole pixels
+    pub fn from_rect(plotter: P, rect: Rect) -> Viewport<P> {
+        let (x0, y0) = (rect.x0.round() as i64, rect.y0.round() as i64);
+        let (x1, y1) = (rect.x1.round() as i64, rect.y1.round() as i64);
+
+        Viewport::new(plotter, x0, y0, (x1 - x0).max(0) as u32, (y1 - y0).max(0) as u32)
+    }
+
+    /// Position of the viewport's origin in the wrapped plotter
+    pub fn origin(&self) -> (i64, i64) {
+        (self.x, self.y)
+    }
+
+    pub fn inner(&self) -> &P { &self.plotter }
+
+    pub fn inner_mut(&mut self) -> &mut P { &mut self.plotter }
+
+    pub fn into_inner(self) -> P { self.plotter }
+}
+
+/// Primitives are rasterized by the default `Plotter` methods, so every pixel passes through the clipped `draw_pixel`
+impl<P: Plotter> Plotter for Viewport<P> {
+    fn width(&self) -> u32 { self.width }
+
+    fn height(&self) -> u32 { self.height }
+
+    fn set_color(&mut self, color: Color) {
+        self.plotter.set_color(color)
+    }
+
+    fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
+        if 0 <= x && x < self.width as i64 && 0 <= y && y < self.height as i64 {
+            self.plotter.draw_pixel(x + self.x, y + self.y, alpha)
+        }
+    }
+
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> { Some(&mut self.clip) }
+}
+
+/// Splits a plotter into a grid of equally sized panels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridLayout {
+    pub rows: usize,
+    pub columns: usize,
+    /// Space around the whole grid, in pixels
+    pub margin: f64,
+    /// Space between neighboring panels, in pixels
+    pub spacing: f64,
+}
+
+impl GridLayout {
+    pub fn new(rows: usize, columns: usize) -> GridLayout {
+        GridLayout { rows: rows, columns: columns, margin: 0.0, spacing: 0.0 }
+    }
+
+    pub fn with_margin(self, margin: f64) -> GridLayout {
+        GridLayout { margin: margin, ..self }
+    }
+
+    pub fn with_spacing(self, spacing: f64) -> GridLayout {
+        GridLayout { spacing: spacing, ..self }
+    }
+
+    /// Rectangle of the panel at the given row and column, counting rows from the top
+    pub fn cell(&self, row: usize, column: usize, width: u32, height: u32) -> Rect {
+        let inner_w = width as f64 - 2.0 * self.margin - self.spacing * (self.columns as f64 - 1.0);
+        let inner_h = height as f64 - 2.0 * self.margin - self.spacing * (self.rows as f64 - 1.0);
+
+        let cell_w = (inner_w / self.columns as f64).max(0.0);
+        let cell_h = (inner_h / self.rows as f64).max(0.0);
+
+        let x = self.margin + column as f64 * (cell_w + self.spacing);
+        let y = height as f64 - self.margin - (row + 1) as f64 * cell_h - row as f64 * self.spacing;
+
+        Rect::with_size(x, y, cell_w, cell_h)
+    }
+
+    /// Rectangles of every panel, row by row from the top left
+    pub fn cells(&self, width: u32, height: u32) -> Vec<Rect> {
+        let mut cells = Vec::with_capacity(self.rows * self.columns);
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                cells.push(self.cell(row, column, width, height));
+            }
+        }
+
+        cells
+    }
+
+    /// Create a viewport onto the panel at the given row and column
+    pub fn viewport<P: Plotter>(&self, plotter: P, row: usize, column: usize) -> Viewport<P> {
+        let rect = self.cell(row, column, plotter.width(), plotter.height());
+
+        Viewport::from_rect(plotter, rect)
+    }
+}
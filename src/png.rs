@@ -0,0 +1,128 @@
+//! Minimal PNG writer
+//!
+//! Image data is stored in uncompressed deflate blocks, which trades file size for having
+//! no dependencies. The output is a valid PNG that any decoder can read.
+
+use std::io::{self, Write};
+
+/// Largest payload of a stored deflate block
+const MAX_STORED_BLOCK: usize = 65535;
+
+fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+
+    for n in 0..256 {
+        let mut c = n as u32;
+
+        for _ in 0..8 {
+            c = if c & 1 != 0 { 0xEDB88320 ^ (c >> 1) } else { c >> 1 };
+        }
+
+        table[n] = c;
+    }
+
+    table
+}
+
+/// CRC-32 as used by PNG chunks
+///
+/// ```
+/// use rust_plot::png::crc32;
+///
+/// assert_eq!(crc32(b"IEND"), 0xAE426082);
+/// ```
+pub fn crc32(data: &[u8]) -> u32 {
+    let table = crc32_table();
+
+    !data.iter().fold(!0u32, |c, &byte| table[((c ^ byte as u32) & 0xFF) as usize] ^ (c >> 8))
+}
+
+/// Adler-32 checksum used by zlib streams
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+
+        a %= 65521;
+        b %= 65521;
+    }
+
+    (b << 16) | a
+}
+
+/// Wraps data in a zlib stream made of stored deflate blocks
+pub fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = (data.len() + MAX_STORED_BLOCK - 1) / MAX_STORED_BLOCK;
+
+    let mut out = Vec::with_capacity(data.len() + blocks.max(1) * 5 + 6);
+
+    // 32K window, no preset dictionary, fastest compression level
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    if data.is_empty() {
+        out.extend_from_slice(&[0x01, 0x00, 0x00, 0xFF, 0xFF]);
+    }
+
+    for (i, block) in data.chunks(MAX_STORED_BLOCK).enumerate() {
+        let last = if i + 1 == blocks { 1 } else { 0 };
+        let len = block.len() as u16;
+
+        out.push(last);
+        out.extend_from_slice(&[len as u8, (len >> 8) as u8, !len as u8, (!len >> 8) as u8]);
+        out.extend_from_slice(block);
+    }
+
+    let adler = adler32(data);
+    out.extend_from_slice(&[(adler >> 24) as u8, (adler >> 16) as u8, (adler >> 8) as u8, adler as u8]);
+
+    out
+}
+
+fn be32(x: u32) -> [u8; 4] {
+    [(x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8]
+}
+
+fn write_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    writer.write_all(&be32(data.len() as u32))?;
+
+    let mut crc_data = Vec::with_capacity(data.len() + 4);
+    crc_data.extend_from_slice(kind);
+    crc_data.extend_from_slice(data);
+
+    writer.write_all(&crc_data)?;
+    writer.write_all(&be32(crc32(&crc_data)))
+}
+
+/// Writes an 8-bit RGBA PNG image from sRGB pixel data, row by row from the top
+pub fn write_png<W: Write>(writer: &mut W, width: u32, height: u32, rgba: &[u8]) -> io::Result<()> {
+    let stride = width as usize * 4;
+
+    if rgba.len() != stride * height as usize {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "pixel data doesn't match the image dimensions"));
+    }
+
+    writer.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&be32(width));
+    header.extend_from_slice(&be32(height));
+    // 8 bits per channel, RGBA, deflate, adaptive filtering, no interlacing
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    write_chunk(writer, b"IHDR", &header)?;
+
+    // Every scanline is prefixed by its filter type, which is always "none"
+    let mut scanlines = Vec::with_capacity((stride + 1) * height as usize);
+
+    for row in rgba.chunks(stride.max(1)).take(height as usize) {
+        scanlines.push(0);
+        scanlines.extend_from_slice(row);
+    }
+
+    write_chunk(writer, b"IDAT", &zlib_stored(&scanlines))?;
+    write_chunk(writer, b"IEND", &[])
+}
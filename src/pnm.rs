@@ -0,0 +1,54 @@
+//! Binary Netpbm image writers
+//!
+//! These formats are simple enough to write without any dependencies, and are read by most image tools.
+//! All writers take 8-bit sRGB RGBA pixel data, row by row from the top.
+
+use std::io::{self, Write};
+
+use ::color::{srgb_to_linear, linear_to_srgb, quantize_u8};
+
+fn check_length(width: u32, height: u32, rgba: &[u8]) -> io::Result<()> {
+    if rgba.len() != width as usize * height as usize * 4 {
+        Err(io::Error::new(io::ErrorKind::InvalidInput, "pixel data doesn't match the image dimensions"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Writes a binary PPM (`P6`) image. Alpha is discarded.
+pub fn write_ppm<W: Write>(writer: &mut W, width: u32, height: u32, rgba: &[u8]) -> io::Result<()> {
+    check_length(width, height, rgba)?;
+
+    write!(writer, "P6\n{} {}\n255\n", width, height)?;
+
+    let rgb: Vec<u8> = rgba.chunks(4).flat_map(|p| p[..3].iter().cloned()).collect();
+
+    writer.write_all(&rgb)
+}
+
+/// Writes a binary PGM (`P5`) image of the luminance. Alpha is discarded.
+pub fn write_pgm<W: Write>(writer: &mut W, width: u32, height: u32, rgba: &[u8]) -> io::Result<()> {
+    check_length(width, height, rgba)?;
+
+    write!(writer, "P5\n{} {}\n255\n", width, height)?;
+
+    let gray: Vec<u8> = rgba.chunks(4).map(|p| {
+        let linear = |c: u8| srgb_to_linear(c as f32 / 255.0);
+
+        // Rec. 709 luminance, computed in linear light
+        let y = 0.2126 * linear(p[0]) + 0.7152 * linear(p[1]) + 0.0722 * linear(p[2]);
+
+        quantize_u8(linear_to_srgb(y), 0.0)
+    }).collect();
+
+    writer.write_all(&gray)
+}
+
+/// Writes a PAM (`P7`) image with an alpha channel
+pub fn write_pam<W: Write>(writer: &mut W, width: u32, height: u32, rgba: &[u8]) -> io::Result<()> {
+    check_length(width, height, rgba)?;
+
+    write!(writer, "P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n", width, height)?;
+
+    writer.write_all(rgba)
+}
@@ -0,0 +1,188 @@
+//! Binning data into histograms
+//!
+//! `Bins` decides where the edges between bins go, either given directly or from a rule picking a bin
+//! width suited to the data. A `Histogram` counts the values in every bin and normalizes the counts.
+//!
+//! Every bin includes its lower edge and excludes its upper edge, except for the last bin, which includes
+//! both, so the largest value is counted. Values outside of the edges and values that aren't finite are left out.
+//!
+//! ```
+//! use rust_plot::stat::bins::{Bins, Histogram, Normalization};
+//!
+//! let data = [0.5, 1.5, 1.7, 2.2, 2.5, 2.9, 3.1, 4.0];
+//!
+//! let histogram = Histogram::new(&data, &Bins::Width(1.0), Normalization::Count);
+//!
+//! assert_eq!(histogram.edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+//! assert_eq!(histogram.values, vec![1.0, 2.0, 3.0, 2.0]);
+//!
+//! let cumulative = Histogram::new(&data, &Bins::Width(1.0), Normalization::Cumulative);
+//!
+//! assert_eq!(cumulative.values, vec![0.125, 0.375, 0.75, 1.0]);
+//! ```
+
+use std::cmp::Ordering;
+
+use super::summary::{finite_sorted, interquartile_range, std_dev};
+
+/// Upper limit on the number of bins from a rule or width, so narrow bins on spread out data stay manageable
+pub const MAX_BINS: usize = 10_000;
+
+/// How to place the edges of the bins
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bins {
+    /// A fixed number of bins of equal width spanning the data
+    Count(usize),
+    /// Bins of a fixed width, with edges at multiples of it
+    Width(f64),
+    /// `log2 n + 1` bins, for roughly normal data of moderate size
+    Sturges,
+    /// Bins `3.49 σ / ∛n` wide, for roughly normal data
+    Scott,
+    /// Bins `2 IQR / ∛n` wide, which holds up better against outliers than Scott's rule
+    FreedmanDiaconis,
+    /// Explicit edges, with one more edge than there are bins
+    ///
+    /// The edges are sorted, and repeated edges and edges that aren't finite are dropped, so every bin has a width.
+    Edges(Vec<f64>),
+}
+
+impl Bins {
+    /// Edges of the bins for the given data, in increasing order
+    ///
+    /// Data without a spread gets bins across a unit around its value, as many as `Count` asks for or else a
+    /// single one, and without any finite values the bins cover `0..1`. Rules that can't measure the spread of
+    /// the data fall back to Sturges' rule.
+    ///
+    /// ```
+    /// use rust_plot::stat::bins::Bins;
+    ///
+    /// let bins = Bins::Edges(vec![3.0, 0.0, 1.0, 1.0, ::std::f64::NAN]);
+    ///
+    /// assert_eq!(bins.edges(&[]), vec![0.0, 1.0, 3.0]);
+    /// assert_eq!(Bins::Count(4).edges(&[2.0, 2.0]), vec![1.5, 1.75, 2.0, 2.25, 2.5]);
+    /// assert_eq!(Bins::Sturges.edges(&[2.0, 2.0]), vec![1.5, 2.5]);
+    /// ```
+    pub fn edges(&self, data: &[f64]) -> Vec<f64> {
+        let sorted = finite_sorted(data);
+
+        let (min, max) = match (sorted.first(), sorted.last()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => (0.0, 1.0),
+        };
+
+        let n = sorted.len() as f64;
+        let sturges = (n.log2().ceil() as usize + 1).max(1);
+
+        let width = match *self {
+            Bins::Edges(ref edges) => {
+                let mut edges = finite_sorted(edges);
+                edges.dedup();
+
+                return edges;
+            }
+            Bins::Count(count) if min < max => return uniform(min, max, count.max(1)),
+            Bins::Count(count) => return uniform(min - 0.5, max + 0.5, count.max(1)),
+            _ if min == max => return vec![min - 0.5, max + 0.5],
+            Bins::Sturges => return uniform(min, max, sturges),
+            Bins::Width(width) => width,
+            Bins::Scott => std_dev(&sorted).map_or(0.0, |s| 3.49 * s / n.cbrt()),
+            Bins::FreedmanDiaconis => interquartile_range(&sorted).map_or(0.0, |iqr| 2.0 * iqr / n.cbrt()),
+        };
+
+        if width <= 0.0 || !width.is_finite() {
+            return uniform(min, max, sturges);
+        }
+
+        match *self {
+            // Fixed widths line up with multiples of the width, so histograms of different data match
+            Bins::Width(_) => {
+                let first = (min / width).floor();
+                let count = ((max / width).floor() - first + 1.0).max(1.0);
+
+                // Data ending exactly on an edge belongs to the bin below it
+                let count = if (first + count - 1.0) * width == max && count > 1.0 { count - 1.0 } else { count };
+
+                if count > MAX_BINS as f64 {
+                    return uniform(min, max, MAX_BINS);
+                }
+
+                (0..count as usize + 1).map(|i| (first + i as f64) * width).collect()
+            }
+            _ => uniform(min, max, (((max - min) / width).ceil() as usize).max(1).min(MAX_BINS)),
+        }
+    }
+}
+
+/// `count` bins of equal width from `min` to `max`
+fn uniform(min: f64, max: f64, count: usize) -> Vec<f64> {
+    let count = count.min(MAX_BINS);
+
+    (0..count + 1).map(|i| {
+        if i == count { max } else { min + (max - min) * i as f64 / count as f64 }
+    }).collect()
+}
+
+/// What the values of a histogram measure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Number of values in each bin
+    Count,
+    /// Fraction of the values in each bin divided by its width, so the area of the histogram is one
+    Density,
+    /// Fraction of the values in each bin or any bin before it, rising to one
+    Cumulative,
+}
+
+/// Values binned by their edges
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Edges of the bins in increasing order, one more than there are bins
+    pub edges: Vec<f64>,
+    /// Number of values in each bin
+    pub counts: Vec<usize>,
+    /// Normalized counts of each bin
+    pub values: Vec<f64>,
+    pub normalization: Normalization,
+}
+
+impl Histogram {
+    pub fn new(data: &[f64], bins: &Bins, normalization: Normalization) -> Histogram {
+        let edges = bins.edges(data);
+        let bins = edges.len().saturating_sub(1);
+        let mut counts = vec![0; bins];
+
+        if let (Some(&first), Some(&last)) = (edges.first(), edges.last()) {
+            for &value in data.iter().filter(|v| v.is_finite() && first <= **v && **v <= last) {
+                // Index of the first edge above the value, the bin is just below it
+                let above = match edges.binary_search_by(|e| e.partial_cmp(&value).unwrap_or(Ordering::Less)) {
+                    Ok(i) => i + 1,
+                    Err(i) => i,
+                };
+
+                if bins > 0 {
+                    counts[above.max(1).min(bins) - 1] += 1;
+                }
+            }
+        }
+
+        let total = counts.iter().sum::<usize>().max(1) as f64;
+
+        let values = match normalization {
+            Normalization::Count => counts.iter().map(|&c| c as f64).collect(),
+            Normalization::Density => {
+                counts.iter().zip(edges.windows(2)).map(|(&c, e)| c as f64 / total / (e[1] - e[0])).collect()
+            }
+            Normalization::Cumulative => {
+                counts.iter().scan(0, |sum, &c| { *sum += c; Some(*sum as f64 / total) }).collect()
+            }
+        };
+
+        Histogram { edges: edges, counts: counts, values: values, normalization: normalization }
+    }
+
+    /// The bins as their lower and upper edge with their value
+    pub fn bins(&self) -> Vec<(f64, f64, f64)> {
+        self.edges.windows(2).zip(&self.values).map(|(e, &v)| (e[0], e[1], v)).collect()
+    }
+}
@@ -0,0 +1,189 @@
+//! Kernel density estimation
+//!
+//! A kernel density estimate smooths data into a continuous density by placing a kernel, scaled by the
+//! bandwidth, on every value and averaging them. Compact kernels are zero outside of one bandwidth from
+//! their value, the Gaussian kernel uses the bandwidth as its standard deviation, so it looks wider at the
+//! same bandwidth. The rules of thumb for the bandwidth assume the data is roughly normal.
+//!
+//! ```
+//! use rust_plot::stat::kde::{Bandwidth, Kde, Kernel};
+//!
+//! let data = [1.0, 2.0, 2.5, 3.0, 7.0];
+//!
+//! let kde = Kde::new(&data, Kernel::Epanechnikov, Bandwidth::Fixed(1.0));
+//!
+//! assert_eq!(kde.density(2.0), (0.75 + 0.75 * 0.75 + 0.0) / 5.0);
+//! assert_eq!(kde.density(5.0), 0.0);
+//!
+//! // The density integrates to one
+//! let area: f64 = kde.curve(1001).windows(2).map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) * 0.5).sum();
+//! assert!((area - 1.0).abs() < 1e-3);
+//! ```
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use super::summary::{finite_sorted, interquartile_range, std_dev};
+
+/// Shape of the bump placed on every value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kernel {
+    Gaussian,
+    /// Parabolic, the most efficient kernel in theory
+    Epanechnikov,
+    /// A flat box
+    Uniform,
+    Triangular,
+    /// Also known as quartic
+    Biweight,
+    Cosine,
+}
+
+impl Kernel {
+    /// Value of the kernel at a distance from its center, in bandwidths
+    pub fn evaluate(&self, u: f64) -> f64 {
+        let a = u.abs();
+
+        if *self != Kernel::Gaussian && a > 1.0 {
+            return 0.0;
+        }
+
+        match *self {
+            Kernel::Gaussian => (-0.5 * u * u).exp() / (2.0 * PI).sqrt(),
+            Kernel::Epanechnikov => 0.75 * (1.0 - u * u),
+            Kernel::Uniform => 0.5,
+            Kernel::Triangular => 1.0 - a,
+            Kernel::Biweight => 15.0 / 16.0 * (1.0 - u * u).powi(2),
+            Kernel::Cosine => PI / 4.0 * (PI * 0.5 * u).cos(),
+        }
+    }
+
+    /// Distance in bandwidths beyond which the kernel is zero, or negligible for the Gaussian kernel
+    pub fn support(&self) -> f64 {
+        match *self {
+            Kernel::Gaussian => 6.0,
+            _ => 1.0,
+        }
+    }
+}
+
+/// How the bandwidth is chosen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bandwidth {
+    /// Silverman's rule of thumb, `0.9 min(σ, IQR / 1.34) n^(−1/5)`, which copes with some skew and outliers
+    Silverman,
+    /// Scott's rule of thumb, `1.06 σ n^(−1/5)`
+    Scott,
+    Fixed(f64),
+}
+
+impl Bandwidth {
+    /// Bandwidth for the given data
+    ///
+    /// When the rule can't measure a spread, because there are fewer than two distinct values, the
+    /// bandwidth is one.
+    pub fn select(&self, data: &[f64]) -> f64 {
+        let sorted = finite_sorted(data);
+        let n = sorted.len() as f64;
+
+        let sigma = std_dev(&sorted).unwrap_or(0.0);
+
+        let h = match *self {
+            Bandwidth::Fixed(h) => h,
+            Bandwidth::Scott => 1.06 * sigma * n.powf(-0.2),
+            Bandwidth::Silverman => {
+                let spread = match interquartile_range(&sorted) {
+                    Some(iqr) if iqr > 0.0 => sigma.min(iqr / 1.34),
+                    _ => sigma,
+                };
+
+                0.9 * spread * n.powf(-0.2)
+            }
+        };
+
+        if h > 0.0 && h.is_finite() { h } else { 1.0 }
+    }
+}
+
+/// A kernel density estimate of some data
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kde {
+    /// Finite values of the data in increasing order
+    data: Vec<f64>,
+    kernel: Kernel,
+    bandwidth: f64,
+}
+
+impl Kde {
+    pub fn new(data: &[f64], kernel: Kernel, bandwidth: Bandwidth) -> Kde {
+        Kde { data: finite_sorted(data), kernel: kernel, bandwidth: bandwidth.select(data) }
+    }
+
+    pub fn kernel(&self) -> Kernel { self.kernel }
+
+    pub fn bandwidth(&self) -> f64 { self.bandwidth }
+
+    /// Number of values the estimate is made from
+    pub fn len(&self) -> usize { self.data.len() }
+
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    /// Estimated density at a point, zero everywhere without any data
+    pub fn density(&self, x: f64) -> f64 {
+        if self.data.is_empty() {
+            return 0.0;
+        }
+
+        let reach = self.kernel.support() * self.bandwidth;
+
+        // Only the values within reach of the point contribute
+        let start = count_below(&self.data, |v| v < x - reach);
+        let end = count_below(&self.data, |v| v <= x + reach).max(start);
+
+        let sum: f64 = self.data[start..end].iter().map(|&v| self.kernel.evaluate((x - v) / self.bandwidth)).sum();
+
+        sum / (self.data.len() as f64 * self.bandwidth)
+    }
+
+    /// Estimated density at every point of a grid
+    pub fn evaluate(&self, grid: &[f64]) -> Vec<f64> {
+        grid.iter().map(|&x| self.density(x)).collect()
+    }
+
+    /// Range outside of which the density is zero or negligible, `0..1` without any data
+    pub fn support(&self) -> Range<f64> {
+        match (self.data.first(), self.data.last()) {
+            (Some(&min), Some(&max)) => {
+                let reach = self.kernel.support().min(3.0) * self.bandwidth;
+
+                min - reach..max + reach
+            }
+            _ => 0.0..1.0,
+        }
+    }
+
+    /// Density at evenly spaced points over the support, as `(x, density)`
+    pub fn curve(&self, samples: usize) -> Vec<(f64, f64)> {
+        let range = self.support();
+        let samples = samples.max(2);
+
+        (0..samples).map(|i| {
+            let x = range.start + (range.end - range.start) * i as f64 / (samples - 1) as f64;
+
+            (x, self.density(x))
+        }).collect()
+    }
+}
+
+/// Number of sorted values at the start meeting a condition, which holds for all values before one that doesn't
+fn count_below<F>(sorted: &[f64], condition: F) -> usize where F: Fn(f64) -> bool {
+    let (mut low, mut high) = (0, sorted.len());
+
+    while low < high {
+        let middle = (low + high) / 2;
+
+        if condition(sorted[middle]) { low = middle + 1; } else { high = middle; }
+    }
+
+    low
+}
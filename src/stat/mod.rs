@@ -1,7 +1,13 @@
-//! Probability Distribution Functions
+//! Probability Distribution Functions and statistics of data
+//!
+//! The submodules work on plain slices of values, so they can be used without plotting anything.
 
 use num_traits::Float;
 
+pub mod summary;
+pub mod bins;
+pub mod kde;
+
 pub fn gaussian_dot_pdf(x: f64, width: f64, hardness: f64) -> f64 {
     let exponent = (x / (width * 0.5)).powf(2.0f64.powf(hardness));
 
@@ -0,0 +1,153 @@
+//! Summary statistics of data
+//!
+//! Values that aren't finite are left out, so missing measurements can be marked with NaN.
+//!
+//! ```
+//! use rust_plot::stat::summary::{mean, std_dev, quantile, finite_sorted};
+//!
+//! let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, ::std::f64::NAN];
+//!
+//! assert_eq!(mean(&data), Some(5.0));
+//! assert_eq!(std_dev(&data), Some((32.0f64 / 7.0).sqrt()));
+//! assert_eq!(quantile(&finite_sorted(&data), 0.5), Some(4.5));
+//! ```
+//!
+//! `BoxStats` gathers what a box plot shows of the data:
+//!
+//! ```
+//! use rust_plot::stat::summary::BoxStats;
+//!
+//! let stats = BoxStats::new(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 30.0]).unwrap();
+//!
+//! assert_eq!((stats.q1, stats.median, stats.q3), (3.0, 5.0, 7.0));
+//! assert_eq!((stats.lower_whisker, stats.upper_whisker), (1.0, 8.0));
+//! assert_eq!(stats.outliers, vec![30.0]);
+//! ```
+
+use std::cmp::Ordering;
+
+/// The finite values of the data in increasing order
+pub fn finite_sorted(data: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = data.iter().cloned().filter(|v| v.is_finite()).collect();
+
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    sorted
+}
+
+/// Arithmetic mean of the finite values, or `None` without any
+pub fn mean(data: &[f64]) -> Option<f64> {
+    let (sum, n) = data.iter().filter(|v| v.is_finite()).fold((0.0, 0), |(sum, n), &v| (sum + v, n + 1));
+
+    if n == 0 { None } else { Some(sum / n as f64) }
+}
+
+/// Sample standard deviation of the finite values, or `None` with fewer than two of them
+pub fn std_dev(data: &[f64]) -> Option<f64> {
+    let m = match mean(data) {
+        Some(m) => m,
+        None => return None,
+    };
+
+    let (sum, n) = data.iter().filter(|v| v.is_finite()).fold((0.0, 0), |(sum, n), &v| (sum + (v - m) * (v - m), n + 1));
+
+    if n < 2 { None } else { Some((sum / (n - 1) as f64).sqrt()) }
+}
+
+/// Quantile of sorted finite values, interpolating linearly between the closest ranks
+///
+/// `p` is clamped to `0..1`, where 0 gives the smallest value, 0.5 the median and 1 the largest.
+pub fn quantile(sorted: &[f64], p: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let position = p.max(0.0).min(1.0) * (sorted.len() - 1) as f64;
+
+    let below = position.floor() as usize;
+    let above = (below + 1).min(sorted.len() - 1);
+
+    let t = position - below as f64;
+
+    Some(sorted[below] + (sorted[above] - sorted[below]) * t)
+}
+
+/// Distance between the first and third quartile of sorted finite values
+pub fn interquartile_range(sorted: &[f64]) -> Option<f64> {
+    match (quantile(sorted, 0.25), quantile(sorted, 0.75)) {
+        (Some(q1), Some(q3)) => Some(q3 - q1),
+        _ => None,
+    }
+}
+
+/// First quartile, median and third quartile of sorted finite values
+pub fn quartiles(sorted: &[f64]) -> Option<(f64, f64, f64)> {
+    match (quantile(sorted, 0.25), quantile(sorted, 0.5), quantile(sorted, 0.75)) {
+        (Some(q1), Some(median), Some(q3)) => Some((q1, median, q3)),
+        _ => None,
+    }
+}
+
+/// Summary of data shown by a box plot
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxStats {
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+    /// Smallest value within the lower fence
+    pub lower_whisker: f64,
+    /// Largest value within the upper fence
+    pub upper_whisker: f64,
+    /// Values beyond the fences, in increasing order
+    pub outliers: Vec<f64>,
+    /// Number of finite values
+    pub count: usize,
+}
+
+impl BoxStats {
+    /// Summary with Tukey's fences, 1.5 interquartile ranges beyond the quartiles, or `None` without finite values
+    pub fn new(data: &[f64]) -> Option<BoxStats> {
+        BoxStats::with_fences(data, 1.5)
+    }
+
+    /// Summary with fences `k` interquartile ranges beyond the quartiles
+    pub fn with_fences(data: &[f64], k: f64) -> Option<BoxStats> {
+        let sorted = finite_sorted(data);
+
+        let (q1, median, q3) = match quartiles(&sorted) {
+            Some(quartiles) => quartiles,
+            None => return None,
+        };
+
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - k * iqr, q3 + k * iqr);
+
+        // The quartiles lie between the data, so at least one value is within each fence
+        let lower_whisker = sorted.iter().cloned().find(|&v| v >= low).unwrap_or(q1);
+        let upper_whisker = sorted.iter().cloned().rev().find(|&v| v <= high).unwrap_or(q3);
+
+        Some(BoxStats {
+            q1: q1,
+            median: median,
+            q3: q3,
+            lower_whisker: lower_whisker,
+            upper_whisker: upper_whisker,
+            outliers: sorted.iter().cloned().filter(|&v| v < low || v > high).collect(),
+            count: sorted.len(),
+        })
+    }
+
+    /// Rough 95% confidence interval of the median, `1.57 IQR / √n` around it, shown by the notches of a box
+    pub fn notch(&self) -> (f64, f64) {
+        let half = 1.57 * (self.q3 - self.q1) / (self.count as f64).sqrt();
+
+        (self.median - half, self.median + half)
+    }
+
+    /// Smallest and largest value shown, including outliers
+    pub fn range(&self) -> (f64, f64) {
+        let min = self.outliers.first().map_or(self.lower_whisker, |&v| v.min(self.lower_whisker));
+        let max = self.outliers.last().map_or(self.upper_whisker, |&v| v.max(self.upper_whisker));
+
+        (min, max)
+    }
+}
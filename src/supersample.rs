@@ -0,0 +1,304 @@
+//! Supersampled rendering on top of `ImagePlot`
+//!
+//! Drawing happens at a multiple of the output resolution and the result is downsampled with a
+//! reconstruction filter when exported, which smooths out even the aliased primitives.
+
+use std::f64::consts::PI;
+
+use image::{Rgba, RgbaImage};
+
+use ::canvas::{Anchor, TextExtent};
+use ::color::{Dither, Color};
+use ::image_plot::{ImagePlot, RgbaFloatImage, linear_image_to_u8_srgb};
+use ::plot::{LineStyle, Plotter};
+use ::plot::clip::ClipStack;
+
+/// Hardness used when widening thin lines to cover a full output pixel
+const THIN_HARDNESS: f64 = 4.0;
+
+/// Reconstruction filters used when downsampling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Filter {
+    /// Averages all samples within each output pixel.
+    Box,
+    /// Linear falloff over a radius of one output pixel.
+    Tent,
+    /// Windowed sinc with three lobes. Sharpest, but may ring slightly around hard edges.
+    Lanczos3,
+}
+
+impl Filter {
+    /// Radius of the filter, in output pixels
+    pub fn support(&self) -> f64 {
+        match *self {
+            Filter::Box => 0.5,
+            Filter::Tent => 1.0,
+            Filter::Lanczos3 => 3.0,
+        }
+    }
+
+    /// Evaluate the filter at a distance `x`, in output pixels, from the pixel center
+    pub fn weight(&self, x: f64) -> f64 {
+        let x = x.abs();
+
+        match *self {
+            Filter::Box => if x <= 0.5 { 1.0 } else { 0.0 },
+            Filter::Tent => if x < 1.0 { 1.0 - x } else { 0.0 },
+            Filter::Lanczos3 => {
+                if x < 1e-8 {
+                    1.0
+                } else if x < 3.0 {
+                    let px = PI * x;
+                    3.0 * px.sin() * (px / 3.0).sin() / (px * px)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+/// Plotter that renders onto an `ImagePlot` at `factor` times the resolution.
+///
+/// `width()`, `height()` and all drawing coordinates and line widths are in output pixels,
+/// so anything drawn onto an `ImagePlot` can be drawn onto a `SupersamplePlot` unchanged.
+pub struct SupersamplePlot {
+    plot: ImagePlot,
+    factor: u32,
+    clip: ClipStack,
+}
+
+impl SupersamplePlot {
+    pub fn new(width: u32, height: u32, factor: u32) -> SupersamplePlot {
+        SupersamplePlot::with_background(width, height, factor, Rgba { data: [1.0, 1.0, 1.0, 1.0] })
+    }
+
+    pub fn with_background(width: u32, height: u32, factor: u32, background: Rgba<f32>) -> SupersamplePlot {
+        assert!(factor > 0, "Supersampling factor must be at least 1");
+
+        SupersamplePlot {
+            plot: ImagePlot::with_background(width * factor, height * factor, background),
+            factor: factor,
+            clip: ClipStack::new(),
+        }
+    }
+
+    pub fn factor(&self) -> u32 { self.factor }
+
+    pub fn set_foreground(&mut self, foreground: Rgba<f32>) -> Rgba<f32> {
+        self.plot.set_foreground(foreground)
+    }
+
+    pub fn foreground(&self) -> Rgba<f32> {
+        self.plot.foreground()
+    }
+
+    /// The full resolution plot
+    pub fn inner(&self) -> &ImagePlot { &self.plot }
+
+    /// The full resolution plot
+    pub fn inner_mut(&mut self) -> &mut ImagePlot { &mut self.plot }
+
+    /// Consumes the plot and returns the downsampled image, in linear light
+    pub fn into_image(self, filter: Filter) -> RgbaFloatImage {
+        downsample(self.plot.image(), self.factor, filter)
+    }
+
+    /// Consumes the plot and returns the downsampled image as 8-bit sRGB
+    pub fn into_u8_component_image(self, filter: Filter) -> RgbaImage {
+        self.into_u8_component_image_dithered(filter, Dither::None)
+    }
+
+    /// Consumes the plot and returns the downsampled image as 8-bit sRGB, dithered before quantization
+    pub fn into_u8_component_image_dithered(self, filter: Filter, dither: Dither) -> RgbaImage {
+        linear_image_to_u8_srgb(&self.into_image(filter), dither)
+    }
+
+    /// Maps an output pixel coordinate to the center of its block in the full resolution plot
+    #[inline]
+    fn scale_coord(&self, c: f64) -> f64 {
+        c * self.factor as f64 + (self.factor - 1) as f64 * 0.5
+    }
+
+    /// Scales a line style so that it covers the same area of the output image
+    fn scale_style(&self, style: LineStyle) -> LineStyle {
+        let factor = self.factor as f64;
+
+        if self.factor == 1 {
+            return style;
+        }
+
+        match style {
+            LineStyle::Thin => LineStyle::Thick { width: factor, hardness: THIN_HARDNESS },
+            LineStyle::ThinAA => LineStyle::ThickAA { width: factor, hardness: THIN_HARDNESS },
+            LineStyle::Thick { width, hardness } => LineStyle::Thick { width: width * factor, hardness: hardness },
+            LineStyle::ThickAA { width, hardness } => LineStyle::ThickAA { width: width * factor, hardness: hardness },
+        }
+    }
+}
+
+impl Plotter for SupersamplePlot {
+    fn width(&self) -> u32 { self.plot.width() / self.factor }
+
+    fn height(&self) -> u32 { self.plot.height() / self.factor }
+
+    fn set_color(&mut self, color: Color) {
+        self.plot.set_color(color)
+    }
+
+    fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
+        let factor = self.factor as i64;
+
+        for dy in 0..factor {
+            for dx in 0..factor {
+                self.plot.draw_pixel(x * factor + dx, y * factor + dy, alpha);
+            }
+        }
+    }
+
+    fn draw_dot(&mut self, x: i64, y: i64, alpha: f64, width: f64, hardness: f64) {
+        let (x, y) = (self.scale_coord(x as f64).round() as i64, self.scale_coord(y as f64).round() as i64);
+
+        self.plot.draw_dot(x, y, alpha, width * self.factor as f64, hardness)
+    }
+
+    fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, style: LineStyle) {
+        let style = self.scale_style(style);
+
+        let (x0, y0) = (self.scale_coord(x0), self.scale_coord(y0));
+        let (x1, y1) = (self.scale_coord(x1), self.scale_coord(y1));
+
+        self.plot.draw_line(x0, y0, x1, y1, style)
+    }
+
+    fn draw_circle(&mut self, x: i64, y: i64, radius: i64, style: LineStyle) {
+        let style = self.scale_style(style);
+
+        let (x, y) = (self.scale_coord(x as f64).round() as i64, self.scale_coord(y as f64).round() as i64);
+
+        self.plot.draw_circle(x, y, radius * self.factor as i64, style)
+    }
+
+    fn draw_ellipse(&mut self, x0: i64, y0: i64, x1: i64, y1: i64, style: LineStyle) {
+        let style = self.scale_style(style);
+
+        let (x0, y0) = (self.scale_coord(x0 as f64).round() as i64, self.scale_coord(y0 as f64).round() as i64);
+        let (x1, y1) = (self.scale_coord(x1 as f64).round() as i64, self.scale_coord(y1 as f64).round() as i64);
+
+        self.plot.draw_ellipse(x0, y0, x1, y1, style)
+    }
+
+    fn fill_compound_polygon(&mut self, contours: &[&[(f64, f64)]]) {
+        let scaled: Vec<Vec<(f64, f64)>> = contours.iter().map(|contour| {
+            contour.iter().map(|&(x, y)| (self.scale_coord(x), self.scale_coord(y))).collect()
+        }).collect();
+
+        let scaled: Vec<&[(f64, f64)]> = scaled.iter().map(|contour| &contour[..]).collect();
+
+        self.plot.fill_compound_polygon(&scaled)
+    }
+
+    fn draw_rotated_text(&mut self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) {
+        let (x, y) = (self.scale_coord(x), self.scale_coord(y));
+
+        self.plot.draw_rotated_text(x, y, text, anchor, size * self.factor as f64, rotation)
+    }
+
+    fn measure_text(&self, text: &str, size: f64) -> TextExtent {
+        let factor = self.factor as f64;
+        let extent = self.plot.measure_text(text, size * factor);
+
+        TextExtent { width: extent.width / factor, ascent: extent.ascent / factor, descent: extent.descent / factor }
+    }
+
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> { Some(&mut self.clip) }
+}
+
+/// Precomputed filter taps for a single output pixel
+struct Taps {
+    start: usize,
+    weights: Vec<f32>,
+}
+
+fn compute_taps(input: u32, output: u32, factor: u32, filter: Filter) -> Vec<Taps> {
+    let factor = factor as f64;
+    let support = filter.support();
+
+    (0..output).map(|o| {
+        let center = o as f64 + 0.5;
+
+        let start = ((center - support) * factor).floor().max(0.0) as usize;
+        let end = (((center + support) * factor).ceil() as usize).min(input as usize);
+
+        let mut weights: Vec<f32> = (start..end).map(|i| {
+            filter.weight((i as f64 + 0.5) / factor - center) as f32
+        }).collect();
+
+        let total: f32 = weights.iter().sum();
+
+        if total != 0.0 {
+            for w in &mut weights {
+                *w /= total;
+            }
+        }
+
+        Taps { start: start, weights: weights }
+    }).collect()
+}
+
+/// Downsamples a linear light image by an integer factor using a separable filter.
+///
+/// Filtering is done with premultiplied alpha so transparent pixels don't bleed their color.
+pub fn downsample(image: &RgbaFloatImage, factor: u32, filter: Filter) -> RgbaFloatImage {
+    let (w, h) = image.dimensions();
+    let (ow, oh) = (w / factor, h / factor);
+
+    let x_taps = compute_taps(w, ow, factor, filter);
+    let y_taps = compute_taps(h, oh, factor, filter);
+
+    let premultiplied: Vec<[f32; 4]> = image.pixels().map(|p| {
+        let a = p.data[3];
+        [p.data[0] * a, p.data[1] * a, p.data[2] * a, a]
+    }).collect();
+
+    // Horizontal pass, `ow` by `h`
+    let mut horizontal = vec![[0.0f32; 4]; (ow * h) as usize];
+
+    for y in 0..h as usize {
+        let row = &premultiplied[y * w as usize..(y + 1) * w as usize];
+
+        for (x, taps) in x_taps.iter().enumerate() {
+            let out = &mut horizontal[y * ow as usize + x];
+
+            for (i, weight) in taps.weights.iter().enumerate() {
+                let p = row[taps.start + i];
+
+                for c in 0..4 {
+                    out[c] += p[c] * weight;
+                }
+            }
+        }
+    }
+
+    // Vertical pass, `ow` by `oh`
+    RgbaFloatImage::from_fn(ow, oh, |x, y| {
+        let taps = &y_taps[y as usize];
+        let mut sum = [0.0f32; 4];
+
+        for (i, weight) in taps.weights.iter().enumerate() {
+            let p = horizontal[(taps.start + i) * ow as usize + x as usize];
+
+            for c in 0..4 {
+                sum[c] += p[c] * weight;
+            }
+        }
+
+        let a = sum[3].max(0.0).min(1.0);
+
+        if a > 0.0 {
+            Rgba { data: [sum[0] / a, sum[1] / a, sum[2] / a, a] }
+        } else {
+            Rgba { data: [0.0, 0.0, 0.0, 0.0] }
+        }
+    })
+}
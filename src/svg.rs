@@ -0,0 +1,293 @@
+//! SVG vector output
+//!
+//! `SvgPlot` is a `Canvas` that writes each primitive as an SVG element, producing a standalone
+//! document that scales without loss.
+
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use ::color::Color;
+use ::vector::format_number;
+use ::canvas::{Canvas, Stroke, TextStyle, TextExtent, HAlign, VAlign};
+
+/// Average advance of a sans-serif glyph, relative to the font size
+const AVERAGE_ADVANCE: f64 = 0.55;
+const ASCENT: f64 = 0.75;
+const DESCENT: f64 = 0.25;
+
+/// Canvas that records primitives as SVG elements
+pub struct SvgPlot {
+    width: u32,
+    height: u32,
+    background: Option<Color>,
+    font_family: String,
+    body: String,
+    /// Number of clip paths defined so far, used to give each a unique id
+    clip_count: usize,
+    clip_depth: usize,
+}
+
+/// Formats a color as `#rrggbb`, leaving alpha to a separate opacity attribute
+fn format_color(color: Color) -> String {
+    let c = color.to_u8();
+
+    format!("#{:02x}{:02x}{:02x}", c[0], c[1], c[2])
+}
+
+/// Escapes text for use in SVG content and attribute values
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+impl SvgPlot {
+    pub fn new(width: u32, height: u32) -> SvgPlot {
+        SvgPlot::with_background(width, height, Some(Color::white()))
+    }
+
+    /// Create a new SVG document with an optional background fill
+    pub fn with_background(width: u32, height: u32, background: Option<Color>) -> SvgPlot {
+        SvgPlot {
+            width: width,
+            height: height,
+            background: background,
+            font_family: "sans-serif".to_owned(),
+            body: String::new(),
+            clip_count: 0,
+            clip_depth: 0,
+        }
+    }
+
+    /// Set the CSS font family used for text
+    pub fn set_font_family(&mut self, family: &str) {
+        self.font_family = family.to_owned();
+    }
+
+    /// Flip a y coordinate from the canvas' y-up convention to SVG's y-down
+    #[inline]
+    fn y(&self, y: f64) -> f64 {
+        self.height as f64 - y
+    }
+
+    fn point(&self, x: f64, y: f64) -> String {
+        format!("{},{}", format_number(x), format_number(self.y(y)))
+    }
+
+    fn fill_attributes(fill: Option<Color>) -> String {
+        match fill {
+            Some(color) if color.a < 1.0 => {
+                format!(r#" fill="{}" fill-opacity="{}""#, format_color(color), format_number(color.a as f64))
+            }
+            Some(color) => format!(r#" fill="{}""#, format_color(color)),
+            None => r#" fill="none""#.to_owned(),
+        }
+    }
+
+    fn stroke_attributes(stroke: Option<Stroke>) -> String {
+        let stroke = match stroke {
+            Some(stroke) => stroke,
+            None => return String::new(),
+        };
+
+        // Dashes keep square ends so the gaps stay the requested length
+        let cap = if stroke.dash.is_some() { "butt" } else { "round" };
+
+        let mut attributes = format!(r#" stroke="{}" stroke-width="{}" stroke-linecap="{}" stroke-linejoin="round""#,
+                                     format_color(stroke.color), format_number(stroke.width()), cap);
+
+        if stroke.color.a < 1.0 {
+            write!(attributes, r#" stroke-opacity="{}""#, format_number(stroke.color.a as f64)).unwrap();
+        }
+
+        if let Some((on, off)) = stroke.dash {
+            write!(attributes, r#" stroke-dasharray="{} {}""#, format_number(on), format_number(off)).unwrap();
+        }
+
+        if !stroke.style.is_aa() {
+            attributes.push_str(r#" shape-rendering="crispEdges""#);
+        }
+
+        attributes
+    }
+
+    /// The complete SVG document
+    pub fn document(&self) -> String {
+        let mut document = String::new();
+
+        writeln!(document, r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>"#).unwrap();
+        writeln!(document, r#"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+                 self.width, self.height).unwrap();
+
+        if let Some(background) = self.background {
+            writeln!(document, r#"<rect x="0" y="0" width="{}" height="{}"{}/>"#,
+                     self.width, self.height, SvgPlot::fill_attributes(Some(background))).unwrap();
+        }
+
+        document.push_str(&self.body);
+
+        // Close any clipping groups left open
+        for _ in 0..self.clip_depth {
+            document.push_str("</g>\n");
+        }
+
+        document.push_str("</svg>\n");
+
+        document
+    }
+
+    /// Write the SVG document to a writer
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.document().as_bytes())
+    }
+
+    /// Write the SVG document to a file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(&mut File::create(path)?)
+    }
+}
+
+impl Canvas for SvgPlot {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, stroke: Stroke) {
+        let element = format!(r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                              format_number(x0), format_number(self.y(y0)),
+                              format_number(x1), format_number(self.y(y1)),
+                              SvgPlot::stroke_attributes(Some(stroke)));
+
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke) {
+        let points: Vec<String> = points.iter().map(|&(x, y)| self.point(x, y)).collect();
+
+        let element = format!(r#"<polyline points="{}" fill="none"{}/>"#,
+                              points.join(" "), SvgPlot::stroke_attributes(Some(stroke)));
+
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>) {
+        let mut path = String::new();
+
+        for contour in contours {
+            for (i, &(x, y)) in contour.iter().enumerate() {
+                path.push_str(if i == 0 { "M" } else { "L" });
+                path.push_str(&self.point(x, y));
+            }
+
+            if !contour.is_empty() {
+                path.push('Z');
+            }
+        }
+
+        let element = format!(r#"<path d="{}" fill-rule="nonzero"{}{}/>"#,
+                              path, SvgPlot::fill_attributes(fill), SvgPlot::stroke_attributes(stroke));
+
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        let (left, right) = (x0.min(x1), x0.max(x1));
+        let (bottom, top) = (y0.min(y1), y0.max(y1));
+
+        let element = format!(r#"<rect x="{}" y="{}" width="{}" height="{}"{}{}/>"#,
+                              format_number(left), format_number(self.y(top)),
+                              format_number(right - left), format_number(top - bottom),
+                              SvgPlot::fill_attributes(fill), SvgPlot::stroke_attributes(stroke));
+
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        let element = if rx == ry {
+            format!(r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+                    format_number(cx), format_number(self.y(cy)), format_number(rx.abs()),
+                    SvgPlot::fill_attributes(fill), SvgPlot::stroke_attributes(stroke))
+        } else {
+            format!(r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}"{}{}/>"#,
+                    format_number(cx), format_number(self.y(cy)), format_number(rx.abs()), format_number(ry.abs()),
+                    SvgPlot::fill_attributes(fill), SvgPlot::stroke_attributes(stroke))
+        };
+
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle) {
+        let anchor = match style.anchor.horizontal {
+            HAlign::Left => "start",
+            HAlign::Center => "middle",
+            HAlign::Right => "end",
+        };
+
+        let baseline = match style.anchor.vertical {
+            VAlign::Top => "text-before-edge",
+            VAlign::Middle => "central",
+            VAlign::Baseline => "alphabetic",
+            VAlign::Bottom => "text-after-edge",
+        };
+
+        let (sx, sy) = (format_number(x), format_number(self.y(y)));
+
+        let mut element = format!(r#"<text x="{}" y="{}" font-family="{}" font-size="{}" text-anchor="{}" dominant-baseline="{}"{}"#,
+                                  sx, sy, escape(&self.font_family), format_number(style.size), anchor, baseline,
+                                  SvgPlot::fill_attributes(Some(style.color)));
+
+        if style.rotation != 0.0 {
+            write!(element, r#" transform="rotate({} {} {})""#, format_number(-style.rotation.to_degrees()), sx, sy).unwrap();
+        }
+
+        write!(element, ">{}</text>", escape(text)).unwrap();
+
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    /// Estimates the extent from average glyph metrics, since the final font is chosen by the viewer
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        TextExtent {
+            width: text.chars().count() as f64 * style.size * AVERAGE_ADVANCE,
+            ascent: style.size * ASCENT,
+            descent: style.size * DESCENT,
+        }
+    }
+
+    fn push_clip_polygon(&mut self, points: &[(f64, f64)]) {
+        let points: Vec<String> = points.iter().map(|&(x, y)| self.point(x, y)).collect();
+
+        self.clip_count += 1;
+
+        writeln!(self.body, r#"<clipPath id="clip{0}"><polygon points="{1}"/></clipPath>"#, self.clip_count, points.join(" ")).unwrap();
+        writeln!(self.body, r#"<g clip-path="url(#clip{})">"#, self.clip_count).unwrap();
+
+        self.clip_depth += 1;
+    }
+
+    fn pop_clip(&mut self) {
+        if self.clip_depth > 0 {
+            self.body.push_str("</g>\n");
+            self.clip_depth -= 1;
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Plotting to text for terminals and logs
+//!
+//! `TerminalPlot` is a `Plotter` whose pixels are dots of Unicode Braille patterns or halves of
+//! block characters, so anything that can be drawn onto an image can be printed to a terminal.
+
+use std::fmt;
+
+use ::color::Color;
+use ::plot::Plotter;
+use ::plot::clip::ClipStack;
+
+/// How pixels are packed into character cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalMode {
+    /// Braille patterns, with two by four pixels per cell
+    Braille,
+    /// Upper and lower half blocks, with one by two pixels per cell
+    HalfBlock,
+}
+
+impl TerminalMode {
+    /// Pixels per cell, horizontally and vertically
+    pub fn cell_size(&self) -> (u32, u32) {
+        match *self {
+            TerminalMode::Braille => (2, 4),
+            TerminalMode::HalfBlock => (1, 2),
+        }
+    }
+}
+
+/// ANSI escape codes used to color the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalColor {
+    /// Plain text without any escape codes
+    None,
+    /// The 256 color palette supported by most terminals
+    Ansi256,
+    /// 24-bit color
+    TrueColor,
+}
+
+/// Bit of a Braille pattern for each pixel of a cell, indexed by row from the top and then column
+const BRAILLE_BITS: [[u32; 2]; 4] = [
+    [0x01, 0x08],
+    [0x02, 0x10],
+    [0x04, 0x20],
+    [0x40, 0x80],
+];
+
+/// Plotter that renders to Braille or block characters
+pub struct TerminalPlot {
+    columns: u32,
+    rows: u32,
+    mode: TerminalMode,
+    color: TerminalColor,
+    threshold: f64,
+    foreground: Color,
+    coverage: Vec<f64>,
+    colors: Vec<Color>,
+    clip: ClipStack,
+}
+
+/// Maps a color to the closest entry of the 6x6x6 color cube or the grayscale ramp of the 256 color palette
+pub fn ansi256_index(color: Color) -> u8 {
+    let c = color.to_u8();
+
+    let (r, g, b) = (c[0] as i32, c[1] as i32, c[2] as i32);
+
+    // Levels of the color cube
+    fn cube_level(v: i32) -> i32 {
+        if v < 48 { 0 } else if v < 115 { 1 } else { (v - 35) / 40 }
+    }
+
+    fn cube_value(level: i32) -> i32 {
+        if level == 0 { 0 } else { 55 + level * 40 }
+    }
+
+    let (lr, lg, lb) = (cube_level(r), cube_level(g), cube_level(b));
+    let (cr, cg, cb) = (cube_value(lr), cube_value(lg), cube_value(lb));
+
+    let average = (r + g + b) / 3;
+    let gray_level = if average > 238 { 23 } else { ((average - 3).max(0)) / 10 };
+    let gray = 8 + gray_level * 10;
+
+    let distance = |x: i32, y: i32, z: i32| (r - x) * (r - x) + (g - y) * (g - y) + (b - z) * (b - z);
+
+    if distance(gray, gray, gray) < distance(cr, cg, cb) {
+        (232 + gray_level) as u8
+    } else {
+        (16 + 36 * lr + 6 * lg + lb) as u8
+    }
+}
+
+impl TerminalPlot {
+    /// Create a plot that fills the given number of character columns and rows
+    pub fn new(columns: u32, rows: u32, mode: TerminalMode) -> TerminalPlot {
+        let (cw, ch) = mode.cell_size();
+        let pixels = (columns * cw * rows * ch) as usize;
+
+        TerminalPlot {
+            columns: columns,
+            rows: rows,
+            mode: mode,
+            color: TerminalColor::None,
+            threshold: 0.5,
+            foreground: Color::white(),
+            coverage: vec![0.0; pixels],
+            colors: vec![Color::white(); pixels],
+            clip: ClipStack::new(),
+        }
+    }
+
+    /// Set the kind of ANSI color codes written to the output
+    pub fn set_terminal_color(&mut self, color: TerminalColor) {
+        self.color = color;
+    }
+
+    /// Set the minimum coverage for a pixel to be shown. Defaults to `0.5`.
+    pub fn set_threshold(&mut self, threshold: f64) {
+        self.threshold = threshold;
+    }
+
+    pub fn columns(&self) -> u32 { self.columns }
+
+    pub fn rows(&self) -> u32 { self.rows }
+
+    /// Erase everything drawn so far
+    pub fn clear(&mut self) {
+        for c in &mut self.coverage {
+            *c = 0.0;
+        }
+    }
+
+    /// Returns true if the pixel at `x` and `y`, counting rows from the top, is shown
+    fn lit(&self, x: u32, y_from_top: u32) -> bool {
+        let y = self.height() - y_from_top - 1;
+
+        self.coverage[(y * self.width() + x) as usize] >= self.threshold
+    }
+
+    fn pixel_color(&self, x: u32, y_from_top: u32) -> Color {
+        let y = self.height() - y_from_top - 1;
+
+        self.colors[(y * self.width() + x) as usize]
+    }
+
+    fn foreground_code(&self, color: Color) -> String {
+        let c = color.to_u8();
+
+        match self.color {
+            TerminalColor::None => String::new(),
+            TerminalColor::Ansi256 => format!("\x1b[38;5;{}m", ansi256_index(color)),
+            TerminalColor::TrueColor => format!("\x1b[38;2;{};{};{}m", c[0], c[1], c[2]),
+        }
+    }
+
+    fn background_code(&self, color: Color) -> String {
+        let c = color.to_u8();
+
+        match self.color {
+            TerminalColor::None => String::new(),
+            TerminalColor::Ansi256 => format!("\x1b[48;5;{}m", ansi256_index(color)),
+            TerminalColor::TrueColor => format!("\x1b[48;2;{};{};{}m", c[0], c[1], c[2]),
+        }
+    }
+
+    /// Render the plot as text, one line per row of cells
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        let (cw, ch) = self.mode.cell_size();
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let (x0, y0) = (column * cw, row * ch);
+
+                match self.mode {
+                    TerminalMode::Braille => {
+                        let mut bits = 0;
+                        let mut color = None;
+
+                        for dy in 0..4 {
+                            for dx in 0..2 {
+                                if self.lit(x0 + dx, y0 + dy) {
+                                    bits |= BRAILLE_BITS[dy as usize][dx as usize];
+                                    color = Some(self.pixel_color(x0 + dx, y0 + dy));
+                                }
+                            }
+                        }
+
+                        match color {
+                            Some(color) => {
+                                out.push_str(&self.foreground_code(color));
+                                out.push(::std::char::from_u32(0x2800 + bits).unwrap());
+                            }
+                            None => out.push(' '),
+                        }
+                    }
+                    TerminalMode::HalfBlock => {
+                        let top = if self.lit(x0, y0) { Some(self.pixel_color(x0, y0)) } else { None };
+                        let bottom = if self.lit(x0, y0 + 1) { Some(self.pixel_color(x0, y0 + 1)) } else { None };
+
+                        match (top, bottom) {
+                            (Some(top), Some(bottom)) => {
+                                if top == bottom || self.color == TerminalColor::None {
+                                    out.push_str(&self.foreground_code(top));
+                                    out.push('\u{2588}');
+                                } else {
+                                    out.push_str(&self.foreground_code(top));
+                                    out.push_str(&self.background_code(bottom));
+                                    out.push('\u{2580}');
+                                    out.push_str("\x1b[49m");
+                                }
+                            }
+                            (Some(top), None) => {
+                                out.push_str(&self.foreground_code(top));
+                                out.push('\u{2580}');
+                            }
+                            (None, Some(bottom)) => {
+                                out.push_str(&self.foreground_code(bottom));
+                                out.push('\u{2584}');
+                            }
+                            (None, None) => out.push(' '),
+                        }
+                    }
+                }
+            }
+
+            if self.color != TerminalColor::None {
+                out.push_str("\x1b[0m");
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for TerminalPlot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+impl Plotter for TerminalPlot {
+    fn width(&self) -> u32 { self.columns * self.mode.cell_size().0 }
+
+    fn height(&self) -> u32 { self.rows * self.mode.cell_size().1 }
+
+    fn set_color(&mut self, color: Color) {
+        self.foreground = color;
+    }
+
+    fn draw_pixel(&mut self, x: i64, y: i64, alpha: f64) {
+        let (w, h) = (self.width() as i64, self.height() as i64);
+
+        if 0 <= x && x < w && 0 <= y && y < h && alpha > 0.0 {
+            let i = (y * w + x) as usize;
+            let alpha = alpha.min(1.0) * self.foreground.a as f64;
+
+            // Coverage accumulates like alpha compositing, so overlapping faint pixels add up
+            self.coverage[i] = 1.0 - (1.0 - self.coverage[i]) * (1.0 - alpha);
+            self.colors[i] = self.foreground;
+        }
+    }
+
+    fn clip_stack(&mut self) -> Option<&mut ClipStack> { Some(&mut self.clip) }
+}
@@ -0,0 +1,299 @@
+//! 10x20 pixel glyphs from the public domain X11 "fixed" terminal font
+
+use super::BitmapFont;
+
+pub static FIXED_10X20: BitmapFont = BitmapFont {
+    width: 10,
+    height: 20,
+    ascent: 16,
+    glyphs: &GLYPHS,
+};
+
+/// One row of pixels per entry, with the leftmost pixel in the highest bit
+static GLYPHS: [u16; 5700] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // space
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // !
+    0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x3300, 0x1200, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // "
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0D80, 0x0D80, 0x0D80, 0x3FC0, 0x1B00, 0x1B00, 0x1B00, 0x7F80, 0x3600, 0x3600, 0x3600, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // #
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x3F00, 0x6D80, 0x6C00, 0x6C00, 0x6C00, 0x3F00, 0x0D80, 0x0D80, 0x0D80, 0x6D80, 0x3F00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // $
+    0x0000, 0x0000, 0x0000, 0x0000, 0x3980, 0x6D80, 0x6F00, 0x3B00, 0x0600, 0x0600, 0x0C00, 0x0C00, 0x1B80, 0x1EC0, 0x36C0, 0x3380, 0x0000, 0x0000, 0x0000, 0x0000, // %
+    0x0000, 0x0000, 0x0000, 0x1C00, 0x3600, 0x3600, 0x3600, 0x3C00, 0x1800, 0x3800, 0x6C00, 0x66C0, 0x6380, 0x6300, 0x7780, 0x3CC0, 0x0000, 0x0000, 0x0000, 0x0000, // &
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // '
+    0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0C00, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x0C00, 0x0C00, 0x0600, 0x0300, 0x0000, 0x0000, 0x0000, 0x0000, // (
+    0x0000, 0x0000, 0x0000, 0x3000, 0x1800, 0x0C00, 0x0C00, 0x0600, 0x0600, 0x0600, 0x0600, 0x0600, 0x0C00, 0x0C00, 0x1800, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, // )
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x1E00, 0x7F80, 0x1E00, 0x3300, 0x3300, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // *
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // +
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x1C00, 0x0000, 0x0000, 0x0000, // ,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // -
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, // .
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0180, 0x0180, 0x0300, 0x0300, 0x0600, 0x0600, 0x0C00, 0x0C00, 0x1800, 0x1800, 0x3000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, // /
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // 0
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1C00, 0x3C00, 0x6C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // 1
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x0180, 0x0180, 0x0300, 0x0E00, 0x1800, 0x3000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // 2
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x0180, 0x0300, 0x0E00, 0x0300, 0x0180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // 3
+    0x0000, 0x0000, 0x0000, 0x0100, 0x0300, 0x0700, 0x0F00, 0x1B00, 0x3300, 0x6300, 0x6300, 0x7F80, 0x0300, 0x0300, 0x0300, 0x0300, 0x0000, 0x0000, 0x0000, 0x0000, // 4
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x6E00, 0x7300, 0x0180, 0x0180, 0x0180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // 5
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6100, 0x6000, 0x6000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // 6
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0180, 0x0180, 0x0300, 0x0300, 0x0600, 0x0600, 0x0C00, 0x0C00, 0x1800, 0x1800, 0x3000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, // 7
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // 8
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0180, 0x0180, 0x2180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // 9
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, // :
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x1C00, 0x0000, 0x0000, 0x0000, // ;
+    0x0000, 0x0000, 0x0000, 0x0100, 0x0300, 0x0600, 0x0C00, 0x1800, 0x3000, 0x6000, 0x3000, 0x1800, 0x0C00, 0x0600, 0x0300, 0x0100, 0x0000, 0x0000, 0x0000, 0x0000, // <
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // =
+    0x0000, 0x0000, 0x0000, 0x2000, 0x3000, 0x1800, 0x0C00, 0x0600, 0x0300, 0x0180, 0x0300, 0x0600, 0x0C00, 0x1800, 0x3000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, // >
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x0300, 0x0600, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ?
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6780, 0x6F80, 0x6D80, 0x6D80, 0x6D80, 0x6F00, 0x6600, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // @
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // A
+    0x0000, 0x0000, 0x0000, 0x7C00, 0x6600, 0x6300, 0x6300, 0x6300, 0x6600, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x0000, 0x0000, 0x0000, 0x0000, // B
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // C
+    0x0000, 0x0000, 0x0000, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x0000, 0x0000, 0x0000, 0x0000, // D
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // E
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, // F
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6000, 0x6000, 0x6000, 0x6780, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1E80, 0x0000, 0x0000, 0x0000, 0x0000, // G
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // H
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // I
+    0x0000, 0x0000, 0x0000, 0x0FC0, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x6300, 0x6300, 0x3600, 0x1C00, 0x0000, 0x0000, 0x0000, 0x0000, // J
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6300, 0x6300, 0x6600, 0x6600, 0x7C00, 0x6600, 0x6600, 0x6300, 0x6300, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // K
+    0x0000, 0x0000, 0x0000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // L
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x7380, 0x7380, 0x7F80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // M
+    0x0000, 0x0000, 0x0000, 0x6180, 0x7180, 0x7180, 0x7980, 0x7980, 0x6D80, 0x6D80, 0x6780, 0x6780, 0x6380, 0x6380, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // N
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // O
+    0x0000, 0x0000, 0x0000, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, // P
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6D80, 0x6780, 0x3300, 0x1F00, 0x0180, 0x0000, 0x0000, 0x0000, // Q
+    0x0000, 0x0000, 0x0000, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x6600, 0x6300, 0x6300, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // R
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6000, 0x6000, 0x3000, 0x1E00, 0x0300, 0x0180, 0x0180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // S
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // T
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // U
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // V
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x7380, 0x7380, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // W
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x1E00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // X
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Y
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0180, 0x0180, 0x0300, 0x0600, 0x0600, 0x0C00, 0x1800, 0x1800, 0x3000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Z
+    0x0000, 0x0000, 0x0000, 0x3F00, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0x3000, 0x1800, 0x1800, 0x0C00, 0x0C00, 0x0600, 0x0600, 0x0300, 0x0300, 0x0180, 0x0180, 0x0000, 0x0000, 0x0000, 0x0000, // \
+    0x0000, 0x0000, 0x0000, 0x3F00, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x0300, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // ]
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ^
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7FC0, 0x0000, 0x0000, 0x0000, // _
+    0x0000, 0x0000, 0x0000, 0x1800, 0x0C00, 0x0600, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // `
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F00, 0x3180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // a
+    0x0000, 0x0000, 0x0000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x7300, 0x6E00, 0x0000, 0x0000, 0x0000, 0x0000, // b
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F00, 0x3180, 0x6000, 0x6000, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // c
+    0x0000, 0x0000, 0x0000, 0x0180, 0x0180, 0x0180, 0x0180, 0x0180, 0x1D80, 0x3380, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0000, 0x0000, 0x0000, 0x0000, // d
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x7F80, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // e
+    0x0000, 0x0000, 0x0000, 0x0F00, 0x1980, 0x1980, 0x1800, 0x1800, 0x7E00, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, // f
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3E80, 0x6380, 0x6300, 0x6300, 0x6300, 0x3E00, 0x6000, 0x3F00, 0x6180, 0x6180, 0x6180, 0x3F00, // g
+    0x0000, 0x0000, 0x0000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // h
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x3C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // i
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0180, 0x0180, 0x0000, 0x0780, 0x0180, 0x0180, 0x0180, 0x0180, 0x0180, 0x0180, 0x0180, 0x3180, 0x3180, 0x3180, 0x1F00, // j
+    0x0000, 0x0000, 0x0000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6300, 0x6600, 0x6C00, 0x7800, 0x7C00, 0x6600, 0x6300, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // k
+    0x0000, 0x0000, 0x0000, 0x3C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // l
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5B00, 0x7F80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x0000, 0x0000, 0x0000, 0x0000, // m
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // n
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // o
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x7300, 0x6E00, 0x6000, 0x6000, 0x6000, 0x6000, // p
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1D80, 0x3380, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0180, 0x0180, 0x0180, 0x0180, // q
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6F00, 0x3980, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, // r
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3F00, 0x6180, 0x6000, 0x3F00, 0x0180, 0x0180, 0x6180, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // s
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x1800, 0x1800, 0x7E00, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1980, 0x0F00, 0x0000, 0x0000, 0x0000, 0x0000, // t
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0000, 0x0000, 0x0000, 0x0000, // u
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // v
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6D80, 0x6D80, 0x6D80, 0x7F80, 0x3300, 0x0000, 0x0000, 0x0000, 0x0000, // w
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x3300, 0x1E00, 0x0C00, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // x
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0180, 0x6180, 0x3300, 0x1E00, // y
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3F80, 0x0180, 0x0300, 0x0600, 0x0C00, 0x1800, 0x3000, 0x3F80, 0x0000, 0x0000, 0x0000, 0x0000, // z
+    0x0000, 0x0000, 0x0000, 0x0780, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7800, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0780, 0x0000, 0x0000, 0x0000, 0x0000, // {
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // |
+    0x0000, 0x0000, 0x0000, 0x7800, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0780, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, // }
+    0x0000, 0x0000, 0x0000, 0x3980, 0x6D80, 0x6700, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ~
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // no-break space
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ¡
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x1E00, 0x3300, 0x6100, 0x6000, 0x6000, 0x6100, 0x3300, 0x1E00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ¢
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0F00, 0x1980, 0x1980, 0x1800, 0x1800, 0x7E00, 0x1800, 0x1800, 0x1800, 0x7C00, 0x56C0, 0x7380, 0x0000, 0x0000, 0x0000, 0x0000, // £
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8080, 0xDD80, 0x7F00, 0x6300, 0x6300, 0x6300, 0x7F00, 0xDD80, 0x8080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¤
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4080, 0x6180, 0x3300, 0x1E00, 0x3F00, 0x0C00, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ¥
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ¦
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6300, 0x3000, 0x3C00, 0x6600, 0x3300, 0x1980, 0x0F00, 0x0300, 0x3180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // §
+    0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¨
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x5E80, 0x5280, 0x5080, 0x5280, 0x5E80, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ©
+    0x0000, 0x0000, 0x0000, 0x1F00, 0x2180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x3E80, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ª
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0480, 0x0D80, 0x1B00, 0x3600, 0x6C00, 0xD800, 0x6C00, 0x3600, 0x1B00, 0x0D80, 0x0480, 0x0000, 0x0000, 0x0000, 0x0000, // «
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x7F80, 0x0180, 0x0180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¬
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // soft hyphen
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x5E80, 0x5280, 0x5E80, 0x5480, 0x5680, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ®
+    0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¯
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x3300, 0x1E00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // °
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x7F80, 0x0C00, 0x0C00, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ±
+    0x0000, 0x0000, 0x0000, 0x1C00, 0x3600, 0x0600, 0x0C00, 0x1800, 0x3000, 0x3E00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ²
+    0x0000, 0x0000, 0x0000, 0x1C00, 0x3600, 0x0600, 0x0C00, 0x0600, 0x3600, 0x1C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ³
+    0x0000, 0x0000, 0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ´
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x7700, 0x7D00, 0x6000, 0x6000, 0x6000, 0x0000, // µ
+    0x0000, 0x0000, 0x0000, 0x3F80, 0x7F80, 0x7D80, 0x7D80, 0x7D80, 0x3D80, 0x0D80, 0x0D80, 0x0D80, 0x0D80, 0x0D80, 0x0D80, 0x0D80, 0x0000, 0x0000, 0x0000, 0x0000, // ¶
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0E00, 0x0E00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ·
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0600, 0x3600, 0x1C00, // ¸
+    0x0000, 0x0000, 0x0000, 0x1800, 0x3800, 0x1800, 0x1800, 0x1800, 0x1800, 0x3C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¹
+    0x0000, 0x0000, 0x0000, 0x1C00, 0x3600, 0x6300, 0x6300, 0x6300, 0x3600, 0x1C00, 0x0000, 0x7F00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // º
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0x6C00, 0x3600, 0x1B00, 0x0D80, 0x06C0, 0x0D80, 0x1B00, 0x3600, 0x6C00, 0x4800, 0x0000, 0x0000, 0x0000, 0x0000, // »
+    0x0000, 0x0000, 0x0000, 0x2000, 0x6000, 0x2080, 0x2100, 0x7200, 0x0400, 0x0900, 0x1300, 0x2500, 0x4F00, 0x0100, 0x0100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¼
+    0x0000, 0x0000, 0x0000, 0x2000, 0x6000, 0x2080, 0x2100, 0x7200, 0x0400, 0x0B00, 0x1480, 0x2080, 0x4100, 0x0200, 0x0780, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ½
+    0x0000, 0x0000, 0x0000, 0x7000, 0x0800, 0x3080, 0x0900, 0x7200, 0x0400, 0x0900, 0x1300, 0x2500, 0x4F80, 0x0100, 0x0100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¾
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x1800, 0x3000, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ¿
+    0x0000, 0x3000, 0x1800, 0x0C00, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // À
+    0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Á
+    0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Â
+    0x0000, 0x1900, 0x3F00, 0x2600, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Ã
+    0x0000, 0x3300, 0x3300, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Ä
+    0x1E00, 0x3300, 0x3300, 0x1E00, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Å
+    0x0000, 0x0000, 0x0000, 0x0F80, 0x1E00, 0x3600, 0x3600, 0x6600, 0x6600, 0x7F80, 0x6600, 0x6600, 0x6600, 0x6600, 0x6600, 0x6780, 0x0000, 0x0000, 0x0000, 0x0000, // Æ
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6180, 0x3300, 0x1E00, 0x0C00, 0x0600, 0x3600, 0x1C00, // Ç
+    0x0000, 0x3000, 0x1800, 0x0C00, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // È
+    0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // É
+    0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ê
+    0x0000, 0x3300, 0x3300, 0x0000, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ë
+    0x0000, 0x1800, 0x0C00, 0x0600, 0x0000, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // Ì
+    0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // Í
+    0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // Î
+    0x0000, 0x3300, 0x3300, 0x0000, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // Ï
+    0x0000, 0x0000, 0x0000, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6180, 0xF980, 0x6180, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ð
+    0x0000, 0x1900, 0x3F00, 0x2600, 0x0000, 0x6180, 0x7180, 0x7980, 0x7980, 0x6D80, 0x6D80, 0x6780, 0x6780, 0x6380, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Ñ
+    0x0000, 0x1800, 0x0C00, 0x0600, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ò
+    0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ó
+    0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ô
+    0x0000, 0x1900, 0x3F00, 0x2600, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Õ
+    0x0000, 0x3300, 0x3300, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ö
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4100, 0x6300, 0x3600, 0x1C00, 0x1C00, 0x3600, 0x6300, 0x4100, 0x0000, 0x0000, 0x0000, 0x0000, // ×
+    0x0000, 0x0000, 0x0080, 0x1F00, 0x3300, 0x6380, 0x6380, 0x6580, 0x6580, 0x6580, 0x6980, 0x6980, 0x6980, 0x7180, 0x3300, 0x3E00, 0x4000, 0x0000, 0x0000, 0x0000, // Ø
+    0x0000, 0x1800, 0x0C00, 0x0600, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ù
+    0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ú
+    0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Û
+    0x0000, 0x3300, 0x3300, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ü
+    0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Ý
+    0x0000, 0x0000, 0x0000, 0x3000, 0x3000, 0x3000, 0x3F00, 0x3180, 0x3180, 0x3180, 0x3180, 0x3180, 0x3F00, 0x3000, 0x3000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, // Þ
+    0x0000, 0x0000, 0x0000, 0x0E00, 0x1B00, 0x3180, 0x3180, 0x3300, 0x7600, 0x3600, 0x3300, 0x3180, 0x3180, 0x3180, 0x3300, 0x3600, 0x0000, 0x0000, 0x0000, 0x0000, // ß
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x0C00, 0x0600, 0x0000, 0x3F00, 0x6180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // à
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x3F00, 0x6180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // á
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x3F00, 0x6180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // â
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1900, 0x3F00, 0x2600, 0x0000, 0x3F00, 0x6180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // ã
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x3F00, 0x6180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // ä
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x3300, 0x1E00, 0x0000, 0x3F00, 0x6180, 0x0180, 0x3F80, 0x6180, 0x6180, 0x6180, 0x3E80, 0x0000, 0x0000, 0x0000, 0x0000, // å
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3B00, 0x4D80, 0x0D80, 0x0F00, 0x3C00, 0x6C00, 0x6C80, 0x3700, 0x0000, 0x0000, 0x0000, 0x0000, // æ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F00, 0x3180, 0x6000, 0x6000, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0C00, 0x0600, 0x3600, 0x1C00, // ç
+    0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0x1800, 0x0C00, 0x0000, 0x1E00, 0x3300, 0x6180, 0x7F80, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // è
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x1E00, 0x3300, 0x6180, 0x7F80, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // é
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x1E00, 0x3300, 0x6180, 0x7F80, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // ê
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x1E00, 0x3300, 0x6180, 0x7F80, 0x6000, 0x6000, 0x3180, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // ë
+    0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0x1800, 0x0C00, 0x0000, 0x3C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // ì
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x3C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // í
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x3C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // î
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x3C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // ï
+    0x0000, 0x0000, 0x0000, 0x4400, 0x6C00, 0x3800, 0x3800, 0x6C00, 0x4600, 0x1F00, 0x3380, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ð
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1900, 0x3F00, 0x2600, 0x0000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // ñ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0x1800, 0x0C00, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ò
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ó
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ô
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1900, 0x3F00, 0x2600, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // õ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ö
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x7F80, 0x7F80, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ÷
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0080, 0x1F00, 0x3300, 0x6580, 0x6580, 0x6980, 0x6980, 0x3300, 0x3E00, 0x4000, 0x0000, 0x0000, 0x0000, // ø
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x0C00, 0x0600, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0000, 0x0000, 0x0000, 0x0000, // ù
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0000, 0x0000, 0x0000, 0x0000, // ú
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0000, 0x0000, 0x0000, 0x0000, // û
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0000, 0x0000, 0x0000, 0x0000, // ü
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0180, 0x6180, 0x3300, 0x1E00, // ý
+    0x0000, 0x0000, 0x0000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3E00, 0x3300, 0x3180, 0x3180, 0x3180, 0x3300, 0x3E00, 0x3000, 0x3000, 0x3000, 0x3000, // þ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3300, 0x3300, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3380, 0x1D80, 0x0180, 0x6180, 0x3300, 0x1E00, // ÿ
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Α
+    0x0000, 0x0000, 0x0000, 0x7C00, 0x6600, 0x6300, 0x6300, 0x6300, 0x6600, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x0000, 0x0000, 0x0000, 0x0000, // Β
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, // Γ
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x1E00, 0x1E00, 0x1E00, 0x3300, 0x3300, 0x3300, 0x6180, 0x6180, 0x6180, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Δ
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ε
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0180, 0x0180, 0x0300, 0x0600, 0x0600, 0x0C00, 0x1800, 0x1800, 0x3000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ζ
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Η
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Θ
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ι
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6300, 0x6300, 0x6600, 0x6600, 0x7C00, 0x6600, 0x6600, 0x6300, 0x6300, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Κ
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x1E00, 0x1E00, 0x1E00, 0x3300, 0x3300, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Λ
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x7380, 0x7380, 0x7F80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Μ
+    0x0000, 0x0000, 0x0000, 0x6180, 0x7180, 0x7180, 0x7980, 0x7980, 0x6D80, 0x6D80, 0x6780, 0x6780, 0x6380, 0x6380, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Ν
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ξ
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // Ο
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x0000, 0x0000, 0x0000, 0x0000, // Π
+    0x0000, 0x0000, 0x0000, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6300, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, // Ρ
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6000, 0x3000, 0x1800, 0x0C00, 0x0600, 0x0600, 0x0C00, 0x1800, 0x3000, 0x6000, 0x6000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Σ
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Τ
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Υ
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x3F00, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x3F00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Φ
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x1E00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // Χ
+    0x0000, 0x0000, 0x0000, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Ψ
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1200, 0x7380, 0x0000, 0x0000, 0x0000, 0x0000, // Ω
+    0x0000, 0x6180, 0x6180, 0x0000, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // Ϊ
+    0x0000, 0x6180, 0x6180, 0x0000, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // Ϋ
+    0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x0000, 0x3D80, 0x6380, 0x6180, 0x6180, 0x6180, 0x6380, 0x6580, 0x3980, 0x0000, 0x0000, 0x0000, 0x0000, // ά
+    0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x0000, 0x3F00, 0x6180, 0x6000, 0x3E00, 0x6000, 0x6000, 0x6180, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // έ
+    0x0000, 0x0000, 0x0000, 0x0300, 0x0600, 0x0C00, 0x0000, 0x0000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0180, 0x0180, 0x0180, 0x0180, // ή
+    0x0000, 0x0000, 0x0000, 0x0600, 0x0C00, 0x1800, 0x0000, 0x0000, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1B00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, // ί
+    0x0000, 0x0000, 0x0000, 0x0600, 0x6D80, 0x7980, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ΰ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3D80, 0x6380, 0x6180, 0x6180, 0x6180, 0x6380, 0x6580, 0x3980, 0x0000, 0x0000, 0x0000, 0x0000, // α
+    0x0000, 0x0000, 0x0000, 0x1C00, 0x3600, 0x6300, 0x6300, 0x6600, 0x7E00, 0x6300, 0x6180, 0x6180, 0x6180, 0x6180, 0x7B00, 0x6E00, 0x6000, 0x6000, 0x6000, 0x6000, // β
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, // γ
+    0x0000, 0x0000, 0x0000, 0x3E00, 0x6300, 0x3000, 0x1800, 0x0C00, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // δ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3F00, 0x6180, 0x6000, 0x3E00, 0x6000, 0x6000, 0x6180, 0x3F00, 0x0000, 0x0000, 0x0000, 0x0000, // ε
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x1800, 0x3000, 0x3000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x3000, 0x1F00, 0x0180, 0x0180, 0x0F00, 0x0000, // ζ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6E00, 0x7300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0180, 0x0180, 0x0180, 0x0180, // η
+    0x0000, 0x0000, 0x0000, 0x1C00, 0x3600, 0x6300, 0x6300, 0x6300, 0x6300, 0x7F00, 0x6300, 0x6300, 0x6300, 0x6300, 0x3600, 0x1C00, 0x0000, 0x0000, 0x0000, 0x0000, // θ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1B00, 0x0E00, 0x0000, 0x0000, 0x0000, 0x0000, // ι
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6300, 0x6600, 0x7C00, 0x7C00, 0x6600, 0x6300, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // κ
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x0180, 0x0180, 0x1D80, 0x3380, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // λ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x7380, 0x7D80, 0x6000, 0x6000, 0x6000, 0x6000, // μ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ν
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x1800, 0x3000, 0x3000, 0x1800, 0x0F00, 0x1800, 0x3000, 0x6000, 0x6000, 0x6000, 0x3F00, 0x0180, 0x0180, 0x0F00, 0x0000, // ξ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ο
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x3300, 0x0000, 0x0000, 0x0000, 0x0000, // π
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x7300, 0x7E00, 0x6000, 0x6000, 0x6000, 0x6000, // ρ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F00, 0x3180, 0x6000, 0x6000, 0x6000, 0x6000, 0x3000, 0x1F00, 0x0180, 0x0180, 0x0F00, 0x0000, // ς
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F80, 0x3600, 0x6300, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // σ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0D80, 0x0700, 0x0000, 0x0000, 0x0000, 0x0000, // τ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // υ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3700, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, // φ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x0C00, 0x0C00, 0x1E00, 0x3300, 0x3300, 0x6180, 0x6180, // χ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, // ψ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x7F80, 0x3300, 0x0000, 0x0000, 0x0000, 0x0000, // ω
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // −
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0C00, 0x0C00, 0x7F80, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ∓
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6300, 0x9480, 0x8880, 0x9480, 0x6300, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ∞
+    0x0000, 0x0000, 0x07C0, 0x0600, 0x0600, 0x0C00, 0x0C00, 0xCC00, 0xD800, 0xD800, 0xD800, 0xF000, 0x7000, 0x7000, 0x6000, 0x6000, 0x0000, 0x0000, 0x0000, 0x0000, // √
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0380, 0x0E00, 0x3800, 0xE000, 0x3800, 0x0E00, 0x0380, 0x0000, 0x0000, 0xFF80, 0x0000, 0x0000, 0x0000, 0x0000, // ≤
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xE000, 0x3800, 0x0E00, 0x0380, 0x0E00, 0x3800, 0xE000, 0x0000, 0x0000, 0xFF80, 0x0000, 0x0000, 0x0000, 0x0000, // ≥
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3980, 0x6D80, 0x6700, 0x0000, 0x3980, 0x6D80, 0x6700, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ≈
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0300, 0x0300, 0x0600, 0x7F80, 0x0C00, 0x0C00, 0x7F80, 0x1800, 0x3000, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, // ≠
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ≡
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3180, 0x4A00, 0x4400, 0x4A00, 0x3180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ∝
+    0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x0180, 0x0180, 0x0180, 0x0F80, 0x1980, 0x3180, 0x6180, 0x6300, 0x6300, 0x6600, 0x3C00, 0x0000, 0x0000, 0x0000, 0x0000, // ∂
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x6180, 0x6180, 0x6180, 0x3300, 0x3300, 0x3300, 0x1E00, 0x1E00, 0x1E00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ∇
+    0x0000, 0x0000, 0x7F80, 0x3080, 0x3080, 0x1800, 0x1800, 0x0C00, 0x0C00, 0x0600, 0x0600, 0x0C00, 0x0C00, 0x1800, 0x1800, 0x3080, 0x3080, 0x7F80, 0x0000, 0x0000, // ∑
+    0x0000, 0x0000, 0xFF80, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0x6300, 0xF380, 0x0000, 0x0000, // ∏
+    0x0000, 0x0700, 0x0D80, 0x0D80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x6C00, 0x6C00, 0x3800, 0x0000, // ∫
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F80, 0x3000, 0x6000, 0x6000, 0x7F80, 0x6000, 0x6000, 0x3000, 0x1F80, 0x0000, 0x0000, 0x0000, 0x0000, // ∈
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0300, 0x1F80, 0x3600, 0x6600, 0x6600, 0x7F80, 0x6C00, 0x6C00, 0x3800, 0x1F80, 0x1800, 0x0000, 0x0000, 0x0000, // ∉
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F80, 0x3000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x3000, 0x1F80, 0x0000, 0x0000, 0x0000, 0x0000, // ⊂
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x3300, 0x1E00, 0x0000, 0x0000, 0x0000, 0x0000, // ∪
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1E00, 0x3300, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x0000, 0x0000, 0x0000, 0x0000, // ∩
+    0x0000, 0x0000, 0x0000, 0x6180, 0x6180, 0x6180, 0x6180, 0x6180, 0x7F80, 0x6180, 0x6180, 0x6180, 0x3300, 0x3300, 0x1E00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ∀
+    0x0000, 0x0000, 0x0000, 0x7F80, 0x0180, 0x0180, 0x0180, 0x0180, 0x0180, 0x7F80, 0x0180, 0x0180, 0x0180, 0x0180, 0x0180, 0x7F80, 0x0000, 0x0000, 0x0000, 0x0000, // ∃
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x0600, 0x0300, 0xFF80, 0xFF80, 0x0300, 0x0600, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // →
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x3000, 0x6000, 0xFF80, 0xFF80, 0x6000, 0x3000, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ←
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x1E00, 0x3F00, 0x6D80, 0x4C80, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ↑
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x4C80, 0x6D80, 0x3F00, 0x1E00, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, // ↓
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1200, 0x3300, 0x6180, 0xFFC0, 0xFFC0, 0x6180, 0x3300, 0x1200, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ↔
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1800, 0x0C00, 0xFE00, 0xFF00, 0x0180, 0xFF00, 0xFE00, 0x0C00, 0x1800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ⇒
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1200, 0x3300, 0x7F80, 0xFFC0, 0x80C0, 0xFFC0, 0x7F80, 0x3300, 0x1200, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ⇔
+    0x0000, 0x0000, 0x0000, 0x0600, 0x0C00, 0x1800, 0x3000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ′
+    0x0000, 0x0000, 0x0000, 0x0CC0, 0x1980, 0x3300, 0x6600, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ″
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1180, 0x2900, 0x2B00, 0x1200, 0x0600, 0x0400, 0x0C00, 0x0800, 0x1A80, 0x1540, 0x3540, 0x2280, 0x0000, 0x0000, 0x0000, 0x0000, // ‰
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6D80, 0x6D80, 0x0000, 0x0000, 0x0000, 0x0000, // …
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1C00, 0x1C00, 0x1C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ⋅
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0C00, 0x1200, 0x1200, 0x0C00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ∘
+    0x0000, 0x0000, 0x0000, 0x3C00, 0x6600, 0x6300, 0x6300, 0x3300, 0x1F80, 0xC300, 0x6300, 0x6300, 0x6300, 0x6300, 0x3600, 0x1C00, 0x0000, 0x0000, 0x0000, 0x0000, // ϑ
+    0x0000, 0x0000, 0x0000, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, 0x3F00, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x6D80, 0x3F00, 0x0C00, 0x0C00, 0x0C00, 0x0C00, // ϕ
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1F00, 0x3000, 0x6000, 0x7E00, 0x6000, 0x6000, 0x3000, 0x1F00, 0x0000, 0x0000, 0x0000, 0x0000, // ϵ
+];
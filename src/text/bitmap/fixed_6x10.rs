@@ -0,0 +1,299 @@
+//! 6x10 pixel glyphs from the public domain X11 "fixed" terminal font
+
+use super::BitmapFont;
+
+pub static FIXED_6X10: BitmapFont = BitmapFont {
+    width: 6,
+    height: 10,
+    ascent: 8,
+    glyphs: &GLYPHS,
+};
+
+/// One row of pixels per entry, with the leftmost pixel in the highest bit
+static GLYPHS: [u16; 2850] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // space
+    0x0000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x2000, 0x0000, 0x0000, // !
+    0x0000, 0x5000, 0x5000, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // "
+    0x0000, 0x5000, 0x5000, 0xF800, 0x5000, 0xF800, 0x5000, 0x5000, 0x0000, 0x0000, // #
+    0x0000, 0x2000, 0x7000, 0xA000, 0x7000, 0x2800, 0x7000, 0x2000, 0x0000, 0x0000, // $
+    0x0000, 0x4800, 0xA800, 0x5000, 0x2000, 0x5000, 0xA800, 0x9000, 0x0000, 0x0000, // %
+    0x0000, 0x4000, 0xA000, 0xA000, 0x4000, 0xA800, 0x9000, 0x6800, 0x0000, 0x0000, // &
+    0x0000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // '
+    0x0000, 0x1000, 0x2000, 0x4000, 0x4000, 0x4000, 0x2000, 0x1000, 0x0000, 0x0000, // (
+    0x0000, 0x4000, 0x2000, 0x1000, 0x1000, 0x1000, 0x2000, 0x4000, 0x0000, 0x0000, // )
+    0x0000, 0x0000, 0x8800, 0x5000, 0xF800, 0x5000, 0x8800, 0x0000, 0x0000, 0x0000, // *
+    0x0000, 0x0000, 0x2000, 0x2000, 0xF800, 0x2000, 0x2000, 0x0000, 0x0000, 0x0000, // +
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3000, 0x2000, 0x4000, 0x0000, // ,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xF800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // -
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0x7000, 0x2000, 0x0000, // .
+    0x0000, 0x0800, 0x0800, 0x1000, 0x2000, 0x4000, 0x8000, 0x8000, 0x0000, 0x0000, // /
+    0x0000, 0x2000, 0x5000, 0x8800, 0x8800, 0x8800, 0x5000, 0x2000, 0x0000, 0x0000, // 0
+    0x0000, 0x2000, 0x6000, 0xA000, 0x2000, 0x2000, 0x2000, 0xF800, 0x0000, 0x0000, // 1
+    0x0000, 0x7000, 0x8800, 0x0800, 0x3000, 0x4000, 0x8000, 0xF800, 0x0000, 0x0000, // 2
+    0x0000, 0xF800, 0x0800, 0x1000, 0x3000, 0x0800, 0x8800, 0x7000, 0x0000, 0x0000, // 3
+    0x0000, 0x1000, 0x3000, 0x5000, 0x9000, 0xF800, 0x1000, 0x1000, 0x0000, 0x0000, // 4
+    0x0000, 0xF800, 0x8000, 0xB000, 0xC800, 0x0800, 0x8800, 0x7000, 0x0000, 0x0000, // 5
+    0x0000, 0x3000, 0x4000, 0x8000, 0xB000, 0xC800, 0x8800, 0x7000, 0x0000, 0x0000, // 6
+    0x0000, 0xF800, 0x0800, 0x1000, 0x1000, 0x2000, 0x4000, 0x4000, 0x0000, 0x0000, // 7
+    0x0000, 0x7000, 0x8800, 0x8800, 0x7000, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // 8
+    0x0000, 0x7000, 0x8800, 0x9800, 0x6800, 0x0800, 0x1000, 0x6000, 0x0000, 0x0000, // 9
+    0x0000, 0x0000, 0x2000, 0x7000, 0x2000, 0x0000, 0x2000, 0x7000, 0x2000, 0x0000, // :
+    0x0000, 0x0000, 0x2000, 0x7000, 0x2000, 0x0000, 0x3000, 0x2000, 0x4000, 0x0000, // ;
+    0x0000, 0x0800, 0x1000, 0x2000, 0x4000, 0x2000, 0x1000, 0x0800, 0x0000, 0x0000, // <
+    0x0000, 0x0000, 0x0000, 0xF800, 0x0000, 0xF800, 0x0000, 0x0000, 0x0000, 0x0000, // =
+    0x0000, 0x4000, 0x2000, 0x1000, 0x0800, 0x1000, 0x2000, 0x4000, 0x0000, 0x0000, // >
+    0x0000, 0x7000, 0x8800, 0x1000, 0x2000, 0x2000, 0x0000, 0x2000, 0x0000, 0x0000, // ?
+    0x0000, 0x7000, 0x8800, 0x9800, 0xA800, 0xB000, 0x8000, 0x7000, 0x0000, 0x0000, // @
+    0x0000, 0x2000, 0x5000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // A
+    0x0000, 0xF000, 0x4800, 0x4800, 0x7000, 0x4800, 0x4800, 0xF000, 0x0000, 0x0000, // B
+    0x0000, 0x7000, 0x8800, 0x8000, 0x8000, 0x8000, 0x8800, 0x7000, 0x0000, 0x0000, // C
+    0x0000, 0xF000, 0x4800, 0x4800, 0x4800, 0x4800, 0x4800, 0xF000, 0x0000, 0x0000, // D
+    0x0000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // E
+    0x0000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0x8000, 0x0000, 0x0000, // F
+    0x0000, 0x7000, 0x8800, 0x8000, 0x8000, 0x9800, 0x8800, 0x7000, 0x0000, 0x0000, // G
+    0x0000, 0x8800, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // H
+    0x0000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // I
+    0x0000, 0x3800, 0x1000, 0x1000, 0x1000, 0x1000, 0x9000, 0x6000, 0x0000, 0x0000, // J
+    0x0000, 0x8800, 0x9000, 0xA000, 0xC000, 0xA000, 0x9000, 0x8800, 0x0000, 0x0000, // K
+    0x0000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // L
+    0x0000, 0x8800, 0x8800, 0xD800, 0xA800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // M
+    0x0000, 0x8800, 0x8800, 0xC800, 0xA800, 0x9800, 0x8800, 0x8800, 0x0000, 0x0000, // N
+    0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // O
+    0x0000, 0xF000, 0x8800, 0x8800, 0xF000, 0x8000, 0x8000, 0x8000, 0x0000, 0x0000, // P
+    0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0xA800, 0x7000, 0x0800, 0x0000, // Q
+    0x0000, 0xF000, 0x8800, 0x8800, 0xF000, 0xA000, 0x9000, 0x8800, 0x0000, 0x0000, // R
+    0x0000, 0x7000, 0x8800, 0x8000, 0x7000, 0x0800, 0x8800, 0x7000, 0x0000, 0x0000, // S
+    0x0000, 0xF800, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // T
+    0x0000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // U
+    0x0000, 0x8800, 0x8800, 0x8800, 0x5000, 0x5000, 0x5000, 0x2000, 0x0000, 0x0000, // V
+    0x0000, 0x8800, 0x8800, 0x8800, 0xA800, 0xA800, 0xD800, 0x8800, 0x0000, 0x0000, // W
+    0x0000, 0x8800, 0x8800, 0x5000, 0x2000, 0x5000, 0x8800, 0x8800, 0x0000, 0x0000, // X
+    0x0000, 0x8800, 0x8800, 0x5000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // Y
+    0x0000, 0xF800, 0x0800, 0x1000, 0x2000, 0x4000, 0x8000, 0xF800, 0x0000, 0x0000, // Z
+    0x0000, 0x7000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7000, 0x0000, 0x0000, // [
+    0x0000, 0x8000, 0x8000, 0x4000, 0x2000, 0x1000, 0x0800, 0x0800, 0x0000, 0x0000, // \
+    0x0000, 0x7000, 0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x7000, 0x0000, 0x0000, // ]
+    0x0000, 0x2000, 0x5000, 0x8800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ^
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xF800, 0x0000, // _
+    0x2000, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // `
+    0x0000, 0x0000, 0x0000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // a
+    0x0000, 0x8000, 0x8000, 0xB000, 0xC800, 0x8800, 0xC800, 0xB000, 0x0000, 0x0000, // b
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8000, 0x8800, 0x7000, 0x0000, 0x0000, // c
+    0x0000, 0x0800, 0x0800, 0x6800, 0x9800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // d
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0xF800, 0x8000, 0x7000, 0x0000, 0x0000, // e
+    0x0000, 0x3000, 0x4800, 0x4000, 0xF000, 0x4000, 0x4000, 0x4000, 0x0000, 0x0000, // f
+    0x0000, 0x0000, 0x0000, 0x7800, 0x8800, 0x8800, 0x7800, 0x0800, 0x8800, 0x7000, // g
+    0x0000, 0x8000, 0x8000, 0xB000, 0xC800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // h
+    0x0000, 0x2000, 0x0000, 0x6000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // i
+    0x0000, 0x0800, 0x0000, 0x1800, 0x0800, 0x0800, 0x0800, 0x4800, 0x4800, 0x3000, // j
+    0x0000, 0x8000, 0x8000, 0x8800, 0x9000, 0xE000, 0x9000, 0x8800, 0x0000, 0x0000, // k
+    0x0000, 0x6000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // l
+    0x0000, 0x0000, 0x0000, 0xD000, 0xA800, 0xA800, 0xA800, 0x8800, 0x0000, 0x0000, // m
+    0x0000, 0x0000, 0x0000, 0xB000, 0xC800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // n
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // o
+    0x0000, 0x0000, 0x0000, 0xB000, 0xC800, 0x8800, 0xC800, 0xB000, 0x8000, 0x8000, // p
+    0x0000, 0x0000, 0x0000, 0x6800, 0x9800, 0x8800, 0x9800, 0x6800, 0x0800, 0x0800, // q
+    0x0000, 0x0000, 0x0000, 0xB000, 0xC800, 0x8000, 0x8000, 0x8000, 0x0000, 0x0000, // r
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8000, 0x7000, 0x0800, 0xF000, 0x0000, 0x0000, // s
+    0x0000, 0x4000, 0x4000, 0xF000, 0x4000, 0x4000, 0x4800, 0x3000, 0x0000, 0x0000, // t
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // u
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x5000, 0x5000, 0x2000, 0x0000, 0x0000, // v
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0xA800, 0xA800, 0x5000, 0x0000, 0x0000, // w
+    0x0000, 0x0000, 0x0000, 0x8800, 0x5000, 0x2000, 0x5000, 0x8800, 0x0000, 0x0000, // x
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x9800, 0x6800, 0x0800, 0x8800, 0x7000, // y
+    0x0000, 0x0000, 0x0000, 0xF800, 0x1000, 0x2000, 0x4000, 0xF800, 0x0000, 0x0000, // z
+    0x0000, 0x1800, 0x2000, 0x1000, 0x6000, 0x1000, 0x2000, 0x1800, 0x0000, 0x0000, // {
+    0x0000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // |
+    0x0000, 0x6000, 0x1000, 0x2000, 0x1800, 0x2000, 0x1000, 0x6000, 0x0000, 0x0000, // }
+    0x0000, 0x4800, 0xA800, 0x9000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ~
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // no-break space
+    0x0000, 0x2000, 0x0000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // ¡
+    0x0000, 0x0000, 0x2000, 0x7800, 0xA000, 0xA000, 0xA000, 0x7800, 0x2000, 0x0000, // ¢
+    0x0000, 0x3000, 0x4800, 0x4000, 0xE000, 0x4000, 0x4800, 0xB000, 0x0000, 0x0000, // £
+    0x0000, 0x0000, 0x0000, 0x8800, 0x7000, 0x5000, 0x7000, 0x8800, 0x0000, 0x0000, // ¤
+    0x0000, 0x8800, 0x8800, 0x5000, 0x2000, 0xF800, 0x2000, 0x2000, 0x2000, 0x0000, // ¥
+    0x0000, 0x2000, 0x2000, 0x2000, 0x0000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // ¦
+    0x0000, 0x7000, 0x8000, 0xE000, 0x9000, 0x4800, 0x3800, 0x0800, 0x7000, 0x0000, // §
+    0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¨
+    0x0000, 0x7000, 0x8800, 0xA800, 0xC800, 0xA800, 0x8800, 0x7000, 0x0000, 0x0000, // ©
+    0x0000, 0x3800, 0x4800, 0x5800, 0x2800, 0x0000, 0x7800, 0x0000, 0x0000, 0x0000, // ª
+    0x0000, 0x0000, 0x0000, 0x2400, 0x4800, 0x9000, 0x4800, 0x2400, 0x0000, 0x0000, // «
+    0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, // ¬
+    0x0000, 0x0000, 0x0000, 0x0000, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // soft hyphen
+    0x0000, 0x7000, 0x8800, 0xE800, 0xC800, 0xC800, 0x8800, 0x7000, 0x0000, 0x0000, // ®
+    0xF800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¯
+    0x0000, 0x2000, 0x5000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // °
+    0x0000, 0x0000, 0x2000, 0x2000, 0xF800, 0x2000, 0x2000, 0xF800, 0x0000, 0x0000, // ±
+    0x3000, 0x4800, 0x1000, 0x2000, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ²
+    0x7000, 0x0800, 0x3000, 0x0800, 0x7000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ³
+    0x1000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ´
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x8800, 0xC800, 0xB000, 0x8000, 0x0000, // µ
+    0x0000, 0x7800, 0xE800, 0xE800, 0x6800, 0x2800, 0x2800, 0x2800, 0x0000, 0x0000, // ¶
+    0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ·
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1000, 0x2000, // ¸
+    0x2000, 0x6000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ¹
+    0x0000, 0x3000, 0x4800, 0x4800, 0x3000, 0x0000, 0x7800, 0x0000, 0x0000, 0x0000, // º
+    0x0000, 0x0000, 0x0000, 0x9000, 0x4800, 0x2400, 0x4800, 0x9000, 0x0000, 0x0000, // »
+    0x4000, 0xC000, 0x4000, 0x4000, 0xE400, 0x0C00, 0x1400, 0x3C00, 0x0400, 0x0000, // ¼
+    0x4000, 0xC000, 0x4000, 0x4000, 0xE800, 0x1400, 0x0400, 0x0800, 0x1C00, 0x0000, // ½
+    0xC000, 0x2000, 0x4000, 0x2000, 0xC800, 0x1800, 0x2800, 0x7800, 0x0800, 0x0000, // ¾
+    0x0000, 0x2000, 0x0000, 0x2000, 0x2000, 0x4000, 0x8800, 0x7000, 0x0000, 0x0000, // ¿
+    0x4000, 0x2000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // À
+    0x1000, 0x2000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // Á
+    0x2000, 0x5000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // Â
+    0x4800, 0xB000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // Ã
+    0x5000, 0x0000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // Ä
+    0x2000, 0x5000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // Å
+    0x0000, 0x3C00, 0x5000, 0x9000, 0x9C00, 0xF000, 0x9000, 0x9C00, 0x0000, 0x0000, // Æ
+    0x0000, 0x7000, 0x8800, 0x8000, 0x8000, 0x8000, 0x8800, 0x7000, 0x2000, 0x4000, // Ç
+    0x4000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // È
+    0x1000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // É
+    0x2000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // Ê
+    0x5000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // Ë
+    0x4000, 0x2000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // Ì
+    0x1000, 0x2000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // Í
+    0x2000, 0x5000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // Î
+    0x5000, 0x0000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // Ï
+    0x0000, 0xF000, 0x4800, 0x4800, 0xE800, 0x4800, 0x4800, 0xF000, 0x0000, 0x0000, // Ð
+    0x2800, 0x5000, 0x8800, 0xC800, 0xA800, 0x9800, 0x8800, 0x8800, 0x0000, 0x0000, // Ñ
+    0x4000, 0x2000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ò
+    0x1000, 0x2000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ó
+    0x2000, 0x5000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ô
+    0x2800, 0x5000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Õ
+    0x5000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ö
+    0x0000, 0x0000, 0x0000, 0x8800, 0x5000, 0x2000, 0x5000, 0x8800, 0x0000, 0x0000, // ×
+    0x0000, 0x7000, 0x9800, 0x9800, 0xA800, 0xC800, 0xC800, 0x7000, 0x0000, 0x0000, // Ø
+    0x4000, 0x2000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ù
+    0x1000, 0x2000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ú
+    0x2000, 0x5000, 0x0000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Û
+    0x5000, 0x0000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ü
+    0x1000, 0x2000, 0x8800, 0x8800, 0x5000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // Ý
+    0x0000, 0x8000, 0xF000, 0x8800, 0xF000, 0x8000, 0x8000, 0x8000, 0x0000, 0x0000, // Þ
+    0x0000, 0x7000, 0x8800, 0x9000, 0xA000, 0x9000, 0x8800, 0xB000, 0x0000, 0x0000, // ß
+    0x4000, 0x2000, 0x0000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // à
+    0x1000, 0x2000, 0x0000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // á
+    0x2000, 0x5000, 0x0000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // â
+    0x2800, 0x5000, 0x0000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // ã
+    0x0000, 0x5000, 0x0000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // ä
+    0x2000, 0x5000, 0x2000, 0x7000, 0x0800, 0x7800, 0x8800, 0x7800, 0x0000, 0x0000, // å
+    0x0000, 0x0000, 0x0000, 0x7800, 0x1400, 0x7C00, 0x9000, 0x7C00, 0x0000, 0x0000, // æ
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8000, 0x8800, 0x7000, 0x2000, 0x4000, // ç
+    0x4000, 0x2000, 0x0000, 0x7000, 0x8800, 0xF800, 0x8000, 0x7000, 0x0000, 0x0000, // è
+    0x1000, 0x2000, 0x0000, 0x7000, 0x8800, 0xF800, 0x8000, 0x7000, 0x0000, 0x0000, // é
+    0x2000, 0x5000, 0x0000, 0x7000, 0x8800, 0xF800, 0x8000, 0x7000, 0x0000, 0x0000, // ê
+    0x0000, 0x5000, 0x0000, 0x7000, 0x8800, 0xF800, 0x8000, 0x7000, 0x0000, 0x0000, // ë
+    0x4000, 0x2000, 0x0000, 0x6000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // ì
+    0x2000, 0x4000, 0x0000, 0x6000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // í
+    0x2000, 0x5000, 0x0000, 0x6000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // î
+    0x0000, 0x5000, 0x0000, 0x6000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // ï
+    0x0000, 0xC000, 0x3000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ð
+    0x2800, 0x5000, 0x0000, 0xB000, 0xC800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // ñ
+    0x4000, 0x2000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ò
+    0x1000, 0x2000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ó
+    0x2000, 0x5000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ô
+    0x2800, 0x5000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // õ
+    0x0000, 0x5000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ö
+    0x0000, 0x0000, 0x2000, 0x0000, 0xF800, 0x0000, 0x2000, 0x0000, 0x0000, 0x0000, // ÷
+    0x0000, 0x0000, 0x0000, 0x7800, 0x9800, 0xA800, 0xC800, 0xF000, 0x0000, 0x0000, // ø
+    0x4000, 0x2000, 0x0000, 0x8800, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // ù
+    0x1000, 0x2000, 0x0000, 0x8800, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // ú
+    0x2000, 0x5000, 0x0000, 0x8800, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // û
+    0x0000, 0x5000, 0x0000, 0x8800, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // ü
+    0x0000, 0x1000, 0x2000, 0x8800, 0x8800, 0x9800, 0x6800, 0x0800, 0x8800, 0x7000, // ý
+    0x0000, 0x0000, 0x8000, 0xF000, 0x8800, 0x8800, 0x8800, 0xF000, 0x8000, 0x8000, // þ
+    0x0000, 0x5000, 0x0000, 0x8800, 0x8800, 0x9800, 0x6800, 0x0800, 0x8800, 0x7000, // ÿ
+    0x0000, 0x2000, 0x5000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x0000, 0x0000, // Α
+    0x0000, 0xF000, 0x8800, 0x8800, 0xF000, 0x8800, 0x8800, 0xF000, 0x0000, 0x0000, // Β
+    0x0000, 0xF800, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x8000, 0x0000, 0x0000, // Γ
+    0x0000, 0x2000, 0x5000, 0x5000, 0x5000, 0x8800, 0x8800, 0xF800, 0x0000, 0x0000, // Δ
+    0x0000, 0xF800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0xF800, 0x0000, 0x0000, // Ε
+    0x0000, 0xF800, 0x0800, 0x1000, 0x2000, 0x4000, 0x8000, 0xF800, 0x0000, 0x0000, // Ζ
+    0x0000, 0x8800, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // Η
+    0x0000, 0x7000, 0x8800, 0x8800, 0xF800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Θ
+    0x0000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // Ι
+    0x0000, 0x8800, 0x9000, 0xA000, 0xC000, 0xA000, 0x9000, 0x8800, 0x0000, 0x0000, // Κ
+    0x0000, 0x2000, 0x5000, 0x5000, 0x5000, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // Λ
+    0x0000, 0x8800, 0x8800, 0xD800, 0xA800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // Μ
+    0x0000, 0x8800, 0x8800, 0xC800, 0xA800, 0x9800, 0x8800, 0x8800, 0x0000, 0x0000, // Ν
+    0x0000, 0xF800, 0x0000, 0x0000, 0x7000, 0x0000, 0x0000, 0xF800, 0x0000, 0x0000, // Ξ
+    0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // Ο
+    0x0000, 0xF800, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // Π
+    0x0000, 0xF000, 0x8800, 0x8800, 0xF000, 0x8000, 0x8000, 0x8000, 0x0000, 0x0000, // Ρ
+    0x0000, 0xF800, 0x8000, 0x4000, 0x2000, 0x4000, 0x8000, 0xF800, 0x0000, 0x0000, // Σ
+    0x0000, 0xF800, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // Τ
+    0x0000, 0x8800, 0x8800, 0x5000, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // Υ
+    0x0000, 0x2000, 0x7000, 0xA800, 0xA800, 0xA800, 0x7000, 0x2000, 0x0000, 0x0000, // Φ
+    0x0000, 0x8800, 0x8800, 0x5000, 0x2000, 0x5000, 0x8800, 0x8800, 0x0000, 0x0000, // Χ
+    0x0000, 0xA800, 0xA800, 0xA800, 0xA800, 0x7000, 0x2000, 0x2000, 0x0000, 0x0000, // Ψ
+    0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x5000, 0xD800, 0x0000, 0x0000, // Ω
+    0x5000, 0x0000, 0x7000, 0x2000, 0x2000, 0x2000, 0x2000, 0x7000, 0x0000, 0x0000, // Ϊ
+    0x8800, 0x0000, 0x8800, 0x8800, 0x5000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // Ϋ
+    0x1000, 0x2000, 0x0000, 0x7000, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // ά
+    0x1000, 0x2000, 0x0000, 0x7000, 0x8800, 0x6000, 0x8800, 0x7000, 0x0000, 0x0000, // έ
+    0x1000, 0x2000, 0x0000, 0xB000, 0xC800, 0x8800, 0x8800, 0x8800, 0x0800, 0x0800, // ή
+    0x2000, 0x4000, 0x0000, 0x4000, 0x4000, 0x4000, 0x5000, 0x2000, 0x0000, 0x0000, // ί
+    0x1000, 0xA800, 0x0000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ΰ
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8800, 0x9800, 0x6800, 0x0000, 0x0000, // α
+    0x0000, 0x6000, 0x9000, 0x9000, 0xF000, 0x8800, 0x8800, 0xF000, 0x8000, 0x8000, // β
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x5000, 0x5000, 0x2000, 0x2000, 0x2000, // γ
+    0x0000, 0x7000, 0x8800, 0x6000, 0x9000, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // δ
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x6000, 0x8800, 0x7000, 0x0000, 0x0000, // ε
+    0x0000, 0xF800, 0x2000, 0x4000, 0x4000, 0x8000, 0x8000, 0x7000, 0x0800, 0x3000, // ζ
+    0x0000, 0x0000, 0x0000, 0xB000, 0xC800, 0x8800, 0x8800, 0x8800, 0x0800, 0x0800, // η
+    0x0000, 0x3000, 0x4800, 0x4800, 0x7800, 0x4800, 0x4800, 0x3000, 0x0000, 0x0000, // θ
+    0x0000, 0x0000, 0x0000, 0x4000, 0x4000, 0x4000, 0x5000, 0x2000, 0x0000, 0x0000, // ι
+    0x0000, 0x0000, 0x0000, 0x8800, 0x9000, 0xE000, 0x9000, 0x8800, 0x0000, 0x0000, // κ
+    0x0000, 0x7000, 0x8800, 0x6800, 0x9800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // λ
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x8800, 0xD800, 0xA800, 0x8000, 0x8000, // μ
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x5000, 0x5000, 0x2000, 0x0000, 0x0000, // ν
+    0x0000, 0xF800, 0x2000, 0x4000, 0x3000, 0x4000, 0x8000, 0x7000, 0x0800, 0x3000, // ξ
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ο
+    0x0000, 0x0000, 0x0000, 0xF800, 0x5000, 0x5000, 0x5000, 0x5000, 0x0000, 0x0000, // π
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0xF000, 0x8000, 0x8000, // ρ
+    0x0000, 0x0000, 0x0000, 0x7000, 0x8800, 0x8000, 0x8000, 0x7000, 0x0800, 0x3000, // ς
+    0x0000, 0x0000, 0x0000, 0x7800, 0x9000, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // σ
+    0x0000, 0x0000, 0x0000, 0xF800, 0x2000, 0x2000, 0x2000, 0x1000, 0x0000, 0x0000, // τ
+    0x0000, 0x0000, 0x0000, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // υ
+    0x0000, 0x0000, 0x0000, 0x5000, 0xA800, 0xA800, 0xA800, 0x7000, 0x2000, 0x2000, // φ
+    0x0000, 0x0000, 0x0000, 0x8800, 0x5000, 0x5000, 0x2000, 0x5000, 0x5000, 0x8800, // χ
+    0x0000, 0x0000, 0x0000, 0xA800, 0xA800, 0xA800, 0xA800, 0x7000, 0x2000, 0x2000, // ψ
+    0x0000, 0x0000, 0x0000, 0x8800, 0xA800, 0xA800, 0xA800, 0x5000, 0x0000, 0x0000, // ω
+    0x0000, 0x0000, 0x0000, 0x0000, 0xF800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // −
+    0x0000, 0x0000, 0xF800, 0x2000, 0x2000, 0xF800, 0x2000, 0x2000, 0x0000, 0x0000, // ∓
+    0x0000, 0x0000, 0x0000, 0x5000, 0xA800, 0xA800, 0x5000, 0x0000, 0x0000, 0x0000, // ∞
+    0x0000, 0x0000, 0x0C00, 0x1000, 0x1000, 0x2000, 0xA000, 0x4000, 0x0000, 0x0000, // √
+    0x0000, 0x1800, 0x6000, 0x8000, 0x6000, 0x1800, 0x0000, 0xF800, 0x0000, 0x0000, // ≤
+    0x0000, 0xC000, 0x3000, 0x0800, 0x3000, 0xC000, 0x0000, 0xF800, 0x0000, 0x0000, // ≥
+    0x0000, 0x0000, 0x0000, 0x4000, 0xA800, 0x5000, 0xA800, 0x1000, 0x0000, 0x0000, // ≈
+    0x0000, 0x0800, 0x1000, 0xF800, 0x2000, 0xF800, 0x4000, 0x8000, 0x0000, 0x0000, // ≠
+    0x0000, 0x0000, 0x0000, 0xF800, 0x0000, 0xF800, 0x0000, 0xF800, 0x0000, 0x0000, // ≡
+    0x0000, 0x0000, 0x0000, 0x2800, 0x5000, 0x5000, 0x2800, 0x0000, 0x0000, 0x0000, // ∝
+    0x0000, 0x3000, 0x0800, 0x6800, 0x9800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ∂
+    0x0000, 0x0000, 0xF800, 0x8800, 0x5000, 0x5000, 0x2000, 0x2000, 0x0000, 0x0000, // ∇
+    0xF800, 0x8000, 0x4000, 0x2000, 0x1000, 0x2000, 0x4000, 0x8000, 0xF800, 0x0000, // ∑
+    0xF800, 0x5000, 0x5000, 0x5000, 0x5000, 0x5000, 0x5000, 0x5000, 0x5000, 0x0000, // ∏
+    0x1000, 0x2800, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0x2000, 0xA000, 0x4000, // ∫
+    0x0000, 0x7800, 0x8000, 0x8000, 0xF000, 0x8000, 0x8000, 0x7800, 0x0000, 0x0000, // ∈
+    0x2000, 0x7800, 0xA000, 0xA000, 0xF000, 0xA000, 0xA000, 0x7800, 0x2000, 0x0000, // ∉
+    0x0000, 0x0000, 0x0000, 0x7800, 0x8000, 0x8000, 0x8000, 0x7800, 0x0000, 0x0000, // ⊂
+    0x0000, 0x0000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x7000, 0x0000, 0x0000, // ∪
+    0x0000, 0x0000, 0x7000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x0000, 0x0000, // ∩
+    0x0000, 0x8800, 0x8800, 0x7000, 0x5000, 0x5000, 0x2000, 0x2000, 0x0000, 0x0000, // ∀
+    0x0000, 0xF800, 0x0800, 0x0800, 0x7800, 0x0800, 0x0800, 0xF800, 0x0000, 0x0000, // ∃
+    0x0000, 0x0000, 0x0000, 0x1000, 0x0800, 0xFC00, 0x0800, 0x1000, 0x0000, 0x0000, // →
+    0x0000, 0x0000, 0x0000, 0x2000, 0x4000, 0xFC00, 0x4000, 0x2000, 0x0000, 0x0000, // ←
+    0x0000, 0x2000, 0x7000, 0xA800, 0x2000, 0x2000, 0x2000, 0x2000, 0x0000, 0x0000, // ↑
+    0x0000, 0x2000, 0x2000, 0x2000, 0x2000, 0xA800, 0x7000, 0x2000, 0x0000, 0x0000, // ↓
+    0x0000, 0x0000, 0x0000, 0x0000, 0x4800, 0xFC00, 0x4800, 0x0000, 0x0000, 0x0000, // ↔
+    0x0000, 0x2000, 0x1000, 0xF800, 0x0400, 0xF800, 0x1000, 0x2000, 0x0000, 0x0000, // ⇒
+    0x0000, 0x0000, 0x0000, 0x7800, 0x8400, 0x7800, 0x0000, 0x0000, 0x0000, 0x0000, // ⇔
+    0x1000, 0x1000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ′
+    0x2800, 0x2800, 0x5000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ″
+    0x0000, 0x4800, 0xA800, 0x5000, 0x2000, 0x6800, 0xD400, 0xA800, 0x0000, 0x0000, // ‰
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xA800, 0x0000, 0x0000, // …
+    0x0000, 0x0000, 0x0000, 0x0000, 0x2000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, // ⋅
+    0x0000, 0x0000, 0x0000, 0x3000, 0x4800, 0x4800, 0x3000, 0x0000, 0x0000, 0x0000, // ∘
+    0x0000, 0x6000, 0x9000, 0x7800, 0x1000, 0x9000, 0x9000, 0x6000, 0x0000, 0x0000, // ϑ
+    0x0000, 0x2000, 0x2000, 0x7000, 0xA800, 0xA800, 0xA800, 0x7000, 0x2000, 0x2000, // ϕ
+    0x0000, 0x0000, 0x0000, 0x3800, 0x4000, 0x7000, 0x4000, 0x3800, 0x0000, 0x0000, // ϵ
+];
@@ -0,0 +1,216 @@
+//! Built-in monospaced bitmap fonts
+//!
+//! The glyphs come from the public domain X11 "fixed" terminal fonts, in three sizes covering ASCII, Latin-1,
+//! the Greek alphabet and the mathematical symbols listed in `SYMBOLS`.
+//! Text is drawn with whichever font comes closest to the requested size, enlarged by a whole factor when even
+//! the largest font is too small, so it always stays sharp. Rotated text is resampled and anti-aliased.
+
+mod fixed_6x10;
+mod fixed_7x13;
+mod fixed_10x20;
+
+pub use self::fixed_6x10::FIXED_6X10;
+pub use self::fixed_7x13::FIXED_7X13;
+pub use self::fixed_10x20::FIXED_10X20;
+
+use ::canvas::{Anchor, TextExtent};
+
+/// Character drawn in place of those the fonts don't cover
+pub const REPLACEMENT: char = '?';
+
+/// Symbols covered by the built-in fonts, besides ASCII, Latin-1 and Greek
+pub static SYMBOLS: [char; 38] = [
+    '−', '∓', '∞', '√', '≤', '≥', '≈', '≠', '≡', '∝', '∂', '∇', '∑', '∏', '∫', '∈', '∉', '⊂', '∪',
+    '∩', '∀', '∃', '→', '←', '↑', '↓', '↔', '⇒', '⇔', '′', '″', '‰', '…', '⋅', '∘', 'ϑ', 'ϕ', 'ϵ',
+];
+
+/// A monospaced font with one bitmap per character of ASCII, Latin-1, Greek and `SYMBOLS`
+///
+/// Glyphs are stored one after the other, for the characters from U+0020 to U+007E, from U+00A0 to U+00FF,
+/// from U+0391 to U+03C9 except for the unassigned U+03A2, and then for `SYMBOLS` in order,
+/// with `height` rows each. A row is a `u16` with its leftmost pixel in the highest bit.
+pub struct BitmapFont {
+    /// Width of every glyph in pixels, up to 16
+    pub width: u32,
+    /// Height of every glyph in pixels
+    pub height: u32,
+    /// Number of rows above the baseline
+    pub ascent: u32,
+    pub glyphs: &'static [u16],
+}
+
+/// All built-in fonts, from smallest to largest
+pub static FONTS: [&'static BitmapFont; 3] = [&FIXED_6X10, &FIXED_7X13, &FIXED_10X20];
+
+impl BitmapFont {
+    /// Number of rows below the baseline
+    pub fn descent(&self) -> u32 {
+        self.height - self.ascent
+    }
+
+    /// Returns true if the font has a glyph for the character
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.index(c).is_some()
+    }
+
+    fn index(&self, c: char) -> Option<usize> {
+        match c as u32 {
+            c @ 0x20..=0x7E => Some(c as usize - 0x20),
+            c @ 0xA0..=0xFF => Some(c as usize - 0xA0 + 95),
+            c @ 0x391..=0x3A1 => Some(c as usize - 0x391 + 191),
+            c @ 0x3A3..=0x3C9 => Some(c as usize - 0x3A3 + 208),
+            _ => SYMBOLS.iter().position(|&s| s == c).map(|i| i + 247),
+        }
+    }
+
+    /// Rows of the glyph for a character, from the top down, falling back to `REPLACEMENT`
+    pub fn glyph(&self, c: char) -> &'static [u16] {
+        let index = self.index(c).or_else(|| self.index(REPLACEMENT)).unwrap();
+        let height = self.height as usize;
+
+        &self.glyphs[index * height..(index + 1) * height]
+    }
+
+    /// Size of a line of text drawn with the font enlarged by the given factor
+    pub fn extent(&self, text: &str, scale: u32) -> TextExtent {
+        TextExtent {
+            width: (text.chars().count() as u32 * self.width * scale) as f64,
+            ascent: (self.ascent * scale) as f64,
+            descent: (self.descent() * scale) as f64,
+        }
+    }
+}
+
+/// Chooses the built-in font and whole enlargement factor that come closest to a font size in pixels
+///
+/// ```
+/// use rust_plot::text::bitmap::font_for_size;
+///
+/// let (font, scale) = font_for_size(12.0);
+/// assert_eq!((font.height, scale), (13, 1));
+///
+/// let (font, scale) = font_for_size(40.0);
+/// assert_eq!((font.height, scale), (20, 2));
+/// ```
+pub fn font_for_size(size: f64) -> (&'static BitmapFont, u32) {
+    let mut best = (FONTS[0], 1);
+    let mut best_error = ::std::f64::INFINITY;
+
+    for &font in FONTS.iter() {
+        let scale = (size / font.height as f64).round().max(1.0);
+        let error = (font.height as f64 * scale - size).abs();
+
+        // Prefer larger fonts over enlarged smaller ones
+        if error <= best_error {
+            best = (font, scale as u32);
+            best_error = error;
+        }
+    }
+
+    best
+}
+
+/// Measure a line of text as drawn by `draw_text`
+pub fn text_extent(text: &str, size: f64) -> TextExtent {
+    let (font, scale) = font_for_size(size);
+
+    font.extent(text, scale)
+}
+
+/// Draws a line of text with the built-in font closest to the given size in pixels.
+///
+/// The text is rotated counter-clockwise around its anchor point by `rotation` radians.
+/// Unrotated text is drawn pixel for pixel, while rotated text is resampled with anti-aliasing.
+pub fn draw_text<F>(x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64, mut plot: F) where F: FnMut(i64, i64, f64) {
+    let (font, scale) = font_for_size(size);
+
+    let glyphs: Vec<&[u16]> = text.chars().map(|c| font.glyph(c)).collect();
+
+    if glyphs.is_empty() {
+        return;
+    }
+
+    let extent = font.extent(text, scale);
+    let (dx, dy) = extent.anchor_offset(anchor);
+
+    let (width, ascent) = (font.width as i64, font.ascent as i64);
+
+    if rotation == 0.0 {
+        // Bottom left pixel of the row just above the baseline
+        let (ox, oy) = ((x + dx).round() as i64, (y + dy).round() as i64);
+        let s = scale as i64;
+
+        for (n, rows) in glyphs.iter().enumerate() {
+            for (r, &bits) in rows.iter().enumerate() {
+                if bits == 0 { continue; }
+
+                let py = oy + (ascent - 1 - r as i64) * s;
+
+                for col in 0..width {
+                    if bits & (0x8000 >> col) != 0 {
+                        let px = ox + (n as i64 * width + col) * s;
+
+                        for sy in 0..s {
+                            for sx in 0..s {
+                                plot(px + sx, py + sy, 1.0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        let s = scale as f64;
+        let (sin, cos) = rotation.sin_cos();
+
+        // Left end of the baseline, after rotation
+        let (ox, oy) = (x + dx * cos - dy * sin, y + dx * sin + dy * cos);
+
+        // Whether the font pixel at a column of the whole line and a row from the top is set
+        let lit = |col: i64, row: i64| -> f64 {
+            if col < 0 || row < 0 || row >= font.height as i64 {
+                return 0.0;
+            }
+
+            match glyphs.get((col / width) as usize) {
+                Some(rows) if rows[row as usize] & (0x8000 >> (col % width)) != 0 => 1.0,
+                _ => 0.0,
+            }
+        };
+
+        let corners = [(0.0, -extent.descent), (extent.width, -extent.descent),
+                       (extent.width, extent.ascent), (0.0, extent.ascent)];
+
+        let (mut x0, mut y0, mut x1, mut y1) = (ox, oy, ox, oy);
+
+        for &(u, v) in &corners {
+            let (cx, cy) = (ox + u * cos - v * sin, oy + u * sin + v * cos);
+
+            x0 = x0.min(cx);
+            y0 = y0.min(cy);
+            x1 = x1.max(cx);
+            y1 = y1.max(cy);
+        }
+
+        for py in (y0.floor() as i64 - 1)..(y1.ceil() as i64 + 2) {
+            for px in (x0.floor() as i64 - 1)..(x1.ceil() as i64 + 2) {
+                let (lx, ly) = (px as f64 - ox, py as f64 - oy);
+
+                // Position in font pixels, measured from the top left of the line, relative to pixel centers
+                let fx = (lx * cos + ly * sin) / s - 0.5;
+                let fy = ascent as f64 - (ly * cos - lx * sin) / s - 0.5;
+
+                let (col, row) = (fx.floor(), fy.floor());
+                let (tx, ty) = (fx - col, fy - row);
+                let (col, row) = (col as i64, row as i64);
+
+                let alpha = lit(col, row) * (1.0 - tx) * (1.0 - ty) + lit(col + 1, row) * tx * (1.0 - ty) +
+                    lit(col, row + 1) * (1.0 - tx) * ty + lit(col + 1, row + 1) * tx * ty;
+
+                if alpha > 0.0 {
+                    plot(px, py, alpha);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Hershey stroke fonts
+//!
+//! Hershey fonts describe each glyph as a handful of polylines, so text scales and rotates freely
+//! and is drawn with ordinary line primitives, on any `Plotter` or `Canvas`.
+//!
+//! The glyph coordinates derive from the fonts digitized by Dr. A. V. Hershey for the U.S. National Bureau of Standards.
+
+mod simplex;
+mod symbols;
+
+use ::canvas::{Anchor, Canvas, Stroke, TextStyle, TextExtent};
+use ::plot::{LineStyle, Plotter};
+
+use super::bitmap::REPLACEMENT;
+use super::TextRenderer;
+
+/// Height of capitals above the baseline, in font units
+const ASCENT: f64 = 21.0;
+/// Depth of descenders below the baseline, in font units
+const DESCENT: f64 = 7.0;
+
+/// A glyph made of polylines, with its advance width, in font units
+pub struct Glyph {
+    pub width: i8,
+    pub strokes: &'static [&'static [(i8, i8)]],
+}
+
+/// A stroke font covering the characters from U+0020 to U+007E, plus any number of others
+pub struct HersheyFont {
+    pub glyphs: &'static [Glyph],
+    /// Glyphs for characters outside of ASCII, sorted by character
+    pub extra: &'static [(char, Glyph)],
+}
+
+/// The Roman Simplex font, a plain sans-serif drawn with single strokes, together with the
+/// Greek letters and mathematical symbols of the Greek Simplex font
+pub static SIMPLEX: HersheyFont = HersheyFont { glyphs: &simplex::SIMPLEX_GLYPHS, extra: &symbols::SYMBOL_GLYPHS };
+
+impl HersheyFont {
+    /// Returns true if the font has a glyph for the character
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.find(c).is_some()
+    }
+
+    fn find(&self, c: char) -> Option<&Glyph> {
+        match c as u32 {
+            c @ 0x20..=0x7E => self.glyphs.get(c as usize - 0x20),
+            _ => self.extra.binary_search_by_key(&c, |&(e, _)| e).ok().map(|i| &self.extra[i].1),
+        }
+    }
+
+    /// The glyph for a character, falling back to `REPLACEMENT`
+    pub fn glyph(&self, c: char) -> &Glyph {
+        self.find(c).or_else(|| self.find(REPLACEMENT)).unwrap()
+    }
+
+    /// Measure a line of text at the given font size in pixels, the distance from the bottom of the descenders
+    /// to the top of the capitals
+    pub fn extent(&self, text: &str, size: f64) -> TextExtent {
+        let scale = size / (ASCENT + DESCENT);
+
+        let width: i64 = text.chars().map(|c| self.glyph(c).width as i64).sum();
+
+        TextExtent {
+            width: width as f64 * scale,
+            ascent: ASCENT * scale,
+            descent: DESCENT * scale,
+        }
+    }
+
+    /// Lay out a line of text as polylines, anchored at the given point and rotated counter-clockwise
+    /// around it by `rotation` radians
+    pub fn layout(&self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) -> Vec<Vec<(f64, f64)>> {
+        let scale = size / (ASCENT + DESCENT);
+
+        let (dx, dy) = self.extent(text, size).anchor_offset(anchor);
+
+        let (sin, cos) = rotation.sin_cos();
+
+        let mut strokes = Vec::new();
+        let mut advance = 0.0;
+
+        for c in text.chars() {
+            let glyph = self.glyph(c);
+
+            for stroke in glyph.strokes {
+                strokes.push(stroke.iter().map(|&(gx, gy)| {
+                    let u = dx + advance + gx as f64 * scale;
+                    let v = dy + gy as f64 * scale;
+
+                    (x + u * cos - v * sin, y + u * sin + v * cos)
+                }).collect());
+            }
+
+            advance += glyph.width as f64 * scale;
+        }
+
+        strokes
+    }
+
+    /// Draw a line of text onto a plotter with the given line style
+    pub fn draw<P: Plotter + ?Sized>(&self, plot: &mut P, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64, style: LineStyle) {
+        for stroke in self.layout(x, y, text, anchor, size, rotation) {
+            for w in stroke.windows(2) {
+                plot.draw_line(w[0].0, w[0].1, w[1].0, w[1].1, style);
+            }
+        }
+    }
+}
+
+/// Text renderer that draws text as strokes of a Hershey font
+#[derive(Clone, Copy)]
+pub struct HersheyText {
+    pub font: &'static HersheyFont,
+    /// Width of the strokes relative to the font size
+    pub weight: f64,
+}
+
+impl HersheyText {
+    pub fn new(font: &'static HersheyFont) -> HersheyText {
+        HersheyText { font: font, weight: 0.07 }
+    }
+
+    pub fn with_weight(self, weight: f64) -> HersheyText {
+        HersheyText { weight: weight, ..self }
+    }
+
+    /// Line style of the strokes at the given font size
+    pub fn line_style(&self, size: f64) -> LineStyle {
+        let width = size * self.weight;
+
+        if width <= 1.5 { LineStyle::ThinAA } else { LineStyle::thick(width, 2.0).aa() }
+    }
+}
+
+impl Default for HersheyText {
+    fn default() -> HersheyText {
+        HersheyText::new(&SIMPLEX)
+    }
+}
+
+impl TextRenderer for HersheyText {
+    fn extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        self.font.extent(text, style.size)
+    }
+
+    fn render<C: Canvas + ?Sized>(&self, canvas: &mut C, x: f64, y: f64, text: &str, style: &TextStyle) {
+        let stroke = Stroke::new(style.color, self.line_style(style.size));
+
+        for points in self.font.layout(x, y, text, style.anchor, style.size, style.rotation) {
+            canvas.polyline(&points, stroke);
+        }
+    }
+}
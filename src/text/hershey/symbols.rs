@@ -0,0 +1,209 @@
+//! Glyphs of the Hershey Simplex font for Greek letters and mathematical symbols
+//!
+//! Capitals that look the same as Latin letters are drawn with the same strokes. Entries are sorted by character, for binary search.
+
+use super::Glyph;
+
+pub static SYMBOL_GLYPHS: [(char, Glyph); 82] = [
+    ('°', Glyph { width: 14, strokes: &[
+        &[(6, 21), (4, 20), (3, 18), (3, 16), (4, 14), (6, 13), (8, 13), (10, 14), (11, 16), (11, 18), (10, 20), (8, 21), (6, 21)],
+    ] }),
+    ('±', Glyph { width: 26, strokes: &[&[(13, 18), (13, 1)], &[(4, 10), (22, 10)], &[(4, 1), (22, 1)]] }),
+    ('µ', Glyph { width: 19, strokes: &[
+        &[(4, 14), (4, -7)],
+        &[(4, 4), (5, 1), (7, 0), (10, 0), (12, 1), (15, 4)],
+        &[(15, 14), (15, 3), (16, 1), (17, 0)],
+    ] }),
+    ('·', Glyph { width: 10, strokes: &[&[(5, 10), (4, 9), (5, 8), (6, 9), (5, 10)]] }),
+    ('×', Glyph { width: 26, strokes: &[&[(6, 16), (20, 2)], &[(20, 16), (6, 2)]] }),
+    ('Α', Glyph { width: 18, strokes: &[&[(9, 21), (1, 0)], &[(9, 21), (17, 0)], &[(4, 7), (14, 7)]] }),
+    ('Β', Glyph { width: 21, strokes: &[
+        &[(4, 21), (4, 0)],
+        &[(4, 21), (13, 21), (16, 20), (17, 19), (18, 17), (18, 15), (17, 13), (16, 12), (13, 11)],
+        &[(4, 11), (13, 11), (16, 10), (17, 9), (18, 7), (18, 4), (17, 2), (16, 1), (13, 0), (4, 0)],
+    ] }),
+    ('Γ', Glyph { width: 17, strokes: &[&[(4, 21), (4, 0)], &[(4, 21), (16, 21)]] }),
+    ('Δ', Glyph { width: 18, strokes: &[&[(9, 21), (1, 0), (17, 0), (9, 21)]] }),
+    ('Ε', Glyph { width: 19, strokes: &[
+        &[(4, 21), (4, 0)],
+        &[(4, 21), (17, 21)],
+        &[(4, 11), (12, 11)],
+        &[(4, 0), (17, 0)],
+    ] }),
+    ('Ζ', Glyph { width: 20, strokes: &[&[(17, 21), (3, 0)], &[(3, 21), (17, 21)], &[(3, 0), (17, 0)]] }),
+    ('Η', Glyph { width: 22, strokes: &[&[(4, 21), (4, 0)], &[(18, 21), (18, 0)], &[(4, 11), (18, 11)]] }),
+    ('Θ', Glyph { width: 22, strokes: &[
+        &[(9, 21), (7, 20), (5, 18), (4, 16), (3, 13), (3, 8), (4, 5), (5, 3), (7, 1), (9, 0), (13, 0), (15, 1), (17, 3), (18, 5), (19, 8), (19, 13), (18, 16), (17, 18), (15, 20), (13, 21), (9, 21)],
+        &[(8, 11), (14, 11)],
+    ] }),
+    ('Ι', Glyph { width: 8, strokes: &[&[(4, 21), (4, 0)]] }),
+    ('Κ', Glyph { width: 21, strokes: &[&[(4, 21), (4, 0)], &[(18, 21), (4, 7)], &[(9, 12), (18, 0)]] }),
+    ('Λ', Glyph { width: 18, strokes: &[&[(9, 21), (1, 0)], &[(9, 21), (17, 0)]] }),
+    ('Μ', Glyph { width: 24, strokes: &[
+        &[(4, 21), (4, 0)],
+        &[(4, 21), (12, 0)],
+        &[(20, 21), (12, 0)],
+        &[(20, 21), (20, 0)],
+    ] }),
+    ('Ν', Glyph { width: 22, strokes: &[&[(4, 21), (4, 0)], &[(4, 21), (18, 0)], &[(18, 21), (18, 0)]] }),
+    ('Ξ', Glyph { width: 18, strokes: &[&[(3, 21), (15, 21)], &[(6, 11), (12, 11)], &[(3, 0), (15, 0)]] }),
+    ('Ο', Glyph { width: 22, strokes: &[
+        &[(9, 21), (7, 20), (5, 18), (4, 16), (3, 13), (3, 8), (4, 5), (5, 3), (7, 1), (9, 0), (13, 0), (15, 1), (17, 3), (18, 5), (19, 8), (19, 13), (18, 16), (17, 18), (15, 20), (13, 21), (9, 21)],
+    ] }),
+    ('Π', Glyph { width: 22, strokes: &[&[(4, 21), (4, 0)], &[(18, 21), (18, 0)], &[(4, 21), (18, 21)]] }),
+    ('Ρ', Glyph { width: 21, strokes: &[
+        &[(4, 21), (4, 0)],
+        &[(4, 21), (13, 21), (16, 20), (17, 19), (18, 17), (18, 14), (17, 12), (16, 11), (13, 10), (4, 10)],
+    ] }),
+    ('Σ', Glyph { width: 20, strokes: &[&[(4, 21), (11, 11), (4, 0)], &[(4, 21), (17, 21)], &[(4, 0), (17, 0)]] }),
+    ('Τ', Glyph { width: 16, strokes: &[&[(8, 21), (8, 0)], &[(1, 21), (15, 21)]] }),
+    ('Υ', Glyph { width: 18, strokes: &[&[(1, 21), (9, 11), (9, 0)], &[(17, 21), (9, 11)]] }),
+    ('Φ', Glyph { width: 22, strokes: &[
+        &[(11, 21), (11, 0)],
+        &[(11, 16), (10, 16), (8, 16), (7, 16), (6, 15), (5, 15), (4, 14), (4, 13), (3, 12), (3, 11), (3, 10), (3, 9), (4, 8), (4, 7), (5, 6), (6, 6), (7, 5), (8, 5), (10, 5), (11, 4), (12, 5), (14, 5), (15, 5), (16, 6), (17, 6), (18, 7), (18, 8), (19, 9), (19, 10), (19, 11), (19, 12), (18, 13), (18, 14), (17, 15), (16, 15), (15, 16), (14, 16), (12, 16), (11, 16)],
+    ] }),
+    ('Χ', Glyph { width: 20, strokes: &[&[(3, 21), (17, 0)], &[(17, 21), (3, 0)]] }),
+    ('Ψ', Glyph { width: 22, strokes: &[
+        &[(11, 21), (11, 0)],
+        &[(2, 15), (3, 15), (4, 14), (5, 10), (6, 8), (7, 7), (10, 6), (12, 6), (15, 7), (16, 8), (17, 10), (18, 14), (19, 15), (20, 15)],
+    ] }),
+    ('Ω', Glyph { width: 20, strokes: &[
+        &[(3, 0), (7, 0), (6, 4), (5, 5), (5, 6), (4, 7), (3, 8), (3, 9), (3, 10), (3, 12), (3, 13), (3, 14), (3, 16), (4, 17), (4, 18), (5, 19), (6, 20), (7, 20), (8, 21), (9, 21), (10, 21), (11, 21), (12, 21), (13, 20), (14, 20), (15, 19), (16, 18), (16, 17), (17, 16), (17, 14), (17, 13), (17, 12), (17, 10), (17, 9), (17, 8), (16, 7), (15, 6), (15, 5), (14, 4), (13, 0), (17, 0)],
+    ] }),
+    ('α', Glyph { width: 21, strokes: &[
+        &[(15, 10), (13, 13), (11, 14), (8, 14), (6, 13), (4, 11), (3, 8), (3, 6), (4, 3), (6, 1), (8, 0), (10, 0), (12, 1), (14, 4), (15, 7), (16, 11), (17, 14)],
+        &[(15, 7), (15, 4), (16, 1), (18, 0)],
+    ] }),
+    ('β', Glyph { width: 19, strokes: &[
+        &[(4, -7), (4, 16), (5, 19), (7, 21), (10, 21), (12, 20), (13, 18), (13, 16), (12, 14), (10, 13), (7, 13)],
+        &[(10, 13), (13, 12), (15, 10), (15, 4), (14, 2), (12, 0), (9, 0), (6, 1), (4, 3)],
+    ] }),
+    ('γ', Glyph { width: 17, strokes: &[
+        &[(2, 14), (3, 14), (5, 12), (9, 0)],
+        &[(15, 14), (10, 3), (9, 0), (8, -4), (8, -7)],
+    ] }),
+    ('δ', Glyph { width: 19, strokes: &[
+        &[(14, 20), (12, 21), (9, 21), (7, 20), (6, 18), (7, 16), (9, 14), (12, 12), (13, 12), (14, 11), (15, 10), (15, 9), (16, 7), (16, 6), (16, 5), (15, 3), (15, 2), (14, 1), (13, 1), (12, 0), (11, 0), (9, 0), (8, 0), (7, 0), (6, 1), (5, 2), (4, 3), (3, 4), (3, 5), (3, 7), (3, 8), (4, 9), (5, 10), (5, 11), (7, 12), (8, 12), (9, 12), (10, 12), (12, 12)],
+    ] }),
+    ('ε', Glyph { width: 17, strokes: &[
+        &[(14, 12), (12, 14), (7, 14), (4, 13), (3, 11), (4, 9), (7, 8), (10, 8)],
+        &[(7, 8), (4, 7), (3, 5), (3, 3), (4, 1), (7, 0), (11, 0), (13, 1), (15, 3)],
+    ] }),
+    ('ζ', Glyph { width: 16, strokes: &[
+        &[(6, 21), (14, 21), (9, 15), (5, 10), (3, 6), (3, 3), (4, 1), (6, 0), (10, 0), (12, -1), (13, -3), (12, -5), (10, -7)],
+    ] }),
+    ('η', Glyph { width: 19, strokes: &[
+        &[(4, 14), (4, 0)],
+        &[(4, 10), (7, 13), (9, 14), (12, 14), (14, 13), (15, 10), (15, -7)],
+    ] }),
+    ('θ', Glyph { width: 19, strokes: &[
+        &[(10, 21), (9, 21), (8, 21), (7, 20), (6, 20), (6, 19), (5, 18), (4, 17), (4, 16), (4, 15), (3, 14), (3, 12), (3, 11), (3, 10), (3, 9), (3, 7), (4, 6), (4, 5), (4, 4), (5, 3), (6, 2), (6, 1), (7, 1), (8, 0), (9, 0), (10, 0), (11, 0), (12, 1), (13, 1), (13, 2), (14, 3), (15, 4), (15, 5), (15, 6), (16, 7), (16, 9), (16, 10), (16, 11), (16, 12), (16, 14), (15, 15), (15, 16), (15, 17), (14, 18), (13, 19), (13, 20), (12, 20), (11, 21), (10, 21)],
+        &[(3, 11), (16, 11)],
+    ] }),
+    ('ι', Glyph { width: 10, strokes: &[&[(4, 14), (4, 3), (5, 1), (7, 0), (8, 0)]] }),
+    ('κ', Glyph { width: 16, strokes: &[&[(4, 14), (4, 0)], &[(14, 14), (4, 5)], &[(8, 8), (14, 0)]] }),
+    ('λ', Glyph { width: 17, strokes: &[&[(3, 21), (5, 21), (7, 19), (15, 0)], &[(9, 14), (2, 0)]] }),
+    ('μ', Glyph { width: 19, strokes: &[
+        &[(4, 14), (4, -7)],
+        &[(4, 4), (5, 1), (7, 0), (10, 0), (12, 1), (15, 4)],
+        &[(15, 14), (15, 3), (16, 1), (17, 0)],
+    ] }),
+    ('ν', Glyph { width: 17, strokes: &[&[(3, 14), (4, 14), (8, 0)], &[(15, 14), (13, 9), (8, 0)]] }),
+    ('ξ', Glyph { width: 16, strokes: &[
+        &[(12, 21), (9, 21), (7, 20), (7, 18), (9, 16), (12, 15)],
+        &[(12, 15), (8, 15), (5, 14), (4, 12), (4, 10), (6, 8), (10, 7)],
+        &[(10, 7), (6, 7), (4, 6), (3, 4), (3, 2), (5, 0), (9, -1), (11, -2), (11, -4), (10, -5), (8, -7)],
+    ] }),
+    ('ο', Glyph { width: 19, strokes: &[
+        &[(8, 14), (6, 13), (4, 11), (3, 8), (3, 6), (4, 3), (6, 1), (8, 0), (11, 0), (13, 1), (15, 3), (16, 6), (16, 8), (15, 11), (13, 13), (11, 14), (8, 14)],
+    ] }),
+    ('π', Glyph { width: 19, strokes: &[
+        &[(2, 14), (17, 14)],
+        &[(6, 14), (6, 0)],
+        &[(13, 14), (13, 3), (14, 1), (16, 0)],
+    ] }),
+    ('ρ', Glyph { width: 19, strokes: &[
+        &[(4, -7), (4, 8), (5, 11), (7, 13), (9, 14), (11, 14), (13, 13), (15, 11), (16, 8), (16, 6), (15, 3), (13, 1), (11, 0), (8, 0), (6, 1), (4, 3)],
+    ] }),
+    ('ς', Glyph { width: 17, strokes: &[
+        &[(14, 13), (12, 14), (9, 14), (6, 13), (4, 11), (3, 8), (3, 5), (4, 3), (6, 1), (9, 0), (11, -1), (12, -3), (11, -5), (9, -7)],
+    ] }),
+    ('σ', Glyph { width: 20, strokes: &[
+        &[(18, 14), (8, 14), (6, 13), (4, 11), (3, 8), (3, 6), (4, 3), (6, 1), (8, 0), (10, 0), (12, 1), (14, 3), (15, 6), (15, 8), (14, 11), (12, 13), (10, 14)],
+    ] }),
+    ('τ', Glyph { width: 17, strokes: &[&[(2, 14), (15, 14)], &[(9, 14), (9, 3), (10, 1), (12, 0)]] }),
+    ('υ', Glyph { width: 18, strokes: &[
+        &[(3, 14), (3, 5), (4, 2), (6, 0), (9, 0), (12, 1), (14, 3), (15, 6), (15, 10), (14, 14)],
+    ] }),
+    ('φ', Glyph { width: 20, strokes: &[
+        &[(7, 14), (5, 13), (3, 11), (2, 8), (2, 5), (3, 3), (5, 1), (8, 0), (12, 0), (15, 1), (17, 3), (18, 5), (18, 8), (17, 11), (15, 13), (13, 14), (11, 13), (10, 11), (10, 8), (10, -7)],
+    ] }),
+    ('χ', Glyph { width: 20, strokes: &[
+        &[(3, 14), (5, 14), (7, 12), (13, -5), (15, -7), (17, -7)],
+        &[(17, 14), (3, -7)],
+    ] }),
+    ('ψ', Glyph { width: 22, strokes: &[
+        &[(11, 21), (11, -7)],
+        &[(2, 14), (3, 13), (4, 8), (5, 4), (7, 1), (10, 0), (12, 0), (15, 1), (17, 4), (18, 8), (19, 13), (20, 14)],
+    ] }),
+    ('ω', Glyph { width: 22, strokes: &[
+        &[(6, 14), (4, 12), (3, 9), (3, 5), (4, 2), (6, 0), (8, 0), (10, 2), (11, 5), (11, 8)],
+        &[(11, 5), (12, 2), (14, 0), (16, 0), (18, 2), (19, 5), (19, 9), (18, 12), (16, 14)],
+    ] }),
+    ('ϑ', Glyph { width: 19, strokes: &[
+        &[(1, 7), (3, 8), (6, 8), (9, 9), (12, 12), (13, 15), (13, 18), (12, 20), (10, 21), (8, 21), (6, 19), (6, 15), (7, 10), (8, 5), (9, 2), (11, 0), (14, 0), (16, 2), (17, 5), (17, 9), (16, 14)],
+    ] }),
+    ('ϕ', Glyph { width: 20, strokes: &[
+        &[(10, 21), (10, -7)],
+        &[(10, 14), (9, 14), (7, 13), (6, 13), (5, 12), (4, 11), (4, 10), (3, 9), (3, 7), (3, 6), (3, 5), (4, 3), (5, 2), (6, 1), (7, 1), (8, 0), (9, 0), (11, 0), (12, 0), (13, 1), (14, 1), (15, 2), (16, 3), (17, 5), (17, 6), (17, 7), (17, 9), (16, 10), (16, 11), (15, 12), (14, 13), (13, 13), (11, 14), (10, 14)],
+    ] }),
+    ('ϵ', Glyph { width: 16, strokes: &[
+        &[(14, 14), (10, 14), (7, 13), (5, 11), (3, 8), (3, 6), (4, 3), (6, 1), (9, 0), (13, 0)],
+        &[(3, 7), (11, 7)],
+    ] }),
+    ('…', Glyph { width: 30, strokes: &[
+        &[(5, 2), (4, 1), (5, 0), (6, 1), (5, 2)],
+        &[(15, 2), (14, 1), (15, 0), (16, 1), (15, 2)],
+        &[(25, 2), (24, 1), (25, 0), (26, 1), (25, 2)],
+    ] }),
+    ('′', Glyph { width: 8, strokes: &[&[(5, 22), (3, 15)]] }),
+    ('″', Glyph { width: 12, strokes: &[&[(5, 22), (3, 15)], &[(9, 22), (7, 15)]] }),
+    ('←', Glyph { width: 26, strokes: &[&[(22, 9), (4, 9)], &[(9, 14), (4, 9), (9, 4)]] }),
+    ('↑', Glyph { width: 16, strokes: &[&[(8, 21), (8, 0)], &[(3, 16), (8, 21), (13, 16)]] }),
+    ('→', Glyph { width: 26, strokes: &[&[(4, 9), (22, 9)], &[(17, 14), (22, 9), (17, 4)]] }),
+    ('↓', Glyph { width: 16, strokes: &[&[(8, 21), (8, 0)], &[(3, 5), (8, 0), (13, 5)]] }),
+    ('∂', Glyph { width: 19, strokes: &[
+        &[(4, 19), (6, 21), (10, 21), (13, 20), (15, 17), (16, 12), (16, 6), (15, 3), (13, 1), (10, 0), (7, 0), (5, 1), (4, 3), (3, 6), (4, 9), (6, 11), (9, 12), (12, 12), (14, 11), (16, 8)],
+    ] }),
+    ('∇', Glyph { width: 20, strokes: &[&[(2, 21), (18, 21), (10, 0), (2, 21)]] }),
+    ('∈', Glyph { width: 20, strokes: &[
+        &[(16, 16), (10, 16), (7, 15), (5, 13), (4, 10), (4, 8), (5, 5), (7, 3), (10, 2), (16, 2)],
+        &[(4, 9), (13, 9)],
+    ] }),
+    ('∏', Glyph { width: 22, strokes: &[&[(4, 21), (4, 0)], &[(18, 21), (18, 0)], &[(4, 21), (18, 21)]] }),
+    ('∑', Glyph { width: 20, strokes: &[&[(4, 21), (11, 11), (4, 0)], &[(4, 21), (17, 21)], &[(4, 0), (17, 0)]] }),
+    ('−', Glyph { width: 26, strokes: &[&[(4, 9), (22, 9)]] }),
+    ('∓', Glyph { width: 26, strokes: &[&[(13, 17), (13, 0)], &[(4, 17), (22, 17)], &[(4, 8), (22, 8)]] }),
+    ('∘', Glyph { width: 14, strokes: &[
+        &[(7, 12), (6, 12), (5, 11), (4, 10), (4, 8), (5, 7), (6, 6), (7, 6), (8, 6), (9, 7), (10, 8), (10, 10), (9, 11), (8, 12), (7, 12)],
+    ] }),
+    ('√', Glyph { width: 24, strokes: &[&[(3, 10), (6, 12), (12, 0), (22, 24)]] }),
+    ('∝', Glyph { width: 26, strokes: &[
+        &[(22, 14), (19, 13), (16, 11), (12, 7), (9, 5), (6, 5), (4, 7), (4, 11), (6, 13), (9, 13), (12, 11), (16, 7), (19, 5), (22, 4)],
+    ] }),
+    ('∞', Glyph { width: 26, strokes: &[
+        &[(22, 9), (22, 10), (22, 11), (21, 12), (20, 13), (19, 13), (18, 13), (17, 12), (16, 11), (14, 10), (13, 9), (12, 8), (10, 7), (9, 6), (8, 5), (7, 5), (6, 5), (5, 6), (4, 7), (4, 8), (4, 9), (4, 10), (4, 11), (5, 12), (6, 13), (7, 13), (8, 13), (9, 12), (10, 11), (12, 10), (13, 9), (14, 8), (16, 7), (17, 6), (18, 5), (19, 5), (20, 5), (21, 6), (22, 7), (22, 8), (22, 9)],
+    ] }),
+    ('∫', Glyph { width: 15, strokes: &[
+        &[(13, 25), (12, 25), (10, 24), (9, 22), (8, 19), (7, 4), (6, -3), (5, -5), (3, -6), (2, -6)],
+    ] }),
+    ('≈', Glyph { width: 26, strokes: &[
+        &[(4, 12), (6, 13), (7, 13), (8, 14), (10, 13), (12, 13), (13, 12), (14, 11), (16, 11), (18, 10), (19, 11), (20, 11), (22, 12)],
+        &[(4, 6), (6, 7), (7, 7), (8, 8), (10, 7), (12, 7), (13, 6), (14, 5), (16, 5), (18, 4), (19, 5), (20, 5), (22, 6)],
+    ] }),
+    ('≠', Glyph { width: 26, strokes: &[&[(4, 12), (22, 12)], &[(4, 6), (22, 6)], &[(18, 18), (8, 0)]] }),
+    ('≡', Glyph { width: 26, strokes: &[&[(4, 14), (22, 14)], &[(4, 9), (22, 9)], &[(4, 4), (22, 4)]] }),
+    ('≤', Glyph { width: 26, strokes: &[&[(22, 18), (4, 11), (22, 4)], &[(4, 0), (22, 0)]] }),
+    ('≥', Glyph { width: 26, strokes: &[&[(4, 18), (22, 11), (4, 4)], &[(4, 0), (22, 0)]] }),
+    ('⋅', Glyph { width: 10, strokes: &[&[(5, 10), (4, 9), (5, 8), (6, 9), (5, 10)]] }),
+];
@@ -0,0 +1,336 @@
+//! Labels with superscripts, subscripts and symbols
+//!
+//! A small subset of TeX is understood:
+//!
+//! - `x^2` and `x_i` raise or lower the next character, command or `{...}` group, like `10^{-3}` or `σ_{max}`.
+//!   A subscript directly followed by a superscript, like `x_i^2`, is stacked above it.
+//! - `\alpha` to `\omega` and `\Gamma` to `\Omega` stand for Greek letters, along with symbols like `\pm`, `\times`,
+//!   `\infty` and `\sqrt`. A single space after a command is skipped, so `\Delta t` reads as `Δt`.
+//! - `{` and `}` group without drawing anything, and `\{`, `\}`, `\^`, `\_` and `\\` stand for the characters themselves.
+//!
+//! Anything else, including unknown commands, is drawn as written. Text is measured and drawn with the
+//! `text_extent` and `text` methods of a `Canvas`, so it works with any of the text renderers.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::canvas::{Canvas, TextStyle};
+//! use rust_plot::text::markup::Markup;
+//!
+//! let mut plot = BufferPlot::new(200, 50);
+//! let style = TextStyle::new(16.0);
+//!
+//! let label = Markup::parse("\\sigma_i^2 (10^{-3} m^2)");
+//!
+//! // The superscripts reach higher than plain text
+//! assert!(label.extent(&plot, &style).ascent > plot.text_extent("σi (10 m)", &style).ascent);
+//!
+//! label.draw(&mut plot, 10.0, 20.0, &style);
+//! ```
+
+use ::canvas::{Anchor, Canvas, TextStyle, TextExtent};
+
+/// Height of the baseline of superscripts above that of the text they follow, relative to its font size
+const SUPERSCRIPT_RISE: f64 = 0.42;
+/// Depth of the baseline of subscripts below that of the text they follow, relative to its font size
+const SUBSCRIPT_DROP: f64 = 0.22;
+
+/// Names of the commands that stand for a single character
+static COMMANDS: [(&'static str, char); 85] = [
+    ("alpha", 'α'), ("beta", 'β'), ("gamma", 'γ'), ("delta", 'δ'), ("epsilon", 'ϵ'), ("varepsilon", 'ε'),
+    ("zeta", 'ζ'), ("eta", 'η'), ("theta", 'θ'), ("vartheta", 'ϑ'), ("iota", 'ι'), ("kappa", 'κ'),
+    ("lambda", 'λ'), ("mu", 'μ'), ("nu", 'ν'), ("xi", 'ξ'), ("omicron", 'ο'), ("pi", 'π'), ("rho", 'ρ'),
+    ("sigma", 'σ'), ("varsigma", 'ς'), ("tau", 'τ'), ("upsilon", 'υ'), ("phi", 'ϕ'), ("varphi", 'φ'),
+    ("chi", 'χ'), ("psi", 'ψ'), ("omega", 'ω'),
+    ("Alpha", 'Α'), ("Beta", 'Β'), ("Gamma", 'Γ'), ("Delta", 'Δ'), ("Epsilon", 'Ε'), ("Zeta", 'Ζ'),
+    ("Eta", 'Η'), ("Theta", 'Θ'), ("Iota", 'Ι'), ("Kappa", 'Κ'), ("Lambda", 'Λ'), ("Mu", 'Μ'), ("Nu", 'Ν'),
+    ("Xi", 'Ξ'), ("Omicron", 'Ο'), ("Pi", 'Π'), ("Rho", 'Ρ'), ("Sigma", 'Σ'), ("Tau", 'Τ'), ("Upsilon", 'Υ'),
+    ("Phi", 'Φ'), ("Chi", 'Χ'), ("Psi", 'Ψ'), ("Omega", 'Ω'),
+    ("pm", '±'), ("mp", '∓'), ("times", '×'), ("cdot", '⋅'), ("circ", '∘'), ("infty", '∞'), ("sqrt", '√'),
+    ("le", '≤'), ("leq", '≤'), ("ge", '≥'), ("geq", '≥'), ("approx", '≈'), ("ne", '≠'), ("neq", '≠'),
+    ("equiv", '≡'), ("propto", '∝'), ("partial", '∂'), ("nabla", '∇'), ("sum", '∑'), ("prod", '∏'),
+    ("int", '∫'), ("in", '∈'), ("to", '→'), ("rightarrow", '→'), ("leftarrow", '←'), ("uparrow", '↑'),
+    ("downarrow", '↓'), ("prime", '′'), ("dots", '…'), ("ldots", '…'), ("deg", '°'), ("degree", '°'),
+    ("minus", '−'),
+];
+
+/// The character a command stands for, like `'α'` for `\alpha`
+pub fn command_char(name: &str) -> Option<char> {
+    COMMANDS.iter().find(|&&(n, _)| n == name).map(|&(_, c)| c)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Scripts { sup: Option<Vec<Node>>, sub: Option<Vec<Node>> },
+}
+
+/// Parsed label markup, ready to be measured and drawn
+#[derive(Debug, Clone, PartialEq)]
+pub struct Markup {
+    nodes: Vec<Node>,
+}
+
+/// A piece of text placed relative to the left end of the baseline
+struct Run<'a> {
+    text: &'a str,
+    x: f64,
+    rise: f64,
+    size: f64,
+}
+
+impl Markup {
+    /// Parse markup. Every string is valid, since anything that isn't understood is drawn as written.
+    pub fn parse(text: &str) -> Markup {
+        let mut parser = Parser { chars: text.chars().collect(), position: 0 };
+
+        Markup { nodes: parser.parse_list(false) }
+    }
+
+    /// Returns true if the markup is plain text, without any scripts
+    pub fn is_plain(&self) -> bool {
+        self.nodes.iter().all(|node| match *node { Node::Text(_) => true, _ => false })
+    }
+
+    /// The text with scripts written inline and all commands replaced by their characters
+    ///
+    /// ```
+    /// # use rust_plot::text::markup::Markup;
+    /// assert_eq!(Markup::parse("\\Delta t_{max}^2").to_plain_text(), "Δtmax2");
+    /// ```
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+
+        plain_text(&self.nodes, &mut text);
+
+        text
+    }
+
+    /// Measure the markup as drawn on the given canvas with the given style
+    pub fn extent<C: Canvas + ?Sized>(&self, canvas: &C, style: &TextStyle) -> TextExtent {
+        self.layout(canvas, style).1
+    }
+
+    /// Draw the markup, anchored at the given point as described by the style
+    pub fn draw<C: Canvas + ?Sized>(&self, canvas: &mut C, x: f64, y: f64, style: &TextStyle) {
+        let (runs, extent) = self.layout(canvas, style);
+
+        let (dx, dy) = extent.anchor_offset(style.anchor);
+        let (sin, cos) = style.rotation.sin_cos();
+
+        for run in runs {
+            let (u, v) = (dx + run.x, dy + run.rise);
+
+            let run_style = TextStyle { size: run.size, anchor: Anchor::default(), ..*style };
+
+            canvas.text(x + u * cos - v * sin, y + u * sin + v * cos, run.text, &run_style);
+        }
+    }
+
+    fn layout<C: Canvas + ?Sized>(&self, canvas: &C, style: &TextStyle) -> (Vec<Run<'_>>, TextExtent) {
+        let mut runs = Vec::new();
+        let mut extent = TextExtent::default();
+
+        extent.width = layout_nodes(&self.nodes, canvas, style, 0, 0.0, 0.0, &mut runs, &mut extent);
+
+        (runs, extent)
+    }
+}
+
+/// Parse markup and measure it as drawn on the given canvas
+pub fn extent<C: Canvas + ?Sized>(canvas: &C, text: &str, style: &TextStyle) -> TextExtent {
+    Markup::parse(text).extent(canvas, style)
+}
+
+/// Parse markup and draw it onto the given canvas
+pub fn draw<C: Canvas + ?Sized>(canvas: &mut C, x: f64, y: f64, text: &str, style: &TextStyle) {
+    Markup::parse(text).draw(canvas, x, y, style)
+}
+
+/// Font size of scripts nested to the given depth, shrinking like in TeX
+fn script_size(size: f64, depth: usize) -> f64 {
+    match depth {
+        0 => size,
+        1 => size * 0.7,
+        _ => size * 0.5,
+    }
+}
+
+/// Places the nodes starting at `x`, growing `extent` to cover them, and returns the position after the last one
+fn layout_nodes<'a, C: Canvas + ?Sized>(nodes: &'a [Node], canvas: &C, style: &TextStyle, depth: usize,
+                                        mut x: f64, rise: f64, runs: &mut Vec<Run<'a>>, extent: &mut TextExtent) -> f64 {
+    let size = script_size(style.size, depth);
+
+    for node in nodes {
+        match *node {
+            Node::Text(ref text) => {
+                let measured = canvas.text_extent(text, &TextStyle { size: size, ..*style });
+
+                extent.ascent = extent.ascent.max(rise + measured.ascent);
+                extent.descent = extent.descent.max(measured.descent - rise);
+
+                runs.push(Run { text: text, x: x, rise: rise, size: size });
+
+                x += measured.width;
+            }
+            Node::Scripts { ref sup, ref sub } => {
+                let mut end = x;
+
+                if let Some(ref sup) = *sup {
+                    end = end.max(layout_nodes(sup, canvas, style, depth + 1, x, rise + size * SUPERSCRIPT_RISE, runs, extent));
+                }
+
+                if let Some(ref sub) = *sub {
+                    end = end.max(layout_nodes(sub, canvas, style, depth + 1, x, rise - size * SUBSCRIPT_DROP, runs, extent));
+                }
+
+                x = end;
+            }
+        }
+    }
+
+    x
+}
+
+fn plain_text(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match *node {
+            Node::Text(ref text) => out.push_str(text),
+            Node::Scripts { ref sup, ref sub } => {
+                if let Some(ref sub) = *sub {
+                    plain_text(sub, out);
+                }
+
+                if let Some(ref sup) = *sup {
+                    plain_text(sup, out);
+                }
+            }
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+
+        if c.is_some() {
+            self.position += 1;
+        }
+
+        c
+    }
+
+    /// Parses nodes up to the end of the text or, inside a group, up to the closing brace
+    fn parse_list(&mut self, group: bool) -> Vec<Node> {
+        let mut nodes = Vec::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '}' if group => {
+                    self.next();
+                    break;
+                }
+                '^' | '_' => {
+                    self.next();
+
+                    match self.parse_atom() {
+                        Some(script) => add_script(&mut nodes, c == '^', script),
+                        None => push_text(&mut nodes, &c.to_string()),
+                    }
+                }
+                _ => {
+                    for node in self.parse_atom().unwrap_or_default() {
+                        match node {
+                            Node::Text(text) => push_text(&mut nodes, &text),
+                            node => nodes.push(node),
+                        }
+                    }
+                }
+            }
+        }
+
+        nodes
+    }
+
+    /// Parses a single character, command or group, returning `None` at the end of the text
+    fn parse_atom(&mut self) -> Option<Vec<Node>> {
+        let c = self.next()?;
+
+        let text = match c {
+            '{' => return Some(self.parse_list(true)),
+            '\\' => self.parse_command(),
+            c => c.to_string(),
+        };
+
+        Some(vec![Node::Text(text)])
+    }
+
+    /// Parses what follows a backslash, returning the text it stands for
+    fn parse_command(&mut self) -> String {
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.position;
+
+                while self.peek().map_or(false, |c| c.is_ascii_alphabetic()) {
+                    self.next();
+                }
+
+                let name: String = self.chars[start..self.position].iter().cloned().collect();
+
+                match command_char(&name) {
+                    Some(c) => {
+                        if self.peek() == Some(' ') {
+                            self.next();
+                        }
+
+                        c.to_string()
+                    }
+                    None => format!("\\{}", name),
+                }
+            }
+            Some(c @ '{') | Some(c @ '}') | Some(c @ '^') | Some(c @ '_') | Some(c @ '\\') => {
+                self.next();
+
+                c.to_string()
+            }
+            _ => "\\".to_string(),
+        }
+    }
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &str) {
+    if let Some(&mut Node::Text(ref mut last)) = nodes.last_mut() {
+        last.push_str(text);
+        return;
+    }
+
+    if !text.is_empty() {
+        nodes.push(Node::Text(text.to_string()));
+    }
+}
+
+/// Attaches a script to the preceding scripts if they don't have one of the same kind yet, as in `x_i^2`
+fn add_script(nodes: &mut Vec<Node>, superscript: bool, script: Vec<Node>) {
+    if let Some(&mut Node::Scripts { ref mut sup, ref mut sub }) = nodes.last_mut() {
+        let slot = if superscript { sup } else { sub };
+
+        if slot.is_none() {
+            *slot = Some(script);
+            return;
+        }
+    }
+
+    nodes.push(if superscript {
+        Node::Scripts { sup: Some(script), sub: None }
+    } else {
+        Node::Scripts { sup: None, sub: Some(script) }
+    });
+}
@@ -0,0 +1,109 @@
+//! Text rendering
+//!
+//! Plotters draw text with the built-in bitmap fonts from the `bitmap` module, which need no font files
+//! and cover ASCII, Latin-1, Greek and common mathematical symbols. Any `Canvas` can instead draw its text
+//! through another `TextRenderer`, such as the scalable stroke fonts in `hershey` or, with the `truetype`
+//! feature, fonts loaded from TrueType and OpenType files, by wrapping it in a `TextCanvas`.
+
+pub mod bitmap;
+pub mod hershey;
+pub mod markup;
+
+#[cfg(feature = "truetype")]
+pub mod truetype;
+
+use ::canvas::{Canvas, Stroke, TextStyle, TextExtent};
+use ::color::Color;
+
+/// Lays out and draws text with primitives of a `Canvas`
+pub trait TextRenderer {
+    /// Measure a line of text as it would be drawn by `render`
+    fn extent(&self, text: &str, style: &TextStyle) -> TextExtent;
+
+    /// Draw a line of text anchored at the given point
+    fn render<C: Canvas + ?Sized>(&self, canvas: &mut C, x: f64, y: f64, text: &str, style: &TextStyle);
+}
+
+impl<'a, R: TextRenderer> TextRenderer for &'a R {
+    fn extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        (**self).extent(text, style)
+    }
+
+    fn render<C: Canvas + ?Sized>(&self, canvas: &mut C, x: f64, y: f64, text: &str, style: &TextStyle) {
+        (**self).render(canvas, x, y, text, style)
+    }
+}
+
+/// Canvas adapter that draws all text with the given renderer, instead of the canvas' own text support
+pub struct TextCanvas<C: Canvas, R: TextRenderer> {
+    canvas: C,
+    renderer: R,
+}
+
+impl<C: Canvas, R: TextRenderer> TextCanvas<C, R> {
+    pub fn new(canvas: C, renderer: R) -> TextCanvas<C, R> {
+        TextCanvas { canvas: canvas, renderer: renderer }
+    }
+
+    pub fn renderer(&self) -> &R { &self.renderer }
+
+    pub fn inner(&self) -> &C { &self.canvas }
+
+    pub fn inner_mut(&mut self) -> &mut C { &mut self.canvas }
+
+    pub fn into_inner(self) -> C { self.canvas }
+}
+
+impl<C: Canvas, R: TextRenderer> Canvas for TextCanvas<C, R> {
+    fn dimensions(&self) -> (u32, u32) {
+        self.canvas.dimensions()
+    }
+
+    fn line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, stroke: Stroke) {
+        self.canvas.line(x0, y0, x1, y1, stroke)
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], stroke: Stroke) {
+        self.canvas.polyline(points, stroke)
+    }
+
+    fn polygon(&mut self, points: &[(f64, f64)], fill: Option<Color>, stroke: Option<Stroke>) {
+        self.canvas.polygon(points, fill, stroke)
+    }
+
+    fn compound_polygon(&mut self, contours: &[&[(f64, f64)]], fill: Option<Color>, stroke: Option<Stroke>) {
+        self.canvas.compound_polygon(contours, fill, stroke)
+    }
+
+    fn rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.canvas.rect(x0, y0, x1, y1, fill, stroke)
+    }
+
+    fn ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.canvas.ellipse(cx, cy, rx, ry, fill, stroke)
+    }
+
+    fn circle(&mut self, cx: f64, cy: f64, radius: f64, fill: Option<Color>, stroke: Option<Stroke>) {
+        self.canvas.circle(cx, cy, radius, fill, stroke)
+    }
+
+    fn text(&mut self, x: f64, y: f64, text: &str, style: &TextStyle) {
+        self.renderer.render(&mut self.canvas, x, y, text, style)
+    }
+
+    fn text_extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        self.renderer.extent(text, style)
+    }
+
+    fn push_clip_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64) {
+        self.canvas.push_clip_rect(x0, y0, x1, y1)
+    }
+
+    fn push_clip_polygon(&mut self, points: &[(f64, f64)]) {
+        self.canvas.push_clip_polygon(points)
+    }
+
+    fn pop_clip(&mut self) {
+        self.canvas.pop_clip()
+    }
+}
@@ -0,0 +1,222 @@
+//! TrueType and OpenType fonts
+//!
+//! Requires the `truetype` feature. Fonts are read with `rusttype`, and each glyph's outline is drawn as a
+//! filled polygon, so text is anti-aliased on raster plotters, stays as vector shapes on vector backends,
+//! and can be rotated to any angle.
+//!
+//! ```
+//! use rust_plot::buffer_plot::BufferPlot;
+//! use rust_plot::canvas::{Canvas, TextStyle};
+//! use rust_plot::text::TextCanvas;
+//! use rust_plot::text::truetype::TrueTypeFont;
+//!
+//! let font = TrueTypeFont::open(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/DejaVuSans.ttf")).unwrap();
+//!
+//! let mut canvas = TextCanvas::new(BufferPlot::new(200, 50), font);
+//!
+//! canvas.text(10.0, 20.0, "Temperature (°C)", &TextStyle::new(16.0));
+//! ```
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use rusttype::{Font, OutlineBuilder, Scale};
+
+use ::bezier::BezierCurve;
+use ::canvas::{Anchor, Canvas, TextStyle, TextExtent};
+
+use super::TextRenderer;
+
+/// Largest distance between a curve and the line segments approximating it, in pixels
+const FLATTEN_TOLERANCE: f64 = 0.1;
+
+/// A scalable font loaded from a TrueType or OpenType file
+pub struct TrueTypeFont {
+    font: Font<'static>,
+}
+
+impl TrueTypeFont {
+    /// Load a font from the contents of a font file, returning `None` if it isn't a valid font
+    pub fn from_bytes(data: Vec<u8>) -> Option<TrueTypeFont> {
+        Font::try_from_vec(data).map(|font| TrueTypeFont { font: font })
+    }
+
+    /// Load a font from a file
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<TrueTypeFont> {
+        let mut data = Vec::new();
+
+        File::open(path)?.read_to_end(&mut data)?;
+
+        TrueTypeFont::from_bytes(data).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "not a valid TrueType or OpenType font")
+        })
+    }
+
+    /// The underlying `rusttype` font
+    pub fn font(&self) -> &Font<'static> { &self.font }
+
+    /// Measure a line of text at the given size in pixels, including kerning
+    ///
+    /// ```
+    /// # use rust_plot::text::truetype::TrueTypeFont;
+    /// # let font = TrueTypeFont::open(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/DejaVuSans.ttf")).unwrap();
+    /// // Kerning pulls the letters closer together
+    /// assert!(font.extent("AV", 20.0).width < font.extent("A", 20.0).width + font.extent("V", 20.0).width);
+    /// ```
+    pub fn extent(&self, text: &str, size: f64) -> TextExtent {
+        let scale = Scale::uniform(size as f32);
+        let metrics = self.font.v_metrics(scale);
+
+        let mut width = 0.0;
+        let mut previous = None;
+
+        for glyph in self.font.glyphs_for(text.chars()) {
+            let glyph = glyph.scaled(scale);
+
+            if let Some(previous) = previous {
+                width += self.font.pair_kerning(scale, previous, glyph.id()) as f64;
+            }
+
+            width += glyph.h_metrics().advance_width as f64;
+            previous = Some(glyph.id());
+        }
+
+        TextExtent {
+            width: width,
+            ascent: metrics.ascent as f64,
+            descent: -metrics.descent as f64,
+        }
+    }
+
+    /// Lay out a line of text as the closed contours of its glyphs, anchored at the given point
+    /// and rotated counter-clockwise around it by `rotation` radians.
+    ///
+    /// The contours are meant to be filled together with the nonzero winding rule.
+    pub fn layout(&self, x: f64, y: f64, text: &str, anchor: Anchor, size: f64, rotation: f64) -> Vec<Vec<(f64, f64)>> {
+        let scale = Scale::uniform(size as f32);
+
+        let (dx, dy) = self.extent(text, size).anchor_offset(anchor);
+
+        let mut flattener = Flattener { contours: Vec::new(), current: Vec::new(), offset: (dx, dy) };
+        let mut previous = None;
+
+        for glyph in self.font.glyphs_for(text.chars()) {
+            let glyph = glyph.scaled(scale);
+
+            if let Some(previous) = previous {
+                flattener.offset.0 += self.font.pair_kerning(scale, previous, glyph.id()) as f64;
+            }
+
+            glyph.build_outline(&mut flattener);
+
+            flattener.offset.0 += glyph.h_metrics().advance_width as f64;
+            previous = Some(glyph.id());
+        }
+
+        let (sin, cos) = rotation.sin_cos();
+
+        let mut contours = flattener.contours;
+
+        for contour in &mut contours {
+            for point in contour.iter_mut() {
+                let (u, v) = *point;
+
+                *point = (x + u * cos - v * sin, y + u * sin + v * cos);
+            }
+        }
+
+        contours
+    }
+}
+
+impl TextRenderer for TrueTypeFont {
+    fn extent(&self, text: &str, style: &TextStyle) -> TextExtent {
+        TrueTypeFont::extent(self, text, style.size)
+    }
+
+    fn render<C: Canvas + ?Sized>(&self, canvas: &mut C, x: f64, y: f64, text: &str, style: &TextStyle) {
+        let contours = self.layout(x, y, text, style.anchor, style.size, style.rotation);
+
+        if !contours.is_empty() {
+            let contours: Vec<&[(f64, f64)]> = contours.iter().map(|c| &c[..]).collect();
+
+            canvas.compound_polygon(&contours, Some(style.color), None);
+        }
+    }
+}
+
+/// Collects glyph outlines as polygons, flattening their curves
+struct Flattener {
+    contours: Vec<Vec<(f64, f64)>>,
+    current: Vec<(f64, f64)>,
+    /// Position of the current glyph's origin
+    offset: (f64, f64),
+}
+
+impl Flattener {
+    /// Converts outline coordinates, which have y pointing down, to positioned y-up coordinates
+    fn point(&self, x: f32, y: f32) -> (f64, f64) {
+        (x as f64 + self.offset.0, self.offset.1 - y as f64)
+    }
+
+    fn finish(&mut self) {
+        if self.current.len() > 2 {
+            let contour = ::std::mem::replace(&mut self.current, Vec::new());
+
+            self.contours.push(contour);
+        } else {
+            self.current.clear();
+        }
+    }
+
+    /// Adds a curve from the current point, with enough segments to stay within `FLATTEN_TOLERANCE`
+    fn curve(&mut self, controls: Vec<(f64, f64)>, deviation: f64) {
+        let segments = (deviation / FLATTEN_TOLERANCE).sqrt().ceil().max(1.0) as usize;
+
+        let curve = BezierCurve::new(controls);
+
+        for i in 1..segments + 1 {
+            self.current.push(curve.evaluate(i as f64 / segments as f64));
+        }
+    }
+}
+
+/// Length of the second difference of three points, which bounds how far a curve through them bends
+fn bend(p0: (f64, f64), p1: (f64, f64), p2: (f64, f64)) -> f64 {
+    (p0.0 - 2.0 * p1.0 + p2.0).hypot(p0.1 - 2.0 * p1.1 + p2.1)
+}
+
+impl OutlineBuilder for Flattener {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish();
+
+        let p = self.point(x, y);
+        self.current.push(p);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.current.push(p);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = match self.current.last() { Some(&p) => p, None => return };
+        let (p1, p2) = (self.point(x1, y1), self.point(x, y));
+
+        self.curve(vec![p0, p1, p2], bend(p0, p1, p2) * 0.25);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = match self.current.last() { Some(&p) => p, None => return };
+        let (p1, p2, p3) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+
+        let deviation = bend(p0, p1, p2).max(bend(p1, p2, p3)) * 0.75;
+
+        self.curve(vec![p0, p1, p2, p3], deviation);
+    }
+
+    fn close(&mut self) {
+        self.finish();
+    }
+}
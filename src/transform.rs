@@ -0,0 +1,135 @@
+//! Two-dimensional affine transforms
+
+use std::ops::Mul;
+
+/// An affine transform of the plane
+///
+/// A point `(x, y)` is mapped to `(a * x + c * y + e, b * x + d * y + f)`, the same layout used by SVG and PDF.
+///
+/// Transforms are combined with `*`, where `t * u` applies `u` first and then `t`.
+///
+/// ```
+/// use rust_plot::transform::Affine2;
+///
+/// let t = Affine2::translate(10.0, 0.0) * Affine2::scale(2.0, 3.0);
+///
+/// assert_eq!(t.apply(1.0, 1.0), (12.0, 3.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2 {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Affine2 {
+    pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Affine2 {
+        Affine2 { a: a, b: b, c: c, d: d, e: e, f: f }
+    }
+
+    pub fn identity() -> Affine2 {
+        Affine2::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    pub fn translate(x: f64, y: f64) -> Affine2 {
+        Affine2::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    pub fn scale(x: f64, y: f64) -> Affine2 {
+        Affine2::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Counter-clockwise rotation around the origin, in radians
+    pub fn rotate(angle: f64) -> Affine2 {
+        let (sin, cos) = angle.sin_cos();
+
+        Affine2::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Counter-clockwise rotation around the given point, in radians
+    pub fn rotate_around(angle: f64, x: f64, y: f64) -> Affine2 {
+        Affine2::translate(x, y) * Affine2::rotate(angle) * Affine2::translate(-x, -y)
+    }
+
+    /// Skew along the x and y axes by the given angles, in radians
+    pub fn skew(x: f64, y: f64) -> Affine2 {
+        Affine2::new(1.0, y.tan(), x.tan(), 1.0, 0.0, 0.0)
+    }
+
+    /// Transform a point
+    #[inline]
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.a * x + self.c * y + self.e, self.b * x + self.d * y + self.f)
+    }
+
+    /// Transform a direction or offset, ignoring the translation
+    #[inline]
+    pub fn apply_vector(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.a * x + self.c * y, self.b * x + self.d * y)
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// The transform that undoes this one, or `None` if it collapses the plane onto a line or point
+    pub fn inverse(&self) -> Option<Affine2> {
+        let det = self.determinant();
+
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        let (a, b, c, d) = (self.d / det, -self.b / det, -self.c / det, self.a / det);
+
+        Some(Affine2::new(a, b, c, d, -(a * self.e + c * self.f), -(b * self.e + d * self.f)))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Affine2::identity()
+    }
+
+    /// Returns true if the transform only translates, rotates, flips and uniformly scales,
+    /// so that circles stay circles
+    pub fn is_similarity(&self) -> bool {
+        let epsilon = 1e-9 * (self.a.abs() + self.b.abs() + self.c.abs() + self.d.abs());
+
+        ((self.a - self.d).abs() <= epsilon && (self.b + self.c).abs() <= epsilon) ||
+            ((self.a + self.d).abs() <= epsilon && (self.b - self.c).abs() <= epsilon)
+    }
+
+    /// Returns true if the transform keeps horizontal lines horizontal and vertical lines vertical
+    pub fn is_axis_aligned(&self) -> bool {
+        self.b == 0.0 && self.c == 0.0
+    }
+
+    /// Factor by which the transform scales lengths, on average
+    pub fn scale_factor(&self) -> f64 {
+        self.determinant().abs().sqrt()
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Affine2 {
+        Affine2::identity()
+    }
+}
+
+impl Mul for Affine2 {
+    type Output = Affine2;
+
+    /// Composes two transforms, applying `rhs` first
+    fn mul(self, rhs: Affine2) -> Affine2 {
+        Affine2 {
+            a: self.a * rhs.a + self.c * rhs.b,
+            b: self.b * rhs.a + self.d * rhs.b,
+            c: self.a * rhs.c + self.c * rhs.d,
+            d: self.b * rhs.c + self.d * rhs.d,
+            e: self.a * rhs.e + self.c * rhs.f + self.e,
+            f: self.b * rhs.e + self.d * rhs.f + self.f,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Shared helpers for the vector output backends
+
+/// Formats a number compactly, without trailing zeros
+///
+/// ```
+/// use rust_plot::vector::format_number;
+///
+/// assert_eq!(format_number(1.5000), "1.5");
+/// assert_eq!(format_number(-0.0001), "0");
+/// assert_eq!(format_number(20.0), "20");
+/// ```
+pub fn format_number(x: f64) -> String {
+    let s = format!("{:.3}", x);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+
+    if s == "-0" { "0".to_owned() } else { s.to_owned() }
+}
+
+/// Distance of the cubic Bezier control points from the ends of a quarter ellipse, relative to its radius
+const KAPPA: f64 = 0.5522847498;
+
+/// Approximates an ellipse with four cubic Bezier curves.
+///
+/// Returns the starting point and the control points and end point of each curve, going counter-clockwise.
+pub fn ellipse_beziers(cx: f64, cy: f64, rx: f64, ry: f64) -> ((f64, f64), [[(f64, f64); 3]; 4]) {
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+
+    ((cx + rx, cy), [
+        [(cx + rx, cy + ky), (cx + kx, cy + ry), (cx, cy + ry)],
+        [(cx - kx, cy + ry), (cx - rx, cy + ky), (cx - rx, cy)],
+        [(cx - rx, cy - ky), (cx - kx, cy - ry), (cx, cy - ry)],
+        [(cx + kx, cy - ry), (cx + rx, cy - ky), (cx + rx, cy)],
+    ])
+}
+
+/// Fonts available to every PDF and PostScript reader without embedding
+///
+/// The text fonts are written in the WinAnsi encoding. Greek letters and mathematical symbols they lack are
+/// drawn with the `Symbol` font instead, which has its own encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandardFont {
+    Helvetica,
+    Courier,
+    /// Greek letters and mathematical symbols
+    Symbol,
+}
+
+/// Advance widths of Helvetica for ASCII 32 to 126, in thousandths of an em
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Characters of WinAnsi at 128 to 159, where it differs from Latin-1, with `None` for unused codes
+const WIN_ANSI_HIGH: [Option<char>; 32] = [
+    Some('€'), None, Some('‚'), Some('ƒ'), Some('„'), Some('…'), Some('†'), Some('‡'),
+    Some('ˆ'), Some('‰'), Some('Š'), Some('‹'), Some('Œ'), None, Some('Ž'), None,
+    None, Some('‘'), Some('’'), Some('“'), Some('”'), Some('•'), Some('–'), Some('—'),
+    Some('˜'), Some('™'), Some('š'), Some('›'), Some('œ'), None, Some('ž'), Some('Ÿ'),
+];
+
+/// Characters of the Symbol font with their code and advance width in thousandths of an em
+static SYMBOL_GLYPHS: [(char, u8, u16); 91] = [
+    ('Α', b'A', 722), ('Β', b'B', 667), ('Χ', b'C', 722), ('Δ', b'D', 612), ('∆', b'D', 612), ('Ε', b'E', 611),
+    ('Φ', b'F', 763), ('Γ', b'G', 603), ('Η', b'H', 722), ('Ι', b'I', 333), ('ϑ', b'J', 631), ('Κ', b'K', 722),
+    ('Λ', b'L', 686), ('Μ', b'M', 889), ('Ν', b'N', 722), ('Ο', b'O', 722), ('Π', b'P', 768), ('Θ', b'Q', 741),
+    ('Ρ', b'R', 556), ('Σ', b'S', 592), ('Τ', b'T', 611), ('Υ', b'U', 690), ('ς', b'V', 439), ('Ω', b'W', 768),
+    ('\u{2126}', b'W', 768), ('Ξ', b'X', 645), ('Ψ', b'Y', 795), ('Ζ', b'Z', 611),
+    ('α', b'a', 631), ('β', b'b', 549), ('χ', b'c', 549), ('δ', b'd', 494), ('ε', b'e', 439), ('ϵ', b'e', 439),
+    ('φ', b'f', 521), ('γ', b'g', 411), ('η', b'h', 603), ('ι', b'i', 329), ('ϕ', b'j', 603), ('κ', b'k', 549),
+    ('λ', b'l', 549), ('μ', b'm', 576), ('ν', b'n', 521), ('ο', b'o', 549), ('π', b'p', 549), ('θ', b'q', 521),
+    ('ρ', b'r', 549), ('σ', b's', 603), ('τ', b't', 439), ('υ', b'u', 576), ('ϖ', b'v', 713), ('ω', b'w', 686),
+    ('ξ', b'x', 493), ('ψ', b'y', 686), ('ζ', b'z', 494),
+    ('∀', 0x22, 713), ('∃', 0x24, 549), ('∗', 0x2A, 500), ('−', 0x2D, 549), ('≅', 0x40, 549), ('∴', 0x5C, 863),
+    ('⊥', 0x5E, 658), ('∼', 0x7E, 549), ('′', 0xA2, 247), ('≤', 0xA3, 549), ('∞', 0xA5, 713), ('↔', 0xAB, 1042),
+    ('←', 0xAC, 987), ('↑', 0xAD, 603), ('→', 0xAE, 987), ('↓', 0xAF, 603), ('″', 0xB2, 411), ('≥', 0xB3, 549),
+    ('∝', 0xB5, 713), ('∂', 0xB6, 494), ('≠', 0xB9, 549), ('≡', 0xBA, 549), ('≈', 0xBB, 549), ('ℵ', 0xC0, 823),
+    ('∅', 0xC6, 823), ('∩', 0xC7, 768), ('∪', 0xC8, 768), ('∈', 0xCE, 713), ('∉', 0xCF, 713), ('∇', 0xD1, 713),
+    ('∏', 0xD5, 823), ('√', 0xD6, 549), ('⋅', 0xD7, 250), ('⇒', 0xDE, 987), ('∑', 0xE5, 713), ('∫', 0xF2, 274),
+];
+
+/// Code of a character in the WinAnsi encoding of the standard text fonts
+///
+/// The minus sign, which these fonts lack, is written as a hyphen.
+///
+/// ```
+/// use rust_plot::vector::win_ansi;
+///
+/// assert_eq!(win_ansi('é'), Some(0xE9));
+/// assert_eq!(win_ansi('€'), Some(0x80));
+/// assert_eq!(win_ansi('−'), Some(b'-'));
+/// assert_eq!(win_ansi('σ'), None);
+/// ```
+pub fn win_ansi(c: char) -> Option<u8> {
+    match c as u32 {
+        0x2212 => Some(b'-'),
+        0x20..=0x7E | 0xA0..=0xFF => Some(c as u8),
+        _ => WIN_ANSI_HIGH.iter().position(|&h| h == Some(c)).map(|i| 0x80 + i as u8),
+    }
+}
+
+fn symbol_glyph(c: char) -> Option<(u8, u16)> {
+    if c == ' ' {
+        return Some((b' ', 250));
+    }
+
+    SYMBOL_GLYPHS.iter().find(|g| g.0 == c).map(|&(_, code, width)| (code, width))
+}
+
+impl StandardFont {
+    /// PostScript name of the font
+    pub fn name(&self) -> &'static str {
+        match *self {
+            StandardFont::Helvetica => "Helvetica",
+            StandardFont::Courier => "Courier",
+            StandardFont::Symbol => "Symbol",
+        }
+    }
+
+    /// Code of a character in the encoding of the font, or `None` if the font lacks it
+    pub fn encode(&self, c: char) -> Option<u8> {
+        match *self {
+            StandardFont::Symbol => symbol_glyph(c).map(|g| g.0),
+            _ => win_ansi(c),
+        }
+    }
+
+    /// The font a character is drawn with when this font is selected, and its code in that font
+    ///
+    /// Characters the font lacks are taken from the Symbol font if it has them, or drawn as `?` otherwise.
+    pub fn glyph(&self, c: char) -> (StandardFont, u8) {
+        match (self.encode(c), StandardFont::Symbol.encode(c)) {
+            (Some(code), _) => (*self, code),
+            (None, Some(code)) => (StandardFont::Symbol, code),
+            (None, None) => (*self, b'?'),
+        }
+    }
+
+    /// Split text into runs drawn with the same font, encoded for that font
+    ///
+    /// ```
+    /// use rust_plot::vector::StandardFont;
+    ///
+    /// assert_eq!(StandardFont::Helvetica.runs("σ = −1"), vec![
+    ///     (StandardFont::Symbol, b"s".to_vec()),
+    ///     (StandardFont::Helvetica, b" = -1".to_vec()),
+    /// ]);
+    /// ```
+    pub fn runs(&self, text: &str) -> Vec<(StandardFont, Vec<u8>)> {
+        let mut runs: Vec<(StandardFont, Vec<u8>)> = Vec::new();
+
+        for c in text.chars() {
+            let (font, code) = self.glyph(c);
+
+            match runs.last_mut() {
+                Some(&mut (last, ref mut bytes)) if last == font => bytes.push(code),
+                _ => runs.push((font, vec![code])),
+            }
+        }
+
+        runs
+    }
+
+    /// Advance width of a character, in thousandths of an em
+    ///
+    /// Characters outside of ASCII use an average width, unless they are taken from the Symbol font.
+    pub fn char_width(&self, c: char) -> u16 {
+        match self.glyph(c) {
+            (StandardFont::Symbol, _) => symbol_glyph(c).map_or(600, |g| g.1),
+            (StandardFont::Helvetica, code) => {
+                if 32 <= code && code < 127 { HELVETICA_WIDTHS[(code - 32) as usize] } else { 556 }
+            }
+            (StandardFont::Courier, _) => 600,
+        }
+    }
+
+    /// Height above the baseline, in thousandths of an em
+    pub fn ascent(&self) -> u16 {
+        match *self {
+            StandardFont::Helvetica => 718,
+            StandardFont::Courier => 629,
+            StandardFont::Symbol => 1010,
+        }
+    }
+
+    /// Depth below the baseline, in thousandths of an em
+    pub fn descent(&self) -> u16 {
+        match *self {
+            StandardFont::Helvetica => 207,
+            StandardFont::Courier => 157,
+            StandardFont::Symbol => 293,
+        }
+    }
+
+    /// Measure a string drawn at the given size
+    pub fn extent(&self, text: &str, size: f64) -> ::canvas::TextExtent {
+        let width: u32 = text.chars().map(|c| self.char_width(c) as u32).sum();
+
+        ::canvas::TextExtent {
+            width: width as f64 * size / 1000.0,
+            ascent: self.ascent() as f64 * size / 1000.0,
+            descent: self.descent() as f64 * size / 1000.0,
+        }
+    }
+}
+
+/// Encodes bytes of text as a PDF or PostScript string literal, including the parentheses
+///
+/// ```
+/// use rust_plot::vector::string_literal;
+///
+/// assert_eq!(string_literal(b"f(x) \\ 2"), "(f\\(x\\) \\\\ 2)");
+/// assert_eq!(string_literal(b"\xE9"), "(\\351)");
+/// ```
+pub fn string_literal(bytes: &[u8]) -> String {
+    let mut literal = String::from("(");
+
+    for &byte in bytes {
+        match byte {
+            b'(' | b')' | b'\\' => {
+                literal.push('\\');
+                literal.push(byte as char);
+            }
+            32..=126 => literal.push(byte as char),
+            _ => literal.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+
+    literal.push(')');
+    literal
+}